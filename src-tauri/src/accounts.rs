@@ -0,0 +1,345 @@
+use crate::balance::BalanceResponse;
+use crate::models::{CreateAccountInput, RenameAccountInput};
+use crate::settings;
+use crate::state::{AppState, ErrorResponse};
+use futures_lite::stream::StreamExt;
+use log::{info, warn};
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{command, AppHandle, State};
+use vecno_wallet_core::account::descriptor::{AccountDescriptorProperty, AccountDescriptorValue};
+use vecno_wallet_core::account::MULTISIG_ACCOUNT_KIND;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::utxo::{balance::AtomicBalance, scan::DEFAULT_WINDOW_SIZE, Scan, UtxoContext};
+
+#[derive(Serialize, Clone, Debug)]
+pub struct MultisigInfo {
+    pub minimum_signatures: u16,
+    pub cosigners: usize,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AccountInfo {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub index: Option<u64>,
+    pub receive_address: Option<String>,
+    pub balance: Option<BalanceResponse>,
+    pub watch_only: bool,
+    pub multisig: Option<MultisigInfo>,
+    pub active: bool,
+}
+
+impl AccountInfo {
+    pub(crate) fn new(account: &Arc<dyn Account>, active: bool) -> Self {
+        AccountInfo {
+            id: account.id().to_string(),
+            name: account.name_or_id(),
+            kind: account.account_kind().to_string(),
+            index: account
+                .clone()
+                .as_derivation_capable()
+                .ok()
+                .map(|derivation| derivation.account_index()),
+            receive_address: account.receive_address().ok().map(|a| a.to_string()),
+            watch_only: is_watch_only(account),
+            multisig: multisig_info(account),
+            balance: account.balance().as_ref().map(BalanceResponse::from),
+            active,
+        }
+    }
+}
+
+pub(crate) fn open_wallet(wallet: Option<&Arc<Wallet>>) -> Result<Arc<Wallet>, ErrorResponse> {
+    let wallet = wallet.ok_or(ErrorResponse {
+        error: "Wallet is not open".to_string(),
+    })?;
+    if !wallet.is_open() {
+        return Err(ErrorResponse {
+            error: "Wallet is not open".to_string(),
+        });
+    }
+    Ok(wallet.clone())
+}
+
+/// Every account stored in the wallet, in storage order.
+pub(crate) async fn load_accounts(wallet: &Arc<Wallet>) -> Result<Vec<Arc<dyn Account>>, ErrorResponse> {
+    let mut ids = Vec::new();
+    let mut stored = wallet
+        .store()
+        .as_account_store()
+        .map_err(|e| ErrorResponse { error: e.to_string() })?
+        .iter(None)
+        .await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    while let Some((account, _)) = stored.try_next().await.map_err(|e| ErrorResponse { error: e.to_string() })? {
+        ids.push(*account.id());
+    }
+
+    let guard_mutex = wallet.guard();
+    let guard = guard_mutex.lock().await;
+
+    let mut accounts = Vec::with_capacity(ids.len());
+    for id in ids {
+        match wallet.get_account_by_id(&id, &guard).await {
+            Ok(Some(account)) => accounts.push(account),
+            Ok(None) => warn!("Account {} is listed but could not be loaded", id),
+            Err(e) => warn!("Failed to load account {}: {}", id, e),
+        }
+    }
+
+    Ok(accounts)
+}
+
+pub(crate) async fn find_account(wallet: &Arc<Wallet>, account_id: &str) -> Result<Arc<dyn Account>, ErrorResponse> {
+    load_accounts(wallet)
+        .await?
+        .into_iter()
+        .find(|account| account.id().to_string() == account_id)
+        .ok_or(ErrorResponse {
+            error: format!("Account {} not found", account_id),
+        })
+}
+
+/// Private keys the account signs with. Multisig accounts may hold
+/// several and do not answer `prv_key_data_id()`, so the descriptor is
+/// used instead.
+pub(crate) fn key_data_ids(account: &Arc<dyn Account>) -> Vec<PrvKeyDataId> {
+    account
+        .descriptor()
+        .map(|descriptor| (&descriptor.prv_key_data_ids).into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// Watch-only accounts carry no private key data to sign with.
+pub(crate) fn is_watch_only(account: &Arc<dyn Account>) -> bool {
+    key_data_ids(account).is_empty()
+}
+
+pub(crate) fn is_multisig(account: &Arc<dyn Account>) -> bool {
+    account.account_kind() == MULTISIG_ACCOUNT_KIND
+}
+
+fn multisig_info(account: &Arc<dyn Account>) -> Option<MultisigInfo> {
+    if !is_multisig(account) {
+        return None;
+    }
+    let cosigners = match account.descriptor().ok()?.properties.get(&AccountDescriptorProperty::XpubKeys) {
+        Some(AccountDescriptorValue::XPubKeys(keys)) => keys.len(),
+        _ => 0,
+    };
+    Some(MultisigInfo {
+        minimum_signatures: account.minimum_signatures(),
+        cosigners,
+    })
+}
+
+/// Scans the account's receive and change chains until an empty window
+/// into the account's own UTXO context, once per account and node
+/// connection. The processor keeps that context current afterwards, so
+/// balance, history and sends all read the same account-wide state.
+///
+/// The account is marked as scanned before the scan starts so the lock is
+/// not held across network calls; a failed scan clears the mark again.
+pub(crate) async fn scan_account(state: &AppState, account: &Arc<dyn Account>) -> Result<UtxoContext, ErrorResponse> {
+    if !state.scanned_accounts.lock().await.insert(*account.id()) {
+        return Ok(account.utxo_context().clone());
+    }
+
+    if let Err(e) = run_scan(state, account).await {
+        state.scanned_accounts.lock().await.remove(account.id());
+        return Err(e);
+    }
+
+    info!("Scanned derivation window of account {}", account.name_or_id());
+    Ok(account.utxo_context().clone())
+}
+
+async fn run_scan(state: &AppState, account: &Arc<dyn Account>) -> Result<(), ErrorResponse> {
+    account
+        .clone()
+        .scan(Some(DEFAULT_WINDOW_SIZE), None)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Scan failed: {}", e) })?;
+
+    let watched = state.watch_addresses.lock().await.clone();
+    if !watched.is_empty() && is_watch_only(account) {
+        let current_daa_score = account.wallet().current_daa_score().unwrap_or_default();
+        Scan::new_with_address_set(watched.iter().cloned().collect(), &Arc::new(AtomicBalance::default()), current_daa_score)
+            .scan(account.utxo_context())
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Watched address scan failed: {}", e) })?;
+        // Subscribe the processor to them too, so later payments show up
+        // without waiting for the next reconnect scan.
+        account
+            .utxo_context()
+            .register_addresses(&watched)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Failed to watch addresses: {}", e) })?;
+        account
+            .utxo_context()
+            .update_balance()
+            .await
+            .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    }
+    Ok(())
+}
+
+/// Starts every account that is not running yet so each keeps its own
+/// UTXO context and balance, not only the selected one.
+pub(crate) async fn activate_all(wallet: &Arc<Wallet>) {
+    let accounts = match load_accounts(wallet).await {
+        Ok(accounts) => accounts,
+        Err(e) => {
+            warn!("Failed to load accounts for activation: {}", e.error);
+            return;
+        }
+    };
+
+    for account in accounts {
+        if wallet.active_accounts().get(account.id()).is_some() {
+            continue;
+        }
+        if let Err(e) = account.clone().start().await {
+            warn!("Failed to start account {}: {}", account.name_or_id(), e);
+        }
+    }
+}
+
+/// First private key behind the selected account, the one new accounts
+/// and multisig cosigner keys are derived from.
+pub(crate) fn selected_key_data_id(wallet: &Arc<Wallet>) -> Result<PrvKeyDataId, ErrorResponse> {
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    key_data_ids(&account)
+        .into_iter()
+        .next()
+        .ok_or(ErrorResponse { error: "The selected account has no private key to derive from".into() })
+}
+
+#[command]
+pub async fn list_accounts(state: State<'_, AppState>) -> Result<Vec<AccountInfo>, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let active_id = wallet.account().ok().map(|account| *account.id());
+
+    let accounts = load_accounts(&wallet)
+        .await?
+        .iter()
+        .map(|account| AccountInfo::new(account, Some(*account.id()) == active_id))
+        .collect::<Vec<_>>();
+
+    info!("Listed {} account(s)", accounts.len());
+    Ok(accounts)
+}
+
+/// Derives a new BIP32 account at the next free index from the key
+/// behind the selected account.
+#[command]
+pub async fn create_account(
+    input: CreateAccountInput,
+    state: State<'_, AppState>,
+) -> Result<AccountInfo, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+
+    let prv_key_data_id = selected_key_data_id(&wallet)?;
+
+    let name = input.name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let payment_secret = input
+        .payment_secret
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Secret::from);
+
+    let wallet_secret_guard = state.wallet_secret.lock().await;
+    let wallet_secret = wallet_secret_guard
+        .as_ref()
+        .ok_or(ErrorResponse { error: "Wallet secret not loaded".into() })?;
+
+    let account = {
+        let guard_mutex = wallet.guard();
+        let guard = guard_mutex.lock().await;
+        let account_args = AccountCreateArgs::new_bip32(prv_key_data_id, payment_secret, name, None);
+        wallet
+            .create_account(wallet_secret, account_args, true, &guard)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Account creation failed: {}", e) })?
+    };
+    drop(wallet_secret_guard);
+
+    account
+        .clone()
+        .start()
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
+
+    info!("Created account {}", account.name_with_id());
+    Ok(AccountInfo::new(&account, false))
+}
+
+#[command]
+pub async fn rename_account(
+    input: RenameAccountInput,
+    state: State<'_, AppState>,
+) -> Result<AccountInfo, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = find_account(&wallet, &input.account_id).await?;
+
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err(ErrorResponse { error: "Account name is required".into() });
+    }
+
+    let wallet_secret_guard = state.wallet_secret.lock().await;
+    let wallet_secret = wallet_secret_guard
+        .as_ref()
+        .ok_or(ErrorResponse { error: "Wallet secret not loaded".into() })?;
+
+    account
+        .rename(wallet_secret, Some(name))
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Rename failed: {}", e) })?;
+
+    let active = wallet.account().map(|a| a.id() == account.id()).unwrap_or(false);
+    info!("Renamed account {} to '{}'", account.id(), name);
+    Ok(AccountInfo::new(&account, active))
+}
+
+/// Makes the account the one balance, history, receive and send act on,
+/// and remembers the choice for the next time the wallet is opened.
+#[command]
+pub async fn select_account(
+    account_id: String,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<AccountInfo, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = find_account(&wallet, &account_id).await?;
+
+    if wallet.active_accounts().get(account.id()).is_none() {
+        account
+            .clone()
+            .start()
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
+    }
+
+    if let Err(e) = scan_account(&state, &account).await {
+        warn!("Account {} selected without a full scan: {}", account.name_or_id(), e.error);
+    }
+
+    wallet
+        .select(Some(&account))
+        .await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    if let Some(wallet_name) = state.wallet_name.lock().await.clone() {
+        let mut meta = settings::load_wallet_meta(&app, &wallet_name);
+        meta.active_account = Some(account.id().to_string());
+        settings::save_wallet_meta(&app, &wallet_name, &meta)?;
+    }
+
+    info!("Selected account {}", account.name_with_id());
+    Ok(AccountInfo::new(&account, true))
+}
@@ -0,0 +1,251 @@
+use crate::accounts::open_wallet;
+use crate::get_transactions::{load_records, record_output_addresses};
+use crate::models::AddressLabelInput;
+use crate::settings;
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::password::{load_file, rewrite_wallet_file, wallet_file};
+use log::{info, warn};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use tauri::{command, AppHandle, State};
+use vecno_addresses::Prefix;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::storage::AddressBookEntry;
+use vecno_wrpc_client::prelude::RpcApi;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DerivedAddress {
+    pub address: String,
+    pub kind: String,
+    pub index: u32,
+    pub balance: u64,
+    pub utxo_count: usize,
+    pub used: bool,
+    pub current: bool,
+    pub label: Option<String>,
+}
+
+/// Rewrites the address book in the wallet's encrypted payload with
+/// `update` applied. wallet-core keeps address book entries but has no
+/// API to edit them, so the file is rewritten and the store reloaded.
+async fn update_address_book(
+    wallet: &Arc<Wallet>,
+    secret: &Secret,
+    update: impl FnOnce(&mut Vec<AddressBookEntry>),
+) -> Result<(), ErrorResponse> {
+    let path = wallet_file(wallet)?;
+    let previous = load_file(&path).await?;
+    let mut payload = previous
+        .payload(secret)
+        .map(|payload| payload.unwrap())
+        .map_err(|_| ErrorResponse { error: "Incorrect wallet password".into() })?;
+    update(&mut payload.address_book);
+
+    let (key_count, account_count) = (payload.prv_key_data.len(), payload.accounts.len());
+    rewrite_wallet_file(wallet, &path, &previous, secret, secret, payload, |payload| {
+        if payload.prv_key_data.len() != key_count || payload.accounts.len() != account_count {
+            return Err(ErrorResponse { error: "Rewritten wallet file is missing keys or accounts".into() });
+        }
+        Ok(())
+    })
+    .await
+}
+
+/// Sets or, for an empty label, removes the address book entry of `address`.
+fn apply_label(entries: &mut Vec<AddressBookEntry>, address: &Address, label: &str) {
+    entries.retain(|entry| &entry.address != address);
+    if !label.is_empty() {
+        entries.push(AddressBookEntry {
+            alias: label.to_string(),
+            title: String::new(),
+            address: address.clone(),
+        });
+    }
+}
+
+/// Labels of the open wallet, read from the address book in its encrypted
+/// payload. Labels older versions kept in the settings file are moved into
+/// the wallet on first read. Empty while the wallet is locked.
+async fn load_labels(state: &AppState, app: &AppHandle) -> Result<BTreeMap<String, String>, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let Some(secret) = state.wallet_secret.lock().await.clone() else {
+        return Ok(BTreeMap::new());
+    };
+
+    if let Some(wallet_name) = state.wallet_name.lock().await.clone() {
+        let mut meta = settings::load_wallet_meta(app, &wallet_name);
+        if !meta.address_labels.is_empty() {
+            let legacy = std::mem::take(&mut meta.address_labels);
+            update_address_book(&wallet, &secret, |entries| {
+                for (address, label) in &legacy {
+                    match Address::try_from(address.as_str()) {
+                        Ok(address) => apply_label(entries, &address, label),
+                        Err(e) => warn!("Dropping label of invalid address {}: {}", address, e),
+                    }
+                }
+            })
+            .await?;
+            settings::save_wallet_meta(app, &wallet_name, &meta)?;
+            info!("Moved {} address label(s) into the wallet file", legacy.len());
+        }
+    }
+
+    let payload = load_file(&wallet_file(&wallet)?)
+        .await?
+        .payload(&secret)
+        .map_err(|e| ErrorResponse { error: format!("Failed to read address labels: {}", e) })?;
+    Ok(payload
+        .as_ref()
+        .address_book
+        .iter()
+        .map(|entry| (entry.address.to_string(), entry.alias.clone()))
+        .collect())
+}
+
+/// Every address derived so far on both chains, up to and including the
+/// current index. Accounts without derivation expose a single address.
+fn derived_addresses(account: &Arc<dyn Account>) -> Result<Vec<(Address, &'static str, u32, bool)>, ErrorResponse> {
+    let derivation_account = match account.clone().as_derivation_capable() {
+        Ok(derivation_account) => derivation_account,
+        Err(_) => {
+            let address = account.receive_address().map_err(ErrorResponse::from)?;
+            return Ok(vec![(address, "receive", 0, true)]);
+        }
+    };
+
+    let derivation = derivation_account.derivation();
+    let managers = [
+        ("receive", derivation.receive_address_manager()),
+        ("change", derivation.change_address_manager()),
+    ];
+
+    let mut list = Vec::new();
+    for (kind, manager) in managers {
+        let current = manager.index();
+        let addresses = manager
+            .get_range_with_args(0..current + 1, false)
+            .map_err(|e| ErrorResponse { error: format!("Failed to derive {} addresses: {}", kind, e) })?;
+        list.extend(
+            addresses
+                .into_iter()
+                .zip(0u32..)
+                .map(|(address, index)| (address, kind, index, index == current)),
+        );
+    }
+
+    Ok(list)
+}
+
+/// Receive and change addresses of the selected account with their
+/// balance, whether they ever appeared in the history, and their label.
+#[command]
+pub async fn list_addresses(state: State<'_, AppState>, app: AppHandle) -> Result<Vec<DerivedAddress>, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account: Arc<dyn Account> = wallet.account().map_err(ErrorResponse::from)?;
+
+    let derived = derived_addresses(&account)?;
+
+    let entries = wallet
+        .rpc_api()
+        .get_utxos_by_addresses(derived.iter().map(|(address, ..)| address.clone()).collect())
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to fetch address balances: {}", e) })?;
+
+    let mut balances: HashMap<String, (u64, usize)> = HashMap::new();
+    for entry in entries {
+        if let Some(address) = entry.address {
+            let slot = balances.entry(address.to_string()).or_default();
+            slot.0 += entry.utxo_entry.amount;
+            slot.1 += 1;
+        }
+    }
+
+    let prefix = Prefix::from(wallet.network_id().map_err(ErrorResponse::from)?);
+    let seen: HashSet<String> = load_records(&wallet, &account)
+        .await?
+        .iter()
+        .flat_map(|record| record_output_addresses(record, prefix))
+        .map(|address| address.to_string())
+        .collect();
+
+    let labels = load_labels(&state, &app).await?;
+
+    let addresses = derived
+        .into_iter()
+        .map(|(address, kind, index, current)| {
+            let address = address.to_string();
+            let (balance, utxo_count) = balances.get(&address).copied().unwrap_or_default();
+            DerivedAddress {
+                used: utxo_count > 0 || seen.contains(&address),
+                label: labels.get(&address).cloned(),
+                address,
+                kind: kind.to_string(),
+                index,
+                balance,
+                utxo_count,
+                current,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    info!("Listed {} derived address(es)", addresses.len());
+    Ok(addresses)
+}
+
+/// Advances the receive chain by one index and starts watching the new
+/// address. The derivation index is saved with the account.
+#[command]
+pub async fn new_receive_address(state: State<'_, AppState>, app: AppHandle) -> Result<DerivedAddress, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account: Arc<dyn Account> = wallet.account().map_err(ErrorResponse::from)?;
+
+    let derivation_account = account.clone().as_derivation_capable().map_err(|_| ErrorResponse {
+        error: "This account has a single address and cannot derive new ones".into(),
+    })?;
+
+    let address = derivation_account
+        .clone()
+        .new_receive_address()
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to derive a new address: {}", e) })?;
+    let index = derivation_account.derivation().receive_address_manager().index();
+
+    let address = address.to_string();
+    let label = load_labels(&state, &app).await?.get(&address).cloned();
+
+    info!("Derived receive address #{} for account {}", index, account.name_or_id());
+    Ok(DerivedAddress {
+        address,
+        kind: "receive".to_string(),
+        index,
+        balance: 0,
+        utxo_count: 0,
+        used: false,
+        current: true,
+        label,
+    })
+}
+
+/// Attaches a label to an address, or removes it when the label is empty.
+/// Labels are stored in the wallet file's encrypted address book, so they
+/// travel with the file.
+#[command]
+pub async fn set_address_label(
+    input: AddressLabelInput,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let secret = state.wallet_secret.lock().await.clone().ok_or(ErrorResponse {
+        error: "Unlock the wallet first".to_string(),
+    })?;
+
+    let address = Address::try_from(input.address.trim())
+        .map_err(|e| ErrorResponse { error: format!("Invalid address: {}", e) })?;
+    let label = input.label.trim();
+
+    update_address_book(&wallet, &secret, |entries| apply_label(entries, &address, label)).await?;
+
+    info!("Updated label for {}", address);
+    Ok(())
+}
@@ -1,16 +1,41 @@
+use crate::accounts;
+use crate::events;
 use crate::state::{AppState, ErrorResponse};
 use tauri::{command, State};
+use vecno_wallet_core::prelude::Balance;
 use log::info;
-use vecno_rpc_core::RpcUtxosByAddressesEntry;
 use serde::{Deserialize, Serialize};
 use chrono::Utc;
 
+/// Account balance split by UTXO state. `balance` is the total the wallet
+/// owns (mature + pending); `outgoing` is locked in sends not yet accepted.
 #[derive(Serialize, Deserialize, Clone)]
 pub struct BalanceResponse {
     pub balance: u64,
+    pub mature: u64,
+    pub pending: u64,
+    pub outgoing: u64,
+    pub mature_utxo_count: usize,
+    pub pending_utxo_count: usize,
+    pub stasis_utxo_count: usize,
     pub timestamp: i64,
 }
 
+impl From<&Balance> for BalanceResponse {
+    fn from(balance: &Balance) -> Self {
+        BalanceResponse {
+            balance: balance.mature + balance.pending,
+            mature: balance.mature,
+            pending: balance.pending,
+            outgoing: balance.outgoing,
+            mature_utxo_count: balance.mature_utxo_count,
+            pending_utxo_count: balance.pending_utxo_count,
+            stasis_utxo_count: balance.stasis_utxo_count,
+            timestamp: Utc::now().timestamp(),
+        }
+    }
+}
+
 #[command]
 pub async fn get_balance(state: State<'_, AppState>) -> Result<BalanceResponse, ErrorResponse> {
     info!("=== BALANCE REFRESH STARTED ===");
@@ -22,6 +47,7 @@ pub async fn get_balance(state: State<'_, AppState>) -> Result<BalanceResponse,
             error: "No wallet initialized".into(),
         })?
         .clone();
+    drop(wallet_guard);
 
     if !wallet.is_open() {
         info!("=== BALANCE REFRESH FAILED: Wallet is not open ===");
@@ -30,74 +56,17 @@ pub async fn get_balance(state: State<'_, AppState>) -> Result<BalanceResponse,
         });
     }
 
+    // Only scans when this account has not covered its derivation window
+    // on the current connection yet; otherwise the context is current.
     let account = wallet
         .account()
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    accounts::scan_account(&state, &account).await?;
 
-    let receive_address = account
-        .receive_address()
-        .map_err(|e| ErrorResponse { error: e.to_string() })?;
-    let change_address = account
-        .change_address()
-        .map_err(|e| ErrorResponse { error: e.to_string() })?;
-
-    info!("Fetching UTXOs for receive: {} | change: {}", receive_address, change_address);
-
-    let addresses = vec![receive_address.clone(), change_address.clone()];
-    let utxos: Vec<RpcUtxosByAddressesEntry> = wallet
-        .rpc_api()
-        .get_utxos_by_addresses(addresses)
-        .await
-        .map_err(|e| ErrorResponse {
-            error: format!("Failed to fetch UTXOs: {}", e),
-        })?;
-
-    let mut receive_utxos = Vec::new();
-    let mut change_utxos = Vec::new();
-
-    for entry in &utxos {
-        if entry.address == Some(receive_address.clone()) {
-            receive_utxos.push(entry);
-        } else {
-            change_utxos.push(entry);
-        }
-    }
-
-    let receive_count = receive_utxos.len();
-    let change_count = change_utxos.len();
-    let total_count = receive_count + change_count;
-
-    let receive_balance: u64 = receive_utxos.iter().map(|e| e.utxo_entry.amount).sum();
-    let change_balance: u64 = change_utxos.iter().map(|e| e.utxo_entry.amount).sum();
-    let total_balance = receive_balance + change_balance;
-
-    info!("────────────────────────────────────────");
-    info!("│ BALANCE REPORT (Receive + Change)");
-    info!("├─ Receive Addr : {}", receive_address);
-    info!("│   ├─ UTXOs     : {}", receive_count);
-    info!("│   └─ Balance   : {} VE", receive_balance);
-    info!("├─ Change Addr  : {}", change_address);
-    info!("│   ├─ UTXOs     : {}", change_count);
-    info!("│   └─ Balance   : {} VE", change_balance);
-    info!("├─ TOTAL");
-    info!("│   ├─ UTXOs     : {}", total_count);
-    info!("│   └─ Balance   : {} VE", total_balance);
-    info!("────────────────────────────────────────");
+    let response = events::account_balance(&wallet).ok_or(ErrorResponse {
+        error: "Balance is not available yet".into(),
+    })?;
 
-    if total_count > 0 {
-        let all_amounts: Vec<u64> = utxos.iter().map(|e| e.utxo_entry.amount).collect();
-        let min = all_amounts.iter().min().unwrap();
-        let max = all_amounts.iter().max().unwrap();
-        info!("   • Smallest UTXO : {} VE", min);
-        info!("   • Largest UTXO  : {} VE", max);
-    }
-
-    let timestamp = Utc::now().timestamp();
-
-    info!("=== BALANCE REFRESH COMPLETED: {} VE at {} ===", total_balance, timestamp);
-
-    Ok(BalanceResponse {
-        balance: total_balance,
-        timestamp,
-    })
-}
\ No newline at end of file
+    info!("=== BALANCE REFRESH COMPLETED: {} VE across all derived addresses ===", response.balance);
+    Ok(response)
+}
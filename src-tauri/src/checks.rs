@@ -1,12 +1,15 @@
 
+use crate::accounts;
+use crate::models::GenerateMnemonicInput;
+use crate::settings;
+use crate::wallet::mnemonic::generate_phrase;
 use crate::state::{AppState, ErrorResponse, WalletAddress, WalletFile};
-use tauri::{command, State};
+use tauri::{command, AppHandle, State};
 use log::{error, info};
 use vecno_wallet_core::prelude::Secret;
 use vecno_wallet_core::storage::local::{Storage, WalletStorage};
 use std::path::Path;
-use rand::Rng;
-use bip39;
+use zeroize::Zeroizing;
 use vecno_wallet_core::storage::keydata::PrvKeyDataVariant;
 use futures_lite::StreamExt;
 
@@ -28,11 +31,9 @@ pub async fn is_wallet_open(state: State<'_, AppState>) -> Result<bool, ErrorRes
 }
 
 #[command]
-pub async fn generate_mnemonic() -> Result<String, ErrorResponse> {
-    let entropy = rand::thread_rng().gen::<[u8; 32]>();
-    let mnemonic = bip39::Mnemonic::from_entropy_in(bip39::Language::English, &entropy)
-        .map_err(|e| ErrorResponse { error: e.to_string() })?;
-    Ok(mnemonic.to_string())
+pub async fn generate_mnemonic(input: GenerateMnemonicInput) -> Result<Zeroizing<String>, ErrorResponse> {
+    let mnemonic = generate_phrase(input.word_count, input.language.as_deref())?;
+    Ok(Zeroizing::new(mnemonic.to_string()))
 }
 
 #[command]
@@ -65,16 +66,22 @@ pub async fn get_address(state: State<'_, AppState>) -> Result<Vec<WalletAddress
         })?
         .to_string();
 
+    let account_index = account
+        .clone()
+        .as_derivation_capable()
+        .map(|derivation| derivation.account_index() as u32)
+        .unwrap_or_default();
+
     Ok(vec![WalletAddress {
-        account_name: "default-account".to_string(),
-        account_index: 0,
+        account_name: account.name_or_id(),
+        account_index,
         receive_address: receive,
         change_address: change,
     }])
 }
 
 #[command]
-pub async fn list_wallets() -> Result<Vec<WalletFile>, ErrorResponse> {
+pub async fn list_wallets(app: AppHandle) -> Result<Vec<WalletFile>, ErrorResponse> {
     use std::fs;
     use vecno_wallet_core::settings::application_folder;
 
@@ -91,11 +98,9 @@ pub async fn list_wallets() -> Result<Vec<WalletFile>, ErrorResponse> {
             if let Ok(file_name) = entry.file_name().into_string() {
                 if file_name.ends_with(".wallet") {
                     let path = entry.path().to_string_lossy().into_owned();
-                    let name = file_name
-                        .strip_suffix(".wallet")
-                        .unwrap_or(&file_name)
-                        .to_string();
-                    wallets.push(WalletFile { name, path });
+                    let name = settings::wallet_meta_key(&file_name);
+                    let network = settings::load_wallet_meta(&app, &name).network_id().to_string();
+                    wallets.push(WalletFile { name, path, network });
                 }
             }
         }
@@ -164,11 +169,25 @@ pub async fn verify_wallet_password(
     Ok(())
 }
 
+/// Whether the selected account is watch-only and therefore cannot sign.
+#[command]
+pub async fn is_watch_only(state: State<'_, AppState>) -> Result<bool, ErrorResponse> {
+    let guard = state.wallet.lock().await;
+    let wallet = guard.as_ref().ok_or(ErrorResponse { error: "No wallet".into() })?;
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    Ok(accounts::is_watch_only(&account))
+}
+
 #[command]
 pub async fn wallet_needs_payment_secret(state: State<'_, AppState>) -> Result<bool, ErrorResponse> {
     let wallet_guard = state.wallet.lock().await;
     let wallet = wallet_guard.as_ref().ok_or(ErrorResponse { error: "No wallet".into() })?;
 
+    // Nothing is ever signed from a watch-only account.
+    if wallet.account().map(|account| accounts::is_watch_only(&account)).unwrap_or(false) {
+        return Ok(false);
+    }
+
     let wallet_secret_guard = state.wallet_secret.lock().await;
     let wallet_secret = wallet_secret_guard.as_ref().ok_or(ErrorResponse { error: "Wallet secret missing".into() })?;
 
@@ -199,7 +218,8 @@ pub async fn wallet_needs_payment_secret(state: State<'_, AppState>) -> Result<b
             }
         };
 
-        let is_mnemonic = matches!(*decrypted.as_variant(), PrvKeyDataVariant::Mnemonic(_));
+        // Phrases outside the English wordlist are stored as their seed.
+        let is_mnemonic = matches!(*decrypted.as_variant(), PrvKeyDataVariant::Mnemonic(_) | PrvKeyDataVariant::Bip39Seed(_));
         info!("Key ID: {} | Is Mnemonic: {}", info.id, is_mnemonic);
 
         if is_mnemonic {
@@ -0,0 +1,192 @@
+use crate::models::CompoundInput;
+use crate::send_transactions::{
+    estimate, fee_rate_for, fetch_fee_tiers, generator_settings, load_send_context, load_signer,
+    total_available, SendContext,
+};
+use crate::state::{AppState, ErrorResponse};
+use log::{error, info, warn};
+use serde::Serialize;
+use tauri::{command, AppHandle, Emitter, State};
+use vecno_wallet_core::tx::generator::Generator;
+use vecno_wallet_core::tx::{Fees, PaymentDestination};
+use workflow_core::prelude::Abortable;
+
+pub const EVENT_PROGRESS: &str = "compound://progress";
+
+#[derive(Serialize, Clone, Debug)]
+pub struct CompoundPreview {
+    pub utxos: usize,
+    pub total_amount: u64,
+    pub transactions: usize,
+    pub total_fees: u64,
+    pub mass: u64,
+    pub final_amount: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct CompoundProgress {
+    pub index: usize,
+    pub estimated_transactions: usize,
+    pub txid: String,
+    pub fees: u64,
+    pub is_final: bool,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct CompoundResult {
+    pub txids: Vec<String>,
+    pub total_fees: u64,
+    pub aborted: bool,
+}
+
+/// Loads the spendable UTXOs, keeping only those below the requested
+/// threshold so large outputs are left untouched.
+async fn load_compound_context(state: &AppState, input: &CompoundInput) -> Result<SendContext, ErrorResponse> {
+    let mut ctx = load_send_context(state, input.utxos.as_deref()).await?;
+
+    if let Some(max_amount) = input.max_utxo_amount {
+        ctx.utxo_entries.retain(|entry| entry.amount() < max_amount);
+    }
+
+    if ctx.utxo_entries.len() < 2 {
+        return Err(ErrorResponse { error: "Nothing to compound: fewer than two eligible UTXOs".into() });
+    }
+
+    Ok(ctx)
+}
+
+#[command]
+pub async fn preview_compound(
+    input: CompoundInput,
+    state: State<'_, AppState>,
+) -> Result<CompoundPreview, ErrorResponse> {
+    let ctx = load_compound_context(&state, &input).await?;
+    let tiers = fetch_fee_tiers(ctx.wallet.rpc_api().as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, input.fee_priority.unwrap_or_default());
+
+    let total_amount = total_available(&ctx);
+    let settings = generator_settings(&ctx, PaymentDestination::Change, fee_rate, Fees::None)?;
+    let summary = estimate(settings)?;
+
+    Ok(CompoundPreview {
+        utxos: ctx.utxo_entries.len(),
+        total_amount,
+        transactions: summary.number_of_generated_transactions(),
+        total_fees: summary.aggregate_fees(),
+        mass: summary.aggregate_mass(),
+        final_amount: total_amount.saturating_sub(summary.aggregate_fees()),
+    })
+}
+
+/// Merges the account's UTXOs into its change address. Each submitted
+/// transaction is reported on `compound://progress`; `cancel_compound`
+/// stops the run after the transaction in flight.
+#[command]
+pub async fn compound_utxos(
+    input: CompoundInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<CompoundResult, ErrorResponse> {
+    let abortable = Abortable::default();
+    {
+        let mut running = state.compound_abort.lock().await;
+        if running.is_some() {
+            return Err(ErrorResponse { error: "A compound run is already in progress".into() });
+        }
+        *running = Some(abortable.clone());
+    }
+
+    let result = run(&input, &state, &app, &abortable).await;
+    *state.compound_abort.lock().await = None;
+    result
+}
+
+async fn run(
+    input: &CompoundInput,
+    state: &AppState,
+    app: &AppHandle,
+    abortable: &Abortable,
+) -> Result<CompoundResult, ErrorResponse> {
+    let ctx = load_compound_context(state, input).await?;
+    let signer = load_signer(state, &ctx, input.payment_secret.as_deref()).await?;
+
+    let rpc = ctx.wallet.rpc_api();
+    let tiers = fetch_fee_tiers(rpc.as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, input.fee_priority.unwrap_or_default());
+
+    let preview = estimate(generator_settings(&ctx, PaymentDestination::Change, fee_rate, Fees::None)?)?;
+    let estimated_transactions = preview.number_of_generated_transactions();
+    info!(
+        "compound_utxos: {} UTXO(s) into ~{} transaction(s)",
+        ctx.utxo_entries.len(),
+        estimated_transactions
+    );
+
+    let settings = generator_settings(&ctx, PaymentDestination::Change, fee_rate, Fees::None)?;
+    let generator = Generator::try_new(settings, Some(signer), Some(abortable))
+        .map_err(|e| ErrorResponse { error: format!("Generator creation failed: {e}") })?;
+
+    let mut txids = Vec::new();
+    let mut total_fees = 0;
+
+    for (i, pending_tx_result) in generator.iter().enumerate() {
+        if abortable.is_aborted() {
+            break;
+        }
+
+        let pending_tx = match pending_tx_result {
+            Ok(pending_tx) => pending_tx,
+            Err(_) if abortable.is_aborted() => break,
+            Err(e) => return Err(ErrorResponse { error: format!("Generator error at tx #{}: {e}", i + 1) }),
+        };
+
+        pending_tx
+            .try_sign()
+            .map_err(|e| ErrorResponse { error: format!("Signing failed for tx #{}: {e}", i + 1) })?;
+
+        let txid = pending_tx
+            .try_submit(&rpc)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Submit failed for tx #{}: {e}", i + 1) })?
+            .to_string();
+
+        total_fees += pending_tx.fees();
+        txids.push(txid.clone());
+
+        let progress = CompoundProgress {
+            index: i + 1,
+            estimated_transactions,
+            txid,
+            fees: pending_tx.fees(),
+            is_final: pending_tx.is_final(),
+        };
+        if let Err(e) = app.emit(EVENT_PROGRESS, progress) {
+            error!("Failed to emit {}: {}", EVENT_PROGRESS, e);
+        }
+    }
+
+    let aborted = abortable.is_aborted();
+    if aborted {
+        warn!("compound_utxos cancelled after {} transaction(s)", txids.len());
+    } else {
+        info!("compound_utxos submitted {} transaction(s), fees {} VENI", txids.len(), total_fees);
+    }
+
+    Ok(CompoundResult {
+        txids,
+        total_fees,
+        aborted,
+    })
+}
+
+#[command]
+pub async fn cancel_compound(state: State<'_, AppState>) -> Result<bool, ErrorResponse> {
+    match state.compound_abort.lock().await.as_ref() {
+        Some(abortable) => {
+            abortable.abort();
+            info!("compound_utxos cancellation requested");
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
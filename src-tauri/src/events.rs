@@ -0,0 +1,132 @@
+use crate::accounts;
+use crate::balance::BalanceResponse;
+use crate::state::AppState;
+use log::{error, info, warn};
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::async_runtime;
+use tauri::{AppHandle, Emitter, Manager};
+use vecno_wallet_core::prelude::*;
+
+pub const EVENT_BALANCE: &str = "wallet://balance";
+pub const EVENT_PENDING: &str = "wallet://pending";
+pub const EVENT_MATURITY: &str = "wallet://maturity";
+pub const EVENT_REORG: &str = "wallet://reorg";
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TransactionEvent {
+    pub txid: String,
+    pub kind: String,
+    pub amount: u64,
+    pub timestamp: Option<u64>,
+}
+
+impl From<&TransactionRecord> for TransactionEvent {
+    fn from(record: &TransactionRecord) -> Self {
+        TransactionEvent {
+            txid: record.id().to_string(),
+            kind: record.kind().to_string(),
+            amount: record.value(),
+            timestamp: record.unixtime_msec(),
+        }
+    }
+}
+
+/// Balance of the selected account as seen by its UTXO context, or `None`
+/// while the initial UTXO scan has not completed yet.
+pub fn account_balance(wallet: &Arc<Wallet>) -> Option<BalanceResponse> {
+    let account = wallet.account().ok()?;
+    let balance = account.balance()?;
+    Some(BalanceResponse::from(&balance))
+}
+
+/// Spawns the forwarder that turns wallet-core events into Tauri events,
+/// replacing any forwarder left over from a previous session.
+pub async fn start(app: &AppHandle, wallet: &Arc<Wallet>) {
+    let handle = async_runtime::spawn(run(app.clone(), wallet.clone()));
+
+    let state = app.state::<AppState>();
+    if let Some(previous) = state.wallet_events.lock().await.replace(handle) {
+        previous.abort();
+    }
+}
+
+pub async fn stop(state: &AppState) {
+    if let Some(handle) = state.wallet_events.lock().await.take() {
+        handle.abort();
+        info!("Wallet event forwarder stopped");
+    }
+}
+
+fn emit<S: Serialize + Clone>(app: &AppHandle, event: &str, payload: S) {
+    if let Err(e) = app.emit(event, payload) {
+        error!("Failed to emit {}: {}", event, e);
+    }
+}
+
+async fn run(app: AppHandle, wallet: Arc<Wallet>) {
+    let channel = wallet.multiplexer().channel();
+    info!("Wallet event forwarder started");
+
+    // Events fired before the channel existed are lost, so publish
+    // whatever the account already knows.
+    if let Some(balance) = account_balance(&wallet) {
+        emit(&app, EVENT_BALANCE, balance);
+    }
+
+    loop {
+        let event = match channel.receiver.recv().await {
+            Ok(event) => event,
+            Err(e) => {
+                error!("Wallet event channel closed: {}", e);
+                break;
+            }
+        };
+
+        match *event {
+            Events::Balance { .. } => {
+                // Every active account reports here; only the selected
+                // account's balance is forwarded.
+                if let Some(balance) = account_balance(&wallet) {
+                    emit(&app, EVENT_BALANCE, balance);
+                }
+            }
+            Events::Pending { record } => {
+                info!("Pending transaction {}", record.id());
+                emit(&app, EVENT_PENDING, TransactionEvent::from(&record));
+            }
+            Events::Maturity { record } => {
+                info!("Transaction matured {}", record.id());
+                emit(&app, EVENT_MATURITY, TransactionEvent::from(&record));
+            }
+            Events::Reorg { record } => {
+                warn!("Transaction reorged {}", record.id());
+                emit(&app, EVENT_REORG, TransactionEvent::from(&record));
+            }
+            Events::UtxoProcStart => {
+                // A fresh node connection starts from empty contexts, so the
+                // selected account is rescanned across its derivation window.
+                let app = app.clone();
+                let wallet = wallet.clone();
+                async_runtime::spawn(async move {
+                    let state = app.state::<AppState>();
+                    state.scanned_accounts.lock().await.clear();
+                    if let Ok(account) = wallet.account() {
+                        if let Err(e) = accounts::scan_account(&state, &account).await {
+                            warn!("Account rescan after reconnect failed: {}", e.error);
+                        }
+                    }
+                });
+            }
+            Events::UtxoProcStop => {
+                app.state::<AppState>().scanned_accounts.lock().await.clear();
+            }
+            Events::UtxoProcError { message } | Events::Error { message } => {
+                warn!("Wallet framework error: {}", message);
+            }
+            _ => {}
+        }
+    }
+
+    channel.close();
+}
@@ -1,27 +1,209 @@
+use crate::accounts::scan_account;
 use crate::state::{AppState, ErrorResponse};
 use tauri::{command, State};
 use vecno_wallet_core::prelude::*;
-use vecno_consensus_core::tx::{TransactionId, TransactionOutpoint};
-use vecno_rpc_core::{RpcUtxosByAddressesEntry};
+use vecno_wallet_core::storage::{Binding, TransactionData, TransactionKind};
+use vecno_wallet_core::utxo::Maturity;
+use vecno_addresses::{Prefix, Version};
+use vecno_consensus_core::tx::ScriptPublicKey;
+use futures_lite::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use chrono::{Local, TimeZone};
+use log::{info, warn};
+
+const DEFAULT_PAGE_SIZE: usize = 25;
+const MAX_PAGE_SIZE: usize = 200;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Transaction {
     pub txid: String,
-    pub to_address: String,
+    pub kind: String,
     pub amount: u64,
+    pub fee: Option<u64>,
+    pub to_address: String,
+    pub block_daa_score: u64,
     pub timestamp: String,
+    pub maturity: String,
+    pub is_coinbase: bool,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TransactionPage {
+    pub transactions: Vec<Transaction>,
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Decodes the standard P2PK / P2PK-ECDSA / P2SH output scripts.
+pub(crate) fn script_address(spk: &ScriptPublicKey, prefix: Prefix) -> Option<Address> {
+    let script = spk.script();
+    match script {
+        [0x20, key @ .., 0xac] if key.len() == 32 => Some(Address::new(prefix, Version::PubKey, key)),
+        [0x21, key @ .., 0xab] if key.len() == 33 => Some(Address::new(prefix, Version::PubKeyECDSA, key)),
+        [0xaa, 0x20, hash @ .., 0x87] if hash.len() == 32 => Some(Address::new(prefix, Version::ScriptHash, hash)),
+        _ => None,
+    }
+}
+
+pub(crate) fn maturity_label(maturity: Maturity) -> &'static str {
+    match maturity {
+        Maturity::Stasis => "stasis",
+        Maturity::Pending => "pending",
+        Maturity::Confirmed => "confirmed",
+    }
+}
+
+fn format_unixtime(ts_ms: u64) -> Option<String> {
+    let ts_sec = ts_ms / 1000;
+    let ts_nsec = ((ts_ms % 1000) * 1_000_000) as u32;
+    Local
+        .timestamp_opt(ts_sec as i64, ts_nsec)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+/// The counterparty address shown for a record: the funded address for
+/// incoming records, the first non-change output for outgoing ones.
+fn record_address(record: &TransactionRecord, change_address: &Address, prefix: Prefix) -> String {
+    match record.transaction_data() {
+        TransactionData::Incoming { utxo_entries, .. }
+        | TransactionData::Reorg { utxo_entries, .. }
+        | TransactionData::Stasis { utxo_entries, .. }
+        | TransactionData::External { utxo_entries, .. } => utxo_entries
+            .iter()
+            .find_map(|utxo| utxo.address.as_ref())
+            .map(|a| a.to_string())
+            .unwrap_or_default(),
+        TransactionData::Outgoing { transaction, .. }
+        | TransactionData::Batch { transaction, .. }
+        | TransactionData::TransferIncoming { transaction, .. }
+        | TransactionData::TransferOutgoing { transaction, .. }
+        | TransactionData::Change { transaction, .. } => transaction
+            .outputs
+            .iter()
+            .filter_map(|output| script_address(&output.script_public_key, prefix))
+            .find(|address| address != change_address)
+            .map(|a| a.to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn record_fee(record: &TransactionRecord) -> Option<u64> {
+    match record.transaction_data() {
+        TransactionData::Outgoing { fees, .. }
+        | TransactionData::Batch { fees, .. }
+        | TransactionData::TransferIncoming { fees, .. }
+        | TransactionData::TransferOutgoing { fees, .. } => Some(*fees),
+        _ => None,
+    }
+}
+
+fn record_is_coinbase(record: &TransactionRecord) -> bool {
+    match record.transaction_data() {
+        TransactionData::Incoming { utxo_entries, .. }
+        | TransactionData::Reorg { utxo_entries, .. }
+        | TransactionData::Stasis { utxo_entries, .. }
+        | TransactionData::External { utxo_entries, .. } => utxo_entries.iter().any(|utxo| utxo.is_coinbase),
+        _ => false,
+    }
+}
+
+/// Every history record stored for the account on the current network.
+pub(crate) async fn load_records(
+    wallet: &Arc<Wallet>,
+    account: &Arc<dyn Account>,
+) -> Result<Vec<Arc<TransactionRecord>>, ErrorResponse> {
+    let network_id = wallet.network_id().map_err(ErrorResponse::from)?;
+    let store = wallet.store().as_transaction_record_store().map_err(ErrorResponse::from)?;
+    let binding = Binding::Account(*account.id());
+    let mut stream = store
+        .transaction_data_iter(&binding, &network_id)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to read transaction history: {}", e) })?;
+
+    let mut records = Vec::new();
+    while let Some(record) = stream.next().await {
+        let record = record.map_err(|e| ErrorResponse { error: format!("Failed to read transaction record: {}", e) })?;
+        // Stasis records are coinbase outputs caught in a reorg window and
+        // are not meant to be shown to users.
+        if record.kind() != TransactionKind::Stasis {
+            records.push(record);
+        }
+    }
+
+    Ok(records)
+}
+
+/// One page of the account's history through the store's range query, so
+/// only the records on the page are read from disk. The store returns them
+/// newest first by when they were written; within the page they are
+/// ordered by DAA score, with the txid breaking ties. Stasis records are
+/// left off the page but still counted in the total, which only differs
+/// while a reorg window is open.
+async fn load_page(
+    wallet: &Arc<Wallet>,
+    account: &Arc<dyn Account>,
+    offset: usize,
+    limit: usize,
+) -> Result<(Vec<Arc<TransactionRecord>>, usize), ErrorResponse> {
+    let network_id = wallet.network_id().map_err(ErrorResponse::from)?;
+    let store = wallet.store().as_transaction_record_store().map_err(ErrorResponse::from)?;
+    let binding = Binding::Account(*account.id());
+    let range = store
+        .load_range(&binding, &network_id, None, offset..offset + limit)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to read transaction history: {}", e) })?;
+
+    let mut records: Vec<_> = range
+        .transactions
+        .into_iter()
+        .filter(|record| record.kind() != TransactionKind::Stasis)
+        .collect();
+    records.sort_by(|a, b| {
+        b.block_daa_score()
+            .cmp(&a.block_daa_score())
+            .then_with(|| a.id().to_string().cmp(&b.id().to_string()))
+    });
+
+    Ok((records, range.total as usize))
+}
+
+/// Every address a record paid into: the funded UTXOs for incoming
+/// records, the decodable outputs for outgoing ones.
+pub(crate) fn record_output_addresses(record: &TransactionRecord, prefix: Prefix) -> Vec<Address> {
+    match record.transaction_data() {
+        TransactionData::Incoming { utxo_entries, .. }
+        | TransactionData::Reorg { utxo_entries, .. }
+        | TransactionData::Stasis { utxo_entries, .. }
+        | TransactionData::External { utxo_entries, .. } => {
+            utxo_entries.iter().filter_map(|utxo| utxo.address.clone()).collect()
+        }
+        TransactionData::Outgoing { transaction, .. }
+        | TransactionData::Batch { transaction, .. }
+        | TransactionData::TransferIncoming { transaction, .. }
+        | TransactionData::TransferOutgoing { transaction, .. }
+        | TransactionData::Change { transaction, .. } => transaction
+            .outputs
+            .iter()
+            .filter_map(|output| script_address(&output.script_public_key, prefix))
+            .collect(),
+    }
 }
 
 #[command]
-pub async fn list_transactions(state: State<'_, AppState>) -> Result<Vec<Transaction>, ErrorResponse> {
+pub async fn list_transactions(
+    offset: Option<usize>,
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<TransactionPage, ErrorResponse> {
     let wallet_guard = state.wallet.lock().await;
     let wallet = wallet_guard.as_ref().ok_or(ErrorResponse {
         error: "Wallet is not open".to_string(),
     })?.clone();
+    drop(wallet_guard);
 
     if !wallet.is_open() {
         return Err(ErrorResponse {
@@ -29,92 +211,72 @@ pub async fn list_transactions(state: State<'_, AppState>) -> Result<Vec<Transac
         });
     }
 
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+
     let account: Arc<dyn Account> = wallet.account().map_err(ErrorResponse::from)?;
-    let receive_address = account.receive_address().map_err(ErrorResponse::from)?;
+    // Records are written as the account's UTXO context discovers funds, so
+    // make sure every derived address has been scanned. Offline, the stored
+    // history is still shown.
+    if let Err(e) = scan_account(&state, &account).await {
+        warn!("list_transactions: account scan unavailable: {}", e.error);
+    }
     let change_address = account.change_address().map_err(ErrorResponse::from)?;
+    let network_id = wallet.network_id().map_err(ErrorResponse::from)?;
+    let prefix = Prefix::from(network_id);
 
-    let receive_addr = receive_address.clone();
-    let change_addr = change_address.clone();
+    let (page, total) = load_page(&wallet, &account, offset, limit).await?;
 
-    let addresses = vec![receive_address.clone(), change_address.clone()];
-    let utxos: Vec<RpcUtxosByAddressesEntry> = wallet
-        .rpc_api()
-        .get_utxos_by_addresses(addresses)
-        .await
-        .map_err(|e| ErrorResponse {
-            error: format!("Failed to fetch UTXOs: {}", e),
-        })?;
-
-    let mut tx_amounts: HashMap<TransactionId, u64> = HashMap::new();
-    let mut tx_daa: HashMap<TransactionId, u64> = HashMap::new();
-    let mut tx_to_address: HashMap<TransactionId, String> = HashMap::new();
-    let mut seen_txids: HashSet<TransactionId> = HashSet::new();
-
-    for entry in &utxos {
-        let outpoint: TransactionOutpoint = entry.outpoint.clone().into();
-        let txid = outpoint.transaction_id.clone();
-
-        let target_address = if entry.address == Some(receive_addr.clone()) {
-            receive_addr.to_string()
-        } else if entry.address == Some(change_addr.clone()) {
-            change_addr.to_string()
-        } else {
-            continue;
-        };
-
-        if seen_txids.insert(txid.clone()) {
-            let daa_score = entry.utxo_entry.block_daa_score;
-            tx_daa.insert(txid.clone(), daa_score);
-            tx_to_address.insert(txid.clone(), target_address);
-        }
-
-        *tx_amounts.entry(txid).or_insert(0) += entry.utxo_entry.amount;
-    }
-
-    let unique_daas: Vec<u64> = tx_daa.values().cloned().collect();
-    let timestamps = if !unique_daas.is_empty() {
-        wallet
+    let missing_daas: Vec<u64> = page
+        .iter()
+        .filter(|record| record.unixtime_msec().is_none())
+        .map(|record| record.block_daa_score())
+        .collect();
+    let daa_to_ts: HashMap<u64, u64> = if missing_daas.is_empty() {
+        HashMap::new()
+    } else {
+        let timestamps = wallet
             .rpc_api()
-            .get_daa_score_timestamp_estimate(unique_daas.clone())
+            .get_daa_score_timestamp_estimate(missing_daas.clone())
             .await
             .map_err(|e| ErrorResponse {
                 error: format!("Failed to fetch timestamps: {}", e),
-            })?
-    } else {
-        vec![]
+            })?;
+        missing_daas.into_iter().zip(timestamps).collect()
     };
 
-    let daa_to_ts: HashMap<u64, u64> = unique_daas.into_iter().zip(timestamps).collect();
+    let current_daa_score = wallet.current_daa_score().unwrap_or_default();
 
-    let mut transactions: Vec<(Transaction, u64)> = tx_amounts
+    let transactions = page
         .iter()
-        .filter_map(|(txid, amount)| {
-            let daa = tx_daa.get(txid)?;
-            let to_addr = tx_to_address.get(txid)?.clone();
-
-            let timestamp = daa_to_ts.get(daa).map(|&ts_ms| {
-                let ts_sec = ts_ms / 1000;
-                let ts_nsec = ((ts_ms % 1000) * 1_000_000) as u32;
-                Local
-                    .timestamp_opt(ts_sec as i64, ts_nsec)
-                    .single()
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                    .unwrap_or_else(|| format!("DAA: {}", daa))
-            }).unwrap_or_else(|| format!("DAA: {}", daa));
-
-            let transaction = Transaction {
-                txid: txid.to_string(),
-                to_address: to_addr,
-                amount: *amount,
-                timestamp,
-            };
+        .map(|record| {
+            let daa = record.block_daa_score();
+            let timestamp = record
+                .unixtime_msec()
+                .or_else(|| daa_to_ts.get(&daa).copied())
+                .and_then(format_unixtime)
+                .unwrap_or_else(|| format!("DAA: {}", daa));
 
-            Some((transaction, *daa))
+            Transaction {
+                txid: record.id().to_string(),
+                kind: record.kind().to_string(),
+                amount: record.value(),
+                fee: record_fee(record),
+                to_address: record_address(record, &change_address, prefix),
+                block_daa_score: daa,
+                timestamp,
+                maturity: maturity_label(record.maturity(current_daa_score)).to_string(),
+                is_coinbase: record_is_coinbase(record),
+            }
         })
-        .collect();
+        .collect::<Vec<_>>();
 
-    transactions.sort_by_key(|(_, daa)| std::cmp::Reverse(*daa));
-    let recent_transactions: Vec<Transaction> = transactions.into_iter().take(20).map(|(tx, _)| tx).collect();
+    info!("Loaded {} of {} transaction record(s) (offset {})", transactions.len(), total, offset);
 
-    Ok(recent_transactions)
-}
\ No newline at end of file
+    Ok(TransactionPage {
+        transactions,
+        total,
+        offset,
+        limit,
+    })
+}
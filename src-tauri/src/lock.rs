@@ -0,0 +1,241 @@
+use crate::accounts::{activate_all, load_accounts, open_wallet, scan_account};
+use crate::models::UnlockWalletInput;
+use crate::settings::{self, LockSettings};
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::open::load_key_material;
+use crate::wallet::password::{verify_password, wallet_file};
+use log::{error, info, warn};
+use serde::Serialize;
+use std::time::{Duration, Instant, SystemTime};
+use tauri::async_runtime;
+use tauri::{command, AppHandle, Emitter, Manager, State};
+use vecno_wallet_core::prelude::*;
+
+pub const EVENT_LOCKED: &str = "wallet://locked";
+pub const EVENT_UNLOCKED: &str = "wallet://unlocked";
+
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Wall-clock time passing this much faster than the poll interval means
+/// the machine was asleep in between.
+const SUSPEND_THRESHOLD: Duration = Duration::from_secs(30);
+const MAX_IDLE_MINUTES: u32 = 24 * 60;
+
+#[derive(Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LockReason {
+    Manual,
+    Idle,
+    Blur,
+    Suspend,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct LockedEvent {
+    pub reason: LockReason,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct LockStatus {
+    pub locked: bool,
+    pub wallet_name: Option<String>,
+    /// Wallet file the unlock password is checked against.
+    pub wallet_path: Option<String>,
+}
+
+/// Spawns the idle watcher that locks the open wallet once it has gone
+/// unused for the configured time, or after the machine wakes from sleep.
+/// It runs for the lifetime of the app and idles while no wallet is open.
+pub fn start(app: &AppHandle) {
+    async_runtime::spawn(run(app.clone()));
+}
+
+/// Locks on window blur when the user asked for it.
+pub fn on_blur(app: &AppHandle) {
+    if !settings::load_lock_settings(app).lock_on_blur {
+        return;
+    }
+    let app = app.clone();
+    async_runtime::spawn(async move {
+        lock(&app, LockReason::Blur).await;
+    });
+}
+
+async fn run(app: AppHandle) {
+    let mut last_tick = SystemTime::now();
+
+    loop {
+        tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+
+        let now = SystemTime::now();
+        let slept = now
+            .duration_since(last_tick)
+            .map(|elapsed| elapsed > IDLE_POLL_INTERVAL + SUSPEND_THRESHOLD)
+            .unwrap_or(false);
+        last_tick = now;
+
+        let state = app.state::<AppState>();
+        if state.wallet.lock().await.is_none() {
+            // Count idle time from when a wallet is opened, not from startup.
+            *state.last_activity.lock().await = Instant::now();
+            continue;
+        }
+
+        let lock_settings = settings::load_lock_settings(&app);
+        if slept && lock_settings.lock_on_suspend {
+            lock(&app, LockReason::Suspend).await;
+            continue;
+        }
+
+        if lock_settings.idle_minutes > 0 {
+            let timeout = Duration::from_secs(u64::from(lock_settings.idle_minutes) * 60);
+            if state.last_activity.lock().await.elapsed() >= timeout {
+                lock(&app, LockReason::Idle).await;
+            }
+        }
+    }
+}
+
+/// Wipes the wallet password, recovery phrase and seed from memory and
+/// stops every running account. The wallet itself stays open and connected
+/// so unlocking does not need a full reopen. Returns whether anything was
+/// locked.
+pub async fn lock(app: &AppHandle, reason: LockReason) -> bool {
+    let state = app.state::<AppState>();
+
+    let Some(wallet) = state.wallet.lock().await.clone() else {
+        return false;
+    };
+    {
+        let mut locked = state.locked.lock().await;
+        if *locked {
+            return false;
+        }
+        *locked = true;
+    }
+
+    if let Some(abortable) = state.compound_abort.lock().await.as_ref() {
+        abortable.abort();
+    }
+
+    *state.wallet_secret.lock().await = None;
+    *state.mnemonic.lock().await = None;
+    *state.bip39_seed.lock().await = None;
+
+    match load_accounts(&wallet).await {
+        Ok(accounts) => {
+            for account in accounts {
+                if wallet.active_accounts().get(account.id()).is_none() {
+                    continue;
+                }
+                if let Err(e) = account.stop().await {
+                    error!("Failed to stop account {} while locking: {}", account.name_or_id(), e);
+                }
+            }
+        }
+        Err(e) => error!("Failed to load accounts while locking: {}", e.error),
+    }
+    // Stopping clears each UTXO context, so every account needs a fresh
+    // scan after unlocking.
+    state.scanned_accounts.lock().await.clear();
+
+    info!("Wallet locked ({:?})", reason);
+    if let Err(e) = app.emit(EVENT_LOCKED, LockedEvent { reason }) {
+        error!("Failed to emit {}: {}", EVENT_LOCKED, e);
+    }
+    true
+}
+
+/// Resets the idle timer; the frontend calls this, throttled, on input.
+#[command]
+pub async fn record_activity(state: State<'_, AppState>) -> Result<(), ErrorResponse> {
+    *state.last_activity.lock().await = Instant::now();
+    Ok(())
+}
+
+#[command]
+pub async fn lock_wallet(app: AppHandle) -> Result<(), ErrorResponse> {
+    lock(&app, LockReason::Manual).await;
+    Ok(())
+}
+
+/// Checks the password against the wallet file, reloads the key material,
+/// restarts the accounts stopped by `lock` and rescans the selected one.
+#[command]
+pub async fn unlock_wallet(
+    input: UnlockWalletInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<(), ErrorResponse> {
+    if input.secret.is_empty() {
+        return Err(ErrorResponse { error: "Wallet password is required".into() });
+    }
+    if !*state.locked.lock().await {
+        return Ok(());
+    }
+
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let wallet_secret = Secret::new(input.secret.as_bytes().to_vec());
+    verify_password(&wallet_file(&wallet)?, &wallet_secret).await?;
+
+    let (mnemonic, bip39_seed) = load_key_material(&wallet, &wallet_secret).await?;
+
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    account
+        .start()
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
+    activate_all(&wallet).await;
+    if let Err(e) = scan_account(&state, &account).await {
+        warn!("Scan after unlock failed for {}: {}", account.name_or_id(), e.error);
+    }
+
+    *state.wallet_secret.lock().await = Some(wallet_secret);
+    *state.mnemonic.lock().await = mnemonic;
+    *state.bip39_seed.lock().await = bip39_seed;
+    *state.last_activity.lock().await = Instant::now();
+    *state.locked.lock().await = false;
+
+    info!("Wallet unlocked");
+    if let Err(e) = app.emit(EVENT_UNLOCKED, ()) {
+        error!("Failed to emit {}: {}", EVENT_UNLOCKED, e);
+    }
+    Ok(())
+}
+
+#[command]
+pub async fn get_lock_status(state: State<'_, AppState>) -> Result<LockStatus, ErrorResponse> {
+    let wallet = state.wallet.lock().await.clone();
+    let wallet_path = wallet
+        .as_ref()
+        .and_then(|wallet| wallet_file(wallet).ok())
+        .map(|path| path.to_string_lossy().into_owned());
+
+    Ok(LockStatus {
+        locked: *state.locked.lock().await,
+        wallet_name: state.wallet_name.lock().await.clone(),
+        wallet_path,
+    })
+}
+
+#[command]
+pub async fn get_lock_settings(app: AppHandle) -> Result<LockSettings, ErrorResponse> {
+    Ok(settings::load_lock_settings(&app))
+}
+
+#[command]
+pub async fn save_lock_settings(
+    lock_settings: LockSettings,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<LockSettings, ErrorResponse> {
+    if lock_settings.idle_minutes > MAX_IDLE_MINUTES {
+        return Err(ErrorResponse {
+            error: format!("Idle timeout cannot exceed {} minutes", MAX_IDLE_MINUTES),
+        });
+    }
+    settings::save_lock_settings(&app, &lock_settings)?;
+    // A shorter timeout should not fire immediately on settings the user
+    // just changed.
+    *state.last_activity.lock().await = Instant::now();
+    Ok(lock_settings)
+}
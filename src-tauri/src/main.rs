@@ -6,10 +6,24 @@ mod get_transactions;
 mod balance;
 mod node;
 mod models;
+mod events;
+mod monitor;
+mod network;
+mod settings;
+mod utxos;
+mod compound;
+mod accounts;
+mod addresses;
+mod multisig;
+mod offline;
+mod sweep;
+mod lock;
 
 use state::{AppState, NodeCache};
+use std::collections::HashSet;
+use std::time::Instant;
 use tauri::async_runtime::Mutex;
-use tauri::Manager;
+use tauri::{Manager, WindowEvent};
 use vecno_wrpc_client::prelude::Resolver;
 use vecno_wallet_core::settings::ensure_application_folder;
 
@@ -50,6 +64,7 @@ async fn main() {
 
     tauri::Builder::default()
         .setup(move |app| {
+            let network_id = settings::load_network(app.handle());
             app.manage(AppState {
                 wallet: Mutex::new(None),
                 resolver: Mutex::new(Some(resolver)),
@@ -57,27 +72,96 @@ async fn main() {
                 mnemonic: Mutex::new(None),
                 node_cache: Mutex::new(NodeCache::default()),
                 bip39_seed: Mutex::new(None),
+                network_id: Mutex::new(network_id),
+                node_monitor: Mutex::new(None),
+                wallet_events: Mutex::new(None),
+                wallet_name: Mutex::new(None),
+                frozen_utxos: Mutex::new(HashSet::new()),
+                compound_abort: Mutex::new(None),
+                scanned_accounts: Mutex::new(HashSet::new()),
+                watch_addresses: Mutex::new(Vec::new()),
+                locked: Mutex::new(false),
+                last_activity: Mutex::new(Instant::now()),
+                backup_challenge: Mutex::new(None),
             });
+            lock::start(app.handle());
             Ok(())
         })
+        .on_window_event(|window, event| {
+            if let WindowEvent::Focused(false) = event {
+                lock::on_blur(window.app_handle());
+            }
+        })
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_store::Builder::default().build())
         .invoke_handler(tauri::generate_handler![
             checks::is_wallet_open,
             node::is_node_connected,
             node::get_node_info,
             wallet::create::create_wallet,
             wallet::import::import_wallets,
+            wallet::watch_only::import_watch_only,
+            wallet::private_key::preview_private_key,
+            wallet::private_key::import_private_key,
+            wallet::password::change_wallet_password,
+            wallet::password::change_payment_secret,
+            wallet::password::payment_secret_changeable,
+            wallet::mnemonic::reveal_mnemonic,
+            wallet::mnemonic::mnemonic_backup_pending,
+            wallet::mnemonic::get_backup_challenge,
+            wallet::mnemonic::confirm_mnemonic_backup,
+            wallet::mnemonic::detect_mnemonic_language,
             checks::generate_mnemonic,
             checks::get_address,
             balance::get_balance,
             send_transactions::send_transaction,
+            send_transactions::estimate_send,
+            send_transactions::send_batch,
             checks::verify_wallet_password,
             checks::list_wallets,
             get_transactions::list_transactions,
+            utxos::list_utxos,
+            utxos::set_utxos_frozen,
+            compound::preview_compound,
+            compound::compound_utxos,
+            compound::cancel_compound,
+            accounts::list_accounts,
+            accounts::create_account,
+            accounts::rename_account,
+            accounts::select_account,
+            addresses::list_addresses,
+            addresses::new_receive_address,
+            addresses::set_address_label,
+            multisig::get_multisig_xpub,
+            multisig::create_multisig_account,
+            multisig::create_partial_transaction,
+            multisig::sign_partial_transaction,
+            multisig::combine_partial_transactions,
+            multisig::submit_partial_transaction,
+            offline::build_unsigned_transaction,
+            offline::review_transaction_file,
+            offline::sign_transaction_file,
+            offline::submit_transaction_file,
+            offline::save_transaction_file,
+            sweep::scan_private_key,
+            sweep::sweep_private_key,
+            lock::record_activity,
+            lock::lock_wallet,
+            lock::unlock_wallet,
+            lock::get_lock_status,
+            lock::get_lock_settings,
+            lock::save_lock_settings,
             wallet::open::open_wallet,
             wallet::close::close_wallet,
             wallet::switch::switch_wallet,
-            checks::wallet_needs_payment_secret
+            checks::wallet_needs_payment_secret,
+            checks::is_watch_only,
+            network::get_network,
+            network::set_network,
+            network::list_networks,
+            node::get_node_settings,
+            node::save_node_settings,
+            node::test_node_connection
         ])
         .run(tauri::generate_context!())
         .expect("Error running Vecno Wallet App");
@@ -4,6 +4,20 @@ pub struct CreateWalletInput {
     pub secret: String,
     pub filename: String,
     pub payment_secret: Option<String>,
+    /// 12 or 24; 24 when left out.
+    #[serde(default)]
+    pub word_count: Option<usize>,
+    /// BIP39 wordlist code such as `spanish`; English when left out.
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct GenerateMnemonicInput {
+    #[serde(default)]
+    pub word_count: Option<usize>,
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 #[derive(serde::Deserialize, Debug)]
@@ -13,6 +27,16 @@ pub struct OpenWalletInput {
     pub payment_secret: Option<String>,
 }
 
+#[derive(serde::Deserialize, Debug)]
+pub struct ImportWatchOnlyInput {
+    pub xpub: String,
+    /// Extra addresses to monitor alongside the xpub's derivation.
+    #[serde(default)]
+    pub addresses: Option<Vec<String>>,
+    pub secret: String,
+    pub filename: String,
+}
+
 #[derive(serde::Deserialize, Debug)]
 pub struct ImportWalletInput {
     pub mnemonic: String,
@@ -21,10 +45,229 @@ pub struct ImportWalletInput {
     pub filename: String,
 }
 
+/// Fee tier picked by the user; maps onto the node's fee-rate buckets.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FeePriority {
+    Low,
+    #[default]
+    Normal,
+    Priority,
+}
+
 #[derive(serde::Deserialize, Debug)]
 pub struct SendTransactionInput {
+    pub to_address: String,
+    #[serde(default)]
+    pub amount: u64,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+    /// Spend every mature UTXO with fees deducted from the output;
+    /// `amount` is ignored.
+    #[serde(default)]
+    pub sweep: bool,
+    /// Outpoints (`txid:index`) to spend exclusively; `None` lets the
+    /// wallet pick from every unfrozen UTXO.
+    #[serde(default)]
+    pub utxos: Option<Vec<String>>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct EstimateSendInput {
+    pub to_address: String,
+    #[serde(default)]
+    pub amount: u64,
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+    #[serde(default)]
+    pub sweep: bool,
+    #[serde(default)]
+    pub utxos: Option<Vec<String>>,
+}
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct PaymentOutputInput {
+    pub to_address: String,
+    pub amount: u64,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SendBatchInput {
+    pub outputs: Vec<PaymentOutputInput>,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+    #[serde(default)]
+    pub utxos: Option<Vec<String>>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct FreezeUtxosInput {
+    pub outpoints: Vec<String>,
+    pub frozen: bool,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CompoundInput {
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+    /// Only UTXOs strictly below this amount (in veni) are merged.
+    #[serde(default)]
+    pub max_utxo_amount: Option<u64>,
+    #[serde(default)]
+    pub utxos: Option<Vec<String>>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CreateAccountInput {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct RenameAccountInput {
+    pub account_id: String,
+    pub name: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct AddressLabelInput {
+    pub address: String,
+    /// An empty label removes it.
+    pub label: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MultisigXpubInput {
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CreateMultisigInput {
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Extended public keys of the other cosigners.
+    pub cosigner_xpubs: Vec<String>,
+    pub minimum_signatures: u16,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct PartialSendInput {
     pub to_address: String,
     pub amount: u64,
     #[serde(default)]
     pub payment_secret: Option<String>,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct PartialSignInput {
+    pub pskb: String,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CombinePartialInput {
+    pub pskbs: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SignTransactionFileInput {
+    pub data: String,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+/// Encoding of a transaction file. Borsh bytes are written as hex so the
+/// file stays text and can be pasted like the JSON form.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionFileFormat {
+    #[default]
+    Json,
+    Borsh,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SaveTransactionFileInput {
+    pub data: String,
+    pub filename: String,
+    /// Format to write; the data is re-encoded when it differs.
+    #[serde(default)]
+    pub format: TransactionFileFormat,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ImportPrivateKeyInput {
+    /// Raw secp256k1 secret key as 64 hex characters.
+    pub private_key: String,
+    pub secret: String,
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+    pub filename: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SweepPrivateKeyInput {
+    pub private_key: String,
+    #[serde(default)]
+    pub fee_priority: Option<FeePriority>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ChangeWalletPasswordInput {
+    pub old_secret: String,
+    pub new_secret: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ChangePaymentSecretInput {
+    /// Wallet password, checked against the file before anything is rewritten.
+    pub secret: String,
+    #[serde(default)]
+    pub old_payment_secret: Option<String>,
+    /// Leaving this empty removes the payment secret.
+    #[serde(default)]
+    pub new_payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct UnlockWalletInput {
+    pub secret: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct RevealMnemonicInput {
+    /// Wallet password, checked against the file on every reveal.
+    pub secret: String,
+    /// Needed when the phrase was stored under a payment secret.
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ConfirmMnemonicBackupInput {
+    /// Answers in the order of the positions returned by
+    /// `get_backup_challenge`.
+    pub words: Vec<String>,
+    /// Needed when the phrase was stored under a payment secret and this
+    /// session no longer holds it.
+    #[serde(default)]
+    pub payment_secret: Option<String>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct DetectMnemonicInput {
+    pub mnemonic: String,
+}
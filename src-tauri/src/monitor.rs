@@ -0,0 +1,201 @@
+use crate::state::AppState;
+use log::{error, info, warn};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::async_runtime;
+use tauri::{AppHandle, Emitter, Manager};
+use vecno_consensus_core::network::NetworkId;
+use vecno_rpc_core::api::ctl::RpcState;
+use vecno_wallet_core::prelude::*;
+use vecno_wrpc_client::prelude::{ConnectOptions, ConnectStrategy, RpcApi};
+use vecno_wrpc_client::VecnoRpcClient;
+
+pub const EVENT_CONNECTED: &str = "node://connected";
+pub const EVENT_DISCONNECTED: &str = "node://disconnected";
+pub const EVENT_SYNCING: &str = "node://syncing";
+
+const RECONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const RECONNECT_BACKOFF_INITIAL: Duration = Duration::from_secs(2);
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(60);
+const SYNC_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct NodeStatusEvent {
+    pub url: Option<String>,
+    pub network: Option<String>,
+    pub server_version: Option<String>,
+    pub is_synced: bool,
+    pub virtual_daa_score: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct NodeDisconnectedEvent {
+    pub url: Option<String>,
+    pub attempt: u32,
+    pub retry_in_secs: u64,
+}
+
+/// Spawns the connection monitor for a freshly bound wallet, replacing
+/// any monitor left over from a previous session.
+pub async fn start(app: &AppHandle, wallet: &Arc<Wallet>, network_id: NetworkId) {
+    let Some(client) = wallet.try_wrpc_client() else {
+        warn!("Connection monitor not started: wallet has no wRPC client");
+        return;
+    };
+
+    let state = app.state::<AppState>();
+
+    // Resolver-backed sessions reconnect through the resolver so a dead
+    // public node gets replaced instead of retried forever.
+    let reconnect_url = {
+        let cache = state.node_cache.lock().await;
+        if cache.from_resolver { None } else { cache.url.clone() }
+    };
+
+    let handle = async_runtime::spawn(run(app.clone(), client, network_id, reconnect_url));
+
+    if let Some(previous) = state.node_monitor.lock().await.replace(handle) {
+        previous.abort();
+    }
+}
+
+/// Stops the monitor before the client is disconnected on purpose,
+/// so a user-initiated close is not mistaken for a dropped socket.
+pub async fn stop(state: &AppState) {
+    if let Some(handle) = state.node_monitor.lock().await.take() {
+        handle.abort();
+        info!("Connection monitor stopped");
+    }
+}
+
+async fn run(app: AppHandle, client: Arc<VecnoRpcClient>, network_id: NetworkId, reconnect_url: Option<String>) {
+    let channel = client.ctl().multiplexer().channel();
+    info!("Connection monitor started");
+
+    let mut synced = refresh(&app, &client).await.unwrap_or(false);
+
+    loop {
+        let sync_tick = async {
+            if synced {
+                std::future::pending::<()>().await
+            } else {
+                tokio::time::sleep(SYNC_POLL_INTERVAL).await
+            }
+        };
+
+        tokio::select! {
+            msg = channel.receiver.recv() => match msg {
+                Ok(RpcState::Opened) => {
+                    info!("Node connection opened");
+                    synced = refresh(&app, &client).await.unwrap_or(false);
+                }
+                Ok(RpcState::Closed) => {
+                    warn!("Node connection closed");
+                    synced = false;
+                    reconnect(&app, &client, network_id, reconnect_url.as_deref()).await;
+                }
+                Err(e) => {
+                    error!("Connection monitor channel closed: {}", e);
+                    break;
+                }
+            },
+            _ = sync_tick => {
+                if client.is_connected() {
+                    synced = refresh(&app, &client).await.unwrap_or(false);
+                }
+            }
+        }
+    }
+
+    channel.close();
+}
+
+/// Reads the server info and publishes it; returns whether the node is synced.
+async fn refresh(app: &AppHandle, client: &Arc<VecnoRpcClient>) -> Option<bool> {
+    let server_info = match client.get_server_info().await {
+        Ok(info) => info,
+        Err(e) => {
+            warn!("Failed to read server info: {}", e);
+            return None;
+        }
+    };
+
+    let state = app.state::<AppState>();
+    let payload = {
+        let mut cache = state.node_cache.lock().await;
+        if let Some(url) = client.url() {
+            cache.url = Some(url);
+        }
+        cache.connected = true;
+        cache.is_synced = server_info.is_synced;
+        cache.server_version = Some(server_info.server_version.clone());
+        cache.network = Some(server_info.network_id.to_string());
+
+        NodeStatusEvent {
+            url: cache.url.clone(),
+            network: cache.network.clone(),
+            server_version: cache.server_version.clone(),
+            is_synced: server_info.is_synced,
+            virtual_daa_score: server_info.virtual_daa_score,
+        }
+    };
+
+    let event = if server_info.is_synced { EVENT_CONNECTED } else { EVENT_SYNCING };
+    if let Err(e) = app.emit(event, payload) {
+        error!("Failed to emit {}: {}", event, e);
+    }
+
+    Some(server_info.is_synced)
+}
+
+async fn reconnect(app: &AppHandle, client: &Arc<VecnoRpcClient>, network_id: NetworkId, url: Option<&str>) {
+    let state = app.state::<AppState>();
+    let last_url = {
+        let mut cache = state.node_cache.lock().await;
+        cache.connected = false;
+        cache.is_synced = false;
+        cache.url.clone()
+    };
+
+    let mut delay = RECONNECT_BACKOFF_INITIAL;
+    let mut attempt: u32 = 0;
+
+    loop {
+        attempt += 1;
+        let payload = NodeDisconnectedEvent {
+            url: last_url.clone(),
+            attempt,
+            retry_in_secs: delay.as_secs(),
+        };
+        if let Err(e) = app.emit(EVENT_DISCONNECTED, payload) {
+            error!("Failed to emit {}: {}", EVENT_DISCONNECTED, e);
+        }
+
+        tokio::time::sleep(delay).await;
+
+        if client.is_connected() {
+            info!("Node connection restored by the client");
+            return;
+        }
+
+        info!("Reconnect attempt {} on {}", attempt, network_id);
+        let options = ConnectOptions {
+            block_async_connect: true,
+            strategy: ConnectStrategy::Fallback,
+            url: url.map(str::to_string),
+            connect_timeout: Some(RECONNECT_TIMEOUT),
+            ..Default::default()
+        };
+
+        match client.connect(Some(options)).await {
+            Ok(_) => {
+                info!("Reconnected after {} attempt(s)", attempt);
+                return;
+            }
+            Err(e) => warn!("Reconnect attempt {} failed: {}", attempt, e),
+        }
+
+        delay = (delay * 2).min(RECONNECT_BACKOFF_MAX);
+    }
+}
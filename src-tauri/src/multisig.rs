@@ -0,0 +1,382 @@
+use crate::accounts::{is_multisig, open_wallet, scan_account, selected_key_data_id, AccountInfo};
+use crate::get_transactions::script_address;
+use crate::models::{CombinePartialInput, CreateMultisigInput, MultisigXpubInput, PartialSendInput, PartialSignInput};
+use crate::send_transactions::{fee_rate_for, fetch_fee_tiers, parse_destination};
+use crate::state::{AppState, ErrorResponse};
+use log::{info, warn};
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{command, State};
+use vecno_addresses::Prefix;
+use vecno_bip32::Prefix as KeyPrefix;
+use vecno_wallet_core::account::MULTISIG_ACCOUNT_KIND;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_pskt::prelude::{Bundle, Combiner, Inner, PSKT};
+use workflow_core::prelude::Abortable;
+
+/// Upper bound on cosigners so the redeem script stays within the
+/// standard script size.
+const MAX_COSIGNERS: usize = 20;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PartialOutput {
+    pub address: Option<String>,
+    pub amount: u64,
+    pub change: bool,
+}
+
+/// A partially signed transaction bundle together with what the UI needs
+/// to review it and tell how many signatures are still missing.
+#[derive(Serialize, Clone, Debug)]
+pub struct PartialTransaction {
+    /// Serialized bundle to hand to the next cosigner.
+    pub pskb: String,
+    pub transactions: usize,
+    pub inputs: usize,
+    pub outputs: Vec<PartialOutput>,
+    pub total_input: u64,
+    pub fees: u64,
+    /// Signatures present on the least signed input.
+    pub signatures: usize,
+    pub required_signatures: u16,
+    pub complete: bool,
+}
+
+fn secret_from(value: Option<&str>) -> Option<Secret> {
+    value.map(str::trim).filter(|s| !s.is_empty()).map(Secret::from)
+}
+
+async fn wallet_secret(state: &AppState) -> Result<Secret, ErrorResponse> {
+    state
+        .wallet_secret
+        .lock()
+        .await
+        .clone()
+        .ok_or(ErrorResponse { error: "Wallet secret not loaded".into() })
+}
+
+/// The selected account, refusing anything that is not a multisig one.
+fn multisig_account(wallet: &Arc<Wallet>) -> Result<Arc<dyn Account>, ErrorResponse> {
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    if !is_multisig(&account) {
+        return Err(ErrorResponse { error: "The selected account is not a multisig account".into() });
+    }
+    Ok(account)
+}
+
+fn parse_bundle(pskb: &str) -> Result<Bundle, ErrorResponse> {
+    Bundle::deserialize(pskb.trim())
+        .map_err(|e| ErrorResponse { error: format!("Invalid partially signed transaction: {}", e) })
+}
+
+/// Extended public key of the local key for the multisig derivation,
+/// which is what the other cosigners need to build the same account.
+async fn local_xpub(
+    wallet: &Arc<Wallet>,
+    wallet_secret: &Secret,
+    payment_secret: Option<&Secret>,
+) -> Result<String, ErrorResponse> {
+    let prv_key_data_id = selected_key_data_id(wallet)?;
+    let prv_key_data = wallet
+        .get_prv_key_data(wallet_secret, &prv_key_data_id)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to load PrvKeyData: {e}") })?
+        .ok_or(ErrorResponse { error: "PrvKeyData not found".into() })?;
+
+    let xpub = prv_key_data
+        .create_xpub(payment_secret, MULTISIG_ACCOUNT_KIND.into(), 0)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to derive the cosigner key: {e}") })?;
+
+    Ok(xpub.to_string(Some(KeyPrefix::XPUB)))
+}
+
+fn summarize(
+    bundle: &Bundle,
+    account: &Arc<dyn Account>,
+    prefix: Prefix,
+) -> Result<PartialTransaction, ErrorResponse> {
+    let change_address = account.change_address().ok();
+
+    let mut inputs = 0;
+    let mut total_input = 0;
+    let mut total_output = 0;
+    let mut signatures: Option<usize> = None;
+    let mut outputs = Vec::new();
+
+    for inner in bundle.iter() {
+        for input in inner.inputs.iter() {
+            inputs += 1;
+            total_input += input.utxo_entry.as_ref().map(|entry| entry.amount).unwrap_or_default();
+            let count = input.partial_sigs.len();
+            signatures = Some(signatures.map_or(count, |least| least.min(count)));
+        }
+        for output in inner.outputs.iter() {
+            total_output += output.amount;
+            let address = script_address(&output.script_public_key, prefix);
+            outputs.push(PartialOutput {
+                change: address.is_some() && address == change_address,
+                address: address.map(|a| a.to_string()),
+                amount: output.amount,
+            });
+        }
+    }
+
+    let signatures = signatures.unwrap_or_default();
+    let required_signatures = account.minimum_signatures();
+
+    Ok(PartialTransaction {
+        pskb: bundle.serialize().map_err(|e| ErrorResponse { error: e.to_string() })?,
+        transactions: bundle.iter().count(),
+        inputs,
+        outputs,
+        total_input,
+        fees: total_input.saturating_sub(total_output),
+        signatures,
+        required_signatures,
+        complete: inputs > 0 && signatures >= required_signatures as usize,
+    })
+}
+
+/// The multisig cosigner key of the local wallet, to share with the
+/// other cosigners before the account is created.
+#[command]
+pub async fn get_multisig_xpub(input: MultisigXpubInput, state: State<'_, AppState>) -> Result<String, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let wallet_secret = wallet_secret(&state).await?;
+    let payment_secret = secret_from(input.payment_secret.as_deref());
+    local_xpub(&wallet, &wallet_secret, payment_secret.as_ref()).await
+}
+
+/// Creates an m-of-n multisig account from the key behind the selected
+/// account plus the extended public keys of the other cosigners.
+#[command]
+pub async fn create_multisig_account(
+    input: CreateMultisigInput,
+    state: State<'_, AppState>,
+) -> Result<AccountInfo, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let prv_key_data_id = selected_key_data_id(&wallet)?;
+
+    let name = input.name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let payment_secret = secret_from(input.payment_secret.as_deref());
+    let wallet_secret = wallet_secret(&state).await?;
+
+    let own_xpub = local_xpub(&wallet, &wallet_secret, payment_secret.as_ref()).await?;
+
+    let mut cosigner_xpubs: Vec<String> = Vec::new();
+    for xpub in input.cosigner_xpubs.iter().map(|x| x.trim()).filter(|x| !x.is_empty()) {
+        if xpub == own_xpub {
+            return Err(ErrorResponse { error: "The list contains this wallet's own cosigner key".into() });
+        }
+        if cosigner_xpubs.iter().any(|known| known == xpub) {
+            return Err(ErrorResponse { error: format!("Cosigner key {} is listed twice", xpub) });
+        }
+        cosigner_xpubs.push(xpub.to_string());
+    }
+
+    if cosigner_xpubs.is_empty() {
+        return Err(ErrorResponse { error: "At least one cosigner extended public key is required".into() });
+    }
+
+    let total = cosigner_xpubs.len() + 1;
+    if total > MAX_COSIGNERS {
+        return Err(ErrorResponse { error: format!("A multisig account supports at most {} cosigners", MAX_COSIGNERS) });
+    }
+    if input.minimum_signatures == 0 || input.minimum_signatures as usize > total {
+        return Err(ErrorResponse {
+            error: format!("Required signatures must be between 1 and {}", total),
+        });
+    }
+
+    let account = {
+        let guard_mutex = wallet.guard();
+        let guard = guard_mutex.lock().await;
+        let account_args = AccountCreateArgs::new_multisig(
+            vec![PrvKeyDataArgs::new(prv_key_data_id, payment_secret)],
+            cosigner_xpubs,
+            name,
+            input.minimum_signatures,
+        );
+        wallet
+            .create_account(&wallet_secret, account_args, true, &guard)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Multisig account creation failed: {}", e) })?
+    };
+
+    account
+        .clone()
+        .start()
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
+
+    info!(
+        "Created {}-of-{} multisig account {}",
+        input.minimum_signatures,
+        total,
+        account.name_with_id()
+    );
+    Ok(AccountInfo::new(&account, false))
+}
+
+/// Builds a payment from the selected multisig account and adds the local
+/// signatures. The result is passed on to the other cosigners.
+#[command]
+pub async fn create_partial_transaction(
+    input: PartialSendInput,
+    state: State<'_, AppState>,
+) -> Result<PartialTransaction, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = multisig_account(&wallet)?;
+    let network_id = wallet.network_id()?;
+
+    if input.amount == 0 {
+        return Err(ErrorResponse { error: "Amount must be greater than 0".into() });
+    }
+    let target_address = parse_destination(&input.to_address, network_id)?;
+
+    scan_account(&state, &account).await?;
+
+    let tiers = fetch_fee_tiers(wallet.rpc_api().as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, input.fee_priority.unwrap_or_default());
+
+    let wallet_secret = wallet_secret(&state).await?;
+    let payment_secret = secret_from(input.payment_secret.as_deref());
+    let destination = PaymentDestination::PaymentOutputs(PaymentOutputs {
+        outputs: vec![PaymentOutput::new(target_address, input.amount)],
+    });
+
+    let abortable = Abortable::default();
+    let bundle = account
+        .clone()
+        .pskb_from_send_generator(
+            destination,
+            fee_rate,
+            Fees::SenderPays(0),
+            None,
+            wallet_secret.clone(),
+            payment_secret.clone(),
+            &abortable,
+        )
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to build transaction: {}", e) })?;
+
+    let bundle = account
+        .clone()
+        .pskb_sign(&bundle, wallet_secret, payment_secret, None)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Signing failed: {}", e) })?;
+
+    let partial = summarize(&bundle, &account, Prefix::from(network_id))?;
+    info!(
+        "Created partially signed transaction: {} tx(s), {}/{} signatures",
+        partial.transactions, partial.signatures, partial.required_signatures
+    );
+    Ok(partial)
+}
+
+/// Adds the local cosigner signatures to a bundle received from another
+/// cosigner.
+#[command]
+pub async fn sign_partial_transaction(
+    input: PartialSignInput,
+    state: State<'_, AppState>,
+) -> Result<PartialTransaction, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = multisig_account(&wallet)?;
+    let bundle = parse_bundle(&input.pskb)?;
+
+    let wallet_secret = wallet_secret(&state).await?;
+    let payment_secret = secret_from(input.payment_secret.as_deref());
+
+    let bundle = account
+        .clone()
+        .pskb_sign(&bundle, wallet_secret, payment_secret, None)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Signing failed: {}", e) })?;
+
+    let partial = summarize(&bundle, &account, Prefix::from(wallet.network_id()?))?;
+    info!("Signed partial transaction: {}/{} signatures", partial.signatures, partial.required_signatures);
+    Ok(partial)
+}
+
+/// Merges the signatures of bundles that cosigners signed independently
+/// from the same unsigned transaction.
+#[command]
+pub async fn combine_partial_transactions(
+    input: CombinePartialInput,
+    state: State<'_, AppState>,
+) -> Result<PartialTransaction, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = multisig_account(&wallet)?;
+
+    let bundles = input
+        .pskbs
+        .iter()
+        .filter(|pskb| !pskb.trim().is_empty())
+        .map(|pskb| parse_bundle(pskb))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let Some((first, rest)) = bundles.split_first() else {
+        return Err(ErrorResponse { error: "Nothing to combine".into() });
+    };
+
+    let mut combined: Vec<Inner> = first.iter().cloned().collect();
+    for bundle in rest {
+        if bundle.iter().count() != combined.len() {
+            return Err(ErrorResponse { error: "The bundles do not describe the same transactions".into() });
+        }
+        for (slot, other) in combined.iter_mut().zip(bundle.iter()) {
+            let merged = (PSKT::<Combiner>::from(slot.clone()) + PSKT::<Combiner>::from(other.clone()))
+                .map_err(|e| ErrorResponse { error: format!("Failed to combine signatures: {}", e) })?;
+            *slot = (*merged).clone();
+        }
+    }
+
+    let mut bundle = Bundle::new();
+    for inner in combined {
+        bundle.add_inner(inner);
+    }
+
+    let partial = summarize(&bundle, &account, Prefix::from(wallet.network_id()?))?;
+    info!(
+        "Combined {} partial transaction(s): {}/{} signatures",
+        bundles.len(),
+        partial.signatures,
+        partial.required_signatures
+    );
+    Ok(partial)
+}
+
+/// Finalizes a bundle that carries enough signatures and broadcasts it.
+#[command]
+pub async fn submit_partial_transaction(pskb: String, state: State<'_, AppState>) -> Result<Vec<String>, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = multisig_account(&wallet)?;
+    let bundle = parse_bundle(&pskb)?;
+
+    let partial = summarize(&bundle, &account, Prefix::from(wallet.network_id()?))?;
+    if !partial.complete {
+        return Err(ErrorResponse {
+            error: format!(
+                "Not enough signatures: {} of {} required",
+                partial.signatures, partial.required_signatures
+            ),
+        });
+    }
+
+    let txids = account
+        .clone()
+        .pskb_broadcast(&bundle)
+        .await
+        .map_err(|e| {
+            warn!("Partial transaction broadcast failed: {}", e);
+            ErrorResponse { error: format!("Submit failed: {}", e) }
+        })?
+        .into_iter()
+        .map(|txid| txid.to_string())
+        .collect::<Vec<_>>();
+
+    info!("Submitted multisig transaction(s): {}", txids.join(", "));
+    Ok(txids)
+}
@@ -0,0 +1,53 @@
+use crate::settings;
+use crate::state::{AppState, ErrorResponse, NodeCache};
+use log::info;
+use tauri::{command, AppHandle, State};
+use vecno_consensus_core::network::NetworkId;
+
+pub const SUPPORTED_NETWORKS: &[&str] = &["mainnet", "testnet-10", "devnet", "simnet"];
+
+pub fn parse_network(network: &str) -> Result<NetworkId, ErrorResponse> {
+    let network = network.trim();
+    if !SUPPORTED_NETWORKS.contains(&network) {
+        return Err(ErrorResponse {
+            error: format!("Unsupported network: {}", network),
+        });
+    }
+    network.parse::<NetworkId>().map_err(|e| ErrorResponse {
+        error: format!("Invalid network '{}': {}", network, e),
+    })
+}
+
+#[command]
+pub async fn get_network(state: State<'_, AppState>) -> Result<String, ErrorResponse> {
+    let network_id = *state.network_id.lock().await;
+    Ok(network_id.to_string())
+}
+
+#[command]
+pub async fn list_networks() -> Result<Vec<String>, ErrorResponse> {
+    Ok(SUPPORTED_NETWORKS.iter().map(|n| n.to_string()).collect())
+}
+
+#[command]
+pub async fn set_network(
+    network: String,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<String, ErrorResponse> {
+    let network_id = parse_network(&network)?;
+
+    if state.wallet.lock().await.is_some() {
+        return Err(ErrorResponse {
+            error: "Close the current wallet before switching networks".into(),
+        });
+    }
+
+    settings::save_network(&app, network_id)?;
+
+    *state.network_id.lock().await = network_id;
+    *state.node_cache.lock().await = NodeCache::default();
+
+    info!("Active network set to {}", network_id);
+    Ok(network_id.to_string())
+}
@@ -1,19 +1,190 @@
-use crate::state::{AppState, ErrorResponse, NodeInfo};
-use tauri::{command, State};
-use vecno_consensus_core::network::{NetworkId, NetworkType};
-use vecno_wrpc_client::prelude::WrpcEncoding;
-use log::{error, info};
+use crate::settings::{self, NodeEncoding, NodeSettings};
+use crate::state::{AppState, ErrorResponse, NodeCache, NodeInfo};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{command, AppHandle, State};
+use vecno_consensus_core::network::NetworkId;
+use vecno_rpc_core::GetServerInfoResponse;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::rpc::Rpc;
+use vecno_wrpc_client::prelude::{ConnectOptions, ConnectStrategy, RpcApi, WrpcEncoding};
+use vecno_wrpc_client::{Resolver, VecnoRpcClient};
+use log::{error, info, warn};
 
-#[command]
-pub async fn is_node_connected(state: State<'_, AppState>) -> Result<bool, ErrorResponse> {
-    {
-        let cache_guard = state.node_cache.lock().await;
-        if cache_guard.url.is_some() {
-            info!("Node already known to be connected (cached).");
-            return Ok(true);
+const CUSTOM_NODE_CONNECT_TIMEOUT: Duration = Duration::from_secs(8);
+
+#[derive(Serialize, Clone, Debug)]
+pub struct NodeTestResult {
+    pub success: bool,
+    pub url: String,
+    pub server_version: Option<String>,
+    pub network: Option<String>,
+    pub is_synced: bool,
+    pub has_utxo_index: bool,
+    pub virtual_daa_score: u64,
+    pub error: Option<String>,
+}
+
+fn validate_url(url: &str) -> Result<String, ErrorResponse> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(ErrorResponse { error: "Node URL is required".into() });
+    }
+    if !(url.starts_with("ws://") || url.starts_with("wss://")) {
+        return Err(ErrorResponse { error: "Node URL must start with ws:// or wss://".into() });
+    }
+    Ok(url.to_string())
+}
+
+/// Connects the client and reads the server info, rejecting nodes
+/// that serve a different network than the one requested.
+async fn handshake(
+    client: &VecnoRpcClient,
+    url: &str,
+    network_id: NetworkId,
+) -> Result<GetServerInfoResponse, ErrorResponse> {
+    let options = ConnectOptions {
+        block_async_connect: true,
+        strategy: ConnectStrategy::Fallback,
+        url: Some(url.to_string()),
+        connect_timeout: Some(CUSTOM_NODE_CONNECT_TIMEOUT),
+        ..Default::default()
+    };
+
+    client.connect(Some(options)).await.map_err(|e| ErrorResponse {
+        error: format!("Connection failed: {}", e),
+    })?;
+
+    let server_info = match client.get_server_info().await {
+        Ok(info) => info,
+        Err(e) => {
+            let _ = client.disconnect().await;
+            return Err(ErrorResponse { error: format!("Handshake failed: {}", e) });
+        }
+    };
+
+    if server_info.network_id.to_string() != network_id.to_string() {
+        let _ = client.disconnect().await;
+        return Err(ErrorResponse {
+            error: format!("Node is on {} but the wallet expects {}", server_info.network_id, network_id),
+        });
+    }
+
+    Ok(server_info)
+}
+
+fn node_record(
+    url: String,
+    encoding: NodeEncoding,
+    from_resolver: bool,
+    server_info: &GetServerInfoResponse,
+) -> NodeCache {
+    NodeCache {
+        url: Some(url),
+        encoding: Some(encoding),
+        from_resolver,
+        server_version: Some(server_info.server_version.clone()),
+        network: Some(server_info.network_id.to_string()),
+        is_synced: server_info.is_synced,
+        connected: true,
+    }
+}
+
+/// Builds a wallet bound to the first reachable endpoint: custom endpoints
+/// are tried in order, then the public resolver if it is enabled.
+pub async fn connect_wallet(
+    store: Arc<dyn Interface>,
+    node_settings: &NodeSettings,
+    network_id: NetworkId,
+) -> Result<(Arc<Wallet>, NodeCache), ErrorResponse> {
+    let mut last_error: Option<String> = None;
+
+    for endpoint in &node_settings.endpoints {
+        let client = Arc::new(
+            VecnoRpcClient::new_with_args(endpoint.encoding.into(), Some(endpoint.url.as_str()), None, Some(network_id), None)
+                .map_err(|e| ErrorResponse { error: format!("Invalid node URL {}: {}", endpoint.url, e) })?,
+        );
+        let rpc = Rpc::new(client.clone(), client.ctl().clone());
+        let wallet = Arc::new(
+            Wallet::try_with_rpc(Some(rpc), store.clone(), Some(network_id))
+                .map_err(|e| ErrorResponse { error: format!("Wallet init failed: {}", e) })?,
+        );
+
+        info!("Trying custom node {} ({:?})", endpoint.url, endpoint.encoding);
+        match handshake(&client, &endpoint.url, network_id).await {
+            Ok(server_info) => {
+                info!("Connected to custom node {}", endpoint.url);
+                let node = node_record(endpoint.url.clone(), endpoint.encoding, false, &server_info);
+                return Ok((wallet, node));
+            }
+            Err(e) => {
+                warn!("Custom node {} unavailable: {}", endpoint.url, e.error);
+                last_error = Some(e.error);
+            }
         }
     }
 
+    if !node_settings.use_resolver {
+        error!("No custom node reachable and resolver disabled");
+        return Err(ErrorResponse {
+            error: match last_error {
+                Some(e) => format!("None of the configured nodes are reachable. Last error: {}", e),
+                None => "No node endpoints configured and the public resolver is disabled".into(),
+            },
+        });
+    }
+
+    let resolver = Resolver::default();
+    let url = resolver
+        .get_url(WrpcEncoding::Borsh, network_id)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Node resolve failed: {}", e) })?;
+
+    let wallet = Arc::new(
+        Wallet::try_new(store, Some(resolver), Some(network_id))
+            .map_err(|e| ErrorResponse { error: format!("Wallet init failed: {}", e) })?,
+    );
+
+    let wrpc = wallet
+        .try_wrpc_client()
+        .ok_or_else(|| ErrorResponse { error: "No wRPC client available".into() })?;
+
+    let server_info = handshake(&wrpc, &url, network_id)
+        .await
+        .map_err(|e| {
+            error!("Node connection failed: {}", e.error);
+            ErrorResponse {
+                error: "Failed to connect to Vecno node. Check your internet connection or try again later.".into(),
+            }
+        })?;
+    let node = node_record(url, NodeEncoding::Borsh, true, &server_info);
+
+    info!("Connected to resolver node {:?}", node.url);
+    Ok((wallet, node))
+}
+
+/// Picks the URL that would be used without opening a connection.
+async fn resolve_candidate(
+    state: &AppState,
+    node_settings: &NodeSettings,
+    network_id: NetworkId,
+) -> Result<NodeCache, ErrorResponse> {
+    if let Some(endpoint) = node_settings.endpoints.first() {
+        return Ok(NodeCache {
+            url: Some(endpoint.url.clone()),
+            encoding: Some(endpoint.encoding),
+            network: Some(network_id.to_string()),
+            ..Default::default()
+        });
+    }
+
+    if !node_settings.use_resolver {
+        return Err(ErrorResponse {
+            error: "No node endpoints configured and the public resolver is disabled".into(),
+        });
+    }
+
     let guard = state.resolver.lock().await;
     let resolver = guard.as_ref().ok_or_else(|| {
         let msg = "Resolver not initialized";
@@ -21,23 +192,19 @@ pub async fn is_node_connected(state: State<'_, AppState>) -> Result<bool, Error
         ErrorResponse { error: msg.to_string() }
     })?;
 
-    let network_id = NetworkId::new(NetworkType::Mainnet);
     info!("Attempting to resolve node URL (cache miss) for network ID: {:?}", network_id);
-
     match resolver.get_url(WrpcEncoding::Borsh, network_id).await {
         Ok(url) => {
             info!("Successfully resolved node URL: {}", url);
-            {
-                let mut cache_guard = state.node_cache.lock().await;
-                cache_guard.url = Some(url.clone());
-            }
-            Ok(true)
+            Ok(NodeCache {
+                url: Some(url),
+                encoding: Some(NodeEncoding::Borsh),
+                from_resolver: true,
+                network: Some(network_id.to_string()),
+                ..Default::default()
+            })
         }
         Err(e) => {
-            {
-                let mut cache_guard = state.node_cache.lock().await;
-                cache_guard.url = None;
-            }
             error!(
                 "Node connection failed: {}. Check Resolvers.toml for valid endpoints",
                 e
@@ -49,45 +216,181 @@ pub async fn is_node_connected(state: State<'_, AppState>) -> Result<bool, Error
     }
 }
 
+/// Handshakes with one endpoint on a throwaway client, confirming it
+/// serves the expected network.
+async fn verify_endpoint(
+    url: &str,
+    encoding: NodeEncoding,
+    from_resolver: bool,
+    network_id: NetworkId,
+) -> Result<NodeCache, ErrorResponse> {
+    let client = VecnoRpcClient::new_with_args(encoding.into(), Some(url), None, Some(network_id), None)
+        .map_err(|e| ErrorResponse { error: format!("Invalid node URL {}: {}", url, e) })?;
+    let server_info = handshake(&client, url, network_id).await?;
+    if let Err(e) = client.disconnect().await {
+        warn!("Failed to disconnect probe client: {}", e);
+    }
+    Ok(node_record(url.to_string(), encoding, from_resolver, &server_info))
+}
+
+/// Runs the same endpoint order and handshake as `connect_wallet` without
+/// building a wallet, returning the first node that passed.
+async fn probe_node(
+    state: &AppState,
+    node_settings: &NodeSettings,
+    network_id: NetworkId,
+) -> Result<NodeCache, ErrorResponse> {
+    let mut last_error: Option<String> = None;
+
+    for endpoint in &node_settings.endpoints {
+        match verify_endpoint(&endpoint.url, endpoint.encoding, false, network_id).await {
+            Ok(node) => return Ok(node),
+            Err(e) => {
+                warn!("Custom node {} unavailable: {}", endpoint.url, e.error);
+                last_error = Some(e.error);
+            }
+        }
+    }
+
+    if !node_settings.use_resolver {
+        return Err(ErrorResponse {
+            error: match last_error {
+                Some(e) => format!("None of the configured nodes are reachable. Last error: {}", e),
+                None => "No node endpoints configured and the public resolver is disabled".into(),
+            },
+        });
+    }
+
+    // Without endpoints, the candidate is whatever the resolver hands out.
+    let candidate = resolve_candidate(state, &NodeSettings::default(), network_id).await?;
+    let url = candidate.url.ok_or(ErrorResponse { error: "Resolver returned no node".into() })?;
+    verify_endpoint(&url, NodeEncoding::Borsh, true, network_id).await
+}
+
+/// Whether a node is usable: the monitored connection of the open wallet,
+/// or else a fresh handshake with the node opening a wallet would use.
+#[command]
+pub async fn is_node_connected(state: State<'_, AppState>, app: AppHandle) -> Result<bool, ErrorResponse> {
+    if state.wallet.lock().await.is_some() {
+        // The connection monitor keeps this flag current for open wallets.
+        return Ok(state.node_cache.lock().await.connected);
+    }
+
+    let network_id = *state.network_id.lock().await;
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let probed = probe_node(&state, &node_settings, network_id).await;
+
+    let mut cache_guard = state.node_cache.lock().await;
+    match probed {
+        Ok(node) => {
+            *cache_guard = node;
+            Ok(true)
+        }
+        Err(e) => {
+            warn!("No node verified for {}: {}", network_id, e.error);
+            *cache_guard = NodeCache::default();
+            Ok(false)
+        }
+    }
+}
+
 #[command]
-pub async fn get_node_info(state: State<'_, AppState>) -> Result<NodeInfo, ErrorResponse> {
+pub async fn get_node_info(state: State<'_, AppState>, app: AppHandle) -> Result<NodeInfo, ErrorResponse> {
+    let network_id = *state.network_id.lock().await;
     {
         let cache_guard = state.node_cache.lock().await;
-        if let Some(url) = &cache_guard.url {
-            info!("Returning cached node URL: {}", url);
-            return Ok(NodeInfo { url: url.clone() });
+        if cache_guard.url.is_some() {
+            info!("Returning cached node URL: {:?}", cache_guard.url);
+            return Ok(NodeInfo::from_cache(&cache_guard, network_id));
         }
     }
 
-    let guard = state.resolver.lock().await;
-    let resolver = guard.as_ref().ok_or_else(|| {
-        let msg = "Resolver not initialized";
-        error!("{}", msg);
-        ErrorResponse { error: msg.to_string() }
-    })?;
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let node = resolve_candidate(&state, &node_settings, network_id).await?;
+    let info = NodeInfo::from_cache(&node, network_id);
+    *state.node_cache.lock().await = node;
+    Ok(info)
+}
 
-    let network_id = NetworkId::new(NetworkType::Mainnet);
-    match resolver.get_url(WrpcEncoding::Borsh, network_id).await {
-        Ok(url) => {
-            info!("Retrieved node URL: {}", url);
-            {
-                let mut cache_guard = state.node_cache.lock().await;
-                cache_guard.url = Some(url.clone());
-            }
-            Ok(NodeInfo { url })
-        }
-        Err(e) => {
-            {
-                let mut cache_guard = state.node_cache.lock().await;
-                cache_guard.url = None;
-            }
-            error!(
-                "Failed to retrieve node URL: {}. Check Resolvers.toml for valid endpoints.",
-                e
-            );
-            Err(ErrorResponse {
-                error: "Failed to connect to Vecno node. Check your internet connection or try again later.".to_string(),
-            })
+#[command]
+pub async fn get_node_settings(state: State<'_, AppState>, app: AppHandle) -> Result<NodeSettings, ErrorResponse> {
+    let network_id = *state.network_id.lock().await;
+    Ok(settings::load_node_settings(&app, network_id))
+}
+
+#[command]
+pub async fn save_node_settings(
+    node_settings: NodeSettings,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<NodeSettings, ErrorResponse> {
+    let mut endpoints = Vec::with_capacity(node_settings.endpoints.len());
+    for endpoint in node_settings.endpoints {
+        let url = validate_url(&endpoint.url)?;
+        if endpoints.iter().any(|e: &settings::NodeEndpoint| e.url == url) {
+            continue;
         }
+        endpoints.push(settings::NodeEndpoint { url, encoding: endpoint.encoding });
     }
-}
\ No newline at end of file
+
+    if endpoints.is_empty() && !node_settings.use_resolver {
+        return Err(ErrorResponse {
+            error: "Add at least one node endpoint or enable the public resolver".into(),
+        });
+    }
+
+    let cleaned = NodeSettings { endpoints, use_resolver: node_settings.use_resolver };
+    let network_id = *state.network_id.lock().await;
+    settings::save_node_settings(&app, network_id, &cleaned)?;
+
+    if state.wallet.lock().await.is_none() {
+        *state.node_cache.lock().await = NodeCache::default();
+    }
+
+    Ok(cleaned)
+}
+
+#[command]
+pub async fn test_node_connection(
+    url: String,
+    encoding: NodeEncoding,
+    state: State<'_, AppState>,
+) -> Result<NodeTestResult, ErrorResponse> {
+    let url = validate_url(&url)?;
+    let network_id = *state.network_id.lock().await;
+
+    info!("Testing node connection: {} ({:?}) on {}", url, encoding, network_id);
+
+    let client = VecnoRpcClient::new_with_args(encoding.into(), Some(url.as_str()), None, Some(network_id), None)
+        .map_err(|e| ErrorResponse { error: format!("Invalid node URL: {}", e) })?;
+
+    let result = match handshake(&client, &url, network_id).await {
+        Ok(server_info) => NodeTestResult {
+            success: true,
+            url: url.clone(),
+            server_version: Some(server_info.server_version),
+            network: Some(server_info.network_id.to_string()),
+            is_synced: server_info.is_synced,
+            has_utxo_index: server_info.has_utxo_index,
+            virtual_daa_score: server_info.virtual_daa_score,
+            error: (!server_info.has_utxo_index).then(|| "Node does not have the UTXO index enabled".to_string()),
+        },
+        Err(e) => NodeTestResult {
+            success: false,
+            url: url.clone(),
+            server_version: None,
+            network: None,
+            is_synced: false,
+            has_utxo_index: false,
+            virtual_daa_score: 0,
+            error: Some(e.error),
+        },
+    };
+
+    if let Err(e) = client.disconnect().await {
+        warn!("Failed to disconnect test client: {}", e);
+    }
+
+    info!("Node test for {} → success: {}", url, result.success);
+    Ok(result)
+}
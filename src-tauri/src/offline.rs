@@ -0,0 +1,504 @@
+use crate::accounts::open_wallet;
+use crate::get_transactions::script_address;
+use crate::models::{EstimateSendInput, SaveTransactionFileInput, SignTransactionFileInput, TransactionFileFormat};
+use crate::send_transactions::{
+    fee_rate_for, fetch_fee_tiers, generator_settings, load_account_signer, load_send_context, outpoint_key,
+    parse_destination, payment_plan,
+};
+use crate::state::{AppState, ErrorResponse};
+use borsh::{BorshDeserialize, BorshSerialize};
+use chrono::Utc;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{command, AppHandle, Manager, State};
+use vecno_addresses::Prefix;
+use vecno_consensus_core::tx::{SignableTransaction, Transaction, UtxoEntry};
+use vecno_rpc_core::RpcTransaction;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::settings::application_folder;
+use vecno_wallet_core::tx::generator::signer::SignerT;
+use vecno_wallet_core::tx::generator::Generator;
+use vecno_wrpc_client::prelude::RpcApi;
+use workflow_core::prelude::Abortable;
+
+/// One generated transaction with the UTXO entry and address behind each
+/// input, which is everything the signing machine needs.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct FileTransaction {
+    pub transaction: Transaction,
+    pub entries: Vec<UtxoEntry>,
+    pub addresses: Vec<Address>,
+}
+
+/// Transaction file moved between the online and the offline machine.
+/// The indexes tell the signer how far to derive each address chain
+/// before looking up the keys for the inputs.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct TransactionFile {
+    pub network_id: String,
+    pub receive_index: u32,
+    pub change_index: u32,
+    pub created: String,
+    pub transactions: Vec<FileTransaction>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ReviewInput {
+    pub outpoint: String,
+    pub address: String,
+    pub amount: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ReviewOutput {
+    pub address: Option<String>,
+    pub amount: u64,
+    pub change: bool,
+}
+
+/// What the review screen shows before a transaction file is signed or
+/// broadcast, along with the file content itself.
+#[derive(Serialize, Clone, Debug)]
+pub struct TransactionReview {
+    pub data: String,
+    pub format: TransactionFileFormat,
+    pub network_id: String,
+    pub transactions: usize,
+    pub inputs: Vec<ReviewInput>,
+    pub outputs: Vec<ReviewOutput>,
+    pub total_input: u64,
+    pub total_output: u64,
+    pub change: u64,
+    pub fees: u64,
+    pub signed: bool,
+}
+
+/// Reads a transaction file in either format: JSON starts with `{`,
+/// anything else is taken as hex-encoded Borsh.
+fn parse_file(data: &str) -> Result<(TransactionFile, TransactionFileFormat), ErrorResponse> {
+    let data = data.trim();
+    let (file, format) = if data.starts_with('{') {
+        let file: TransactionFile = serde_json::from_str(data)
+            .map_err(|e| ErrorResponse { error: format!("Invalid transaction file: {}", e) })?;
+        (file, TransactionFileFormat::Json)
+    } else {
+        let bytes = hex::decode(data)
+            .map_err(|_| ErrorResponse { error: "Invalid transaction file: expected JSON or hex-encoded Borsh".into() })?;
+        let file = borsh::from_slice::<TransactionFile>(&bytes)
+            .map_err(|e| ErrorResponse { error: format!("Invalid transaction file: {}", e) })?;
+        (file, TransactionFileFormat::Borsh)
+    };
+    if file.transactions.is_empty() {
+        return Err(ErrorResponse { error: "The transaction file contains no transactions".into() });
+    }
+    for tx in file.transactions.iter() {
+        if tx.entries.len() != tx.transaction.inputs.len() || tx.addresses.len() != tx.transaction.inputs.len() {
+            return Err(ErrorResponse { error: "The transaction file is inconsistent: inputs and entries differ".into() });
+        }
+    }
+    Ok((file, format))
+}
+
+fn encode_file(file: &TransactionFile, format: TransactionFileFormat) -> Result<String, ErrorResponse> {
+    match format {
+        TransactionFileFormat::Json => serde_json::to_string_pretty(file).map_err(|e| ErrorResponse { error: e.to_string() }),
+        TransactionFileFormat::Borsh => borsh::to_vec(file)
+            .map(hex::encode)
+            .map_err(|e| ErrorResponse { error: e.to_string() }),
+    }
+}
+
+fn ensure_network(file: &TransactionFile, wallet: &Arc<Wallet>) -> Result<(), ErrorResponse> {
+    let network_id = wallet.network_id()?.to_string();
+    if file.network_id != network_id {
+        return Err(ErrorResponse {
+            error: format!("The transaction file is for {}, but the wallet is on {}", file.network_id, network_id),
+        });
+    }
+    Ok(())
+}
+
+fn is_signed(tx: &Transaction) -> bool {
+    tx.inputs.iter().all(|input| !input.signature_script.is_empty())
+}
+
+/// Highest derivation index used by any of the addresses on each chain.
+fn derivation_hints(account: &Arc<dyn Account>, addresses: &[Address]) -> (u32, u32) {
+    let Ok(derivation_account) = account.clone().as_derivation_capable() else {
+        return (0, 0);
+    };
+    let derivation = derivation_account.derivation();
+
+    let (mut receive_index, mut change_index) = (0, 0);
+    for address in addresses {
+        if let Ok((receive, change)) = derivation.addresses_indexes(&[address]) {
+            receive_index = receive.iter().map(|(_, index)| *index).fold(receive_index, u32::max);
+            change_index = change.iter().map(|(_, index)| *index).fold(change_index, u32::max);
+        }
+    }
+    (receive_index, change_index)
+}
+
+/// Derives both chains up to the indexes recorded in the file so the
+/// account can recognise its input and change addresses.
+fn ensure_derived(account: &Arc<dyn Account>, file: &TransactionFile) -> Result<(), ErrorResponse> {
+    let Ok(derivation_account) = account.clone().as_derivation_capable() else {
+        return Ok(());
+    };
+    let derivation = derivation_account.derivation();
+    derivation
+        .receive_address_manager()
+        .get_range(0..file.receive_index + 1)
+        .map_err(|e| ErrorResponse { error: format!("Failed to derive receive addresses: {}", e) })?;
+    derivation
+        .change_address_manager()
+        .get_range(0..file.change_index + 1)
+        .map_err(|e| ErrorResponse { error: format!("Failed to derive change addresses: {}", e) })?;
+    Ok(())
+}
+
+fn is_change(account: &Arc<dyn Account>, address: &Address) -> bool {
+    account
+        .clone()
+        .as_derivation_capable()
+        .ok()
+        .and_then(|derivation_account| derivation_account.derivation().addresses_indexes(&[address]).ok())
+        .map(|(_, change)| !change.is_empty())
+        .unwrap_or(false)
+}
+
+fn review(
+    file: &TransactionFile,
+    format: TransactionFileFormat,
+    account: &Arc<dyn Account>,
+    prefix: Prefix,
+) -> Result<TransactionReview, ErrorResponse> {
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    let (mut total_input, mut total_output, mut change) = (0, 0, 0);
+
+    for tx in file.transactions.iter() {
+        for ((input, entry), address) in tx.transaction.inputs.iter().zip(tx.entries.iter()).zip(tx.addresses.iter()) {
+            total_input += entry.amount;
+            inputs.push(ReviewInput {
+                outpoint: format!("{}:{}", input.previous_outpoint.transaction_id, input.previous_outpoint.index),
+                address: address.to_string(),
+                amount: entry.amount,
+            });
+        }
+        for output in tx.transaction.outputs.iter() {
+            total_output += output.value;
+            let address = script_address(&output.script_public_key, prefix);
+            let change_output = address.as_ref().map(|a| is_change(account, a)).unwrap_or(false);
+            if change_output {
+                change += output.value;
+            }
+            outputs.push(ReviewOutput {
+                address: address.map(|a| a.to_string()),
+                amount: output.value,
+                change: change_output,
+            });
+        }
+    }
+
+    Ok(TransactionReview {
+        data: encode_file(file, format)?,
+        format,
+        network_id: file.network_id.clone(),
+        transactions: file.transactions.len(),
+        inputs,
+        outputs,
+        total_input,
+        total_output,
+        change,
+        fees: total_input.saturating_sub(total_output),
+        signed: file.transactions.iter().all(|tx| is_signed(&tx.transaction)),
+    })
+}
+
+/// First stage of a send: generates the transactions without signing
+/// them. Works on watch-only wallets, which is the online side of an
+/// air-gapped setup.
+#[command]
+pub async fn build_unsigned_transaction(
+    input: EstimateSendInput,
+    state: State<'_, AppState>,
+) -> Result<TransactionReview, ErrorResponse> {
+    let ctx = load_send_context(&state, input.utxos.as_deref()).await?;
+
+    let target_address = parse_destination(&input.to_address, ctx.network_id)?;
+    let (_, destination, fees) = payment_plan(&ctx, target_address, input.amount, input.sweep)?;
+    let tiers = fetch_fee_tiers(ctx.wallet.rpc_api().as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, input.fee_priority.unwrap_or_default());
+
+    let settings = generator_settings(&ctx, destination, fee_rate, fees)?;
+    let abortable = Abortable::default();
+    let generator = Generator::try_new(settings, None, Some(&abortable))
+        .map_err(|e| ErrorResponse { error: format!("Generator creation failed: {e}") })?;
+
+    let mut transactions = Vec::new();
+    let mut used_addresses = vec![ctx.account.change_address().map_err(ErrorResponse::from)?];
+    for (i, pending_tx) in generator.iter().enumerate() {
+        let pending_tx = pending_tx.map_err(|e| ErrorResponse { error: format!("Generator error at tx #{}: {e}", i + 1) })?;
+
+        let entries: HashMap<String, _> = pending_tx
+            .utxo_entries()
+            .iter()
+            .map(|entry| (outpoint_key(entry), entry.clone()))
+            .collect();
+
+        let transaction = pending_tx.transaction();
+        let mut utxo_entries = Vec::with_capacity(transaction.inputs.len());
+        let mut addresses = Vec::with_capacity(transaction.inputs.len());
+        for input in transaction.inputs.iter() {
+            let key = format!("{}:{}", input.previous_outpoint.transaction_id, input.previous_outpoint.index);
+            let entry = entries
+                .get(&key)
+                .ok_or(ErrorResponse { error: format!("Missing UTXO entry for input {}", key) })?;
+            let address = entry
+                .utxo
+                .address
+                .clone()
+                .ok_or(ErrorResponse { error: format!("UTXO {} has no address", key) })?;
+            utxo_entries.push(UtxoEntry::new(
+                entry.amount(),
+                entry.utxo.script_public_key.clone(),
+                entry.block_daa_score(),
+                entry.is_coinbase(),
+            ));
+            used_addresses.push(address.clone());
+            addresses.push(address);
+        }
+
+        transactions.push(FileTransaction { transaction, entries: utxo_entries, addresses });
+    }
+
+    let (receive_index, change_index) = derivation_hints(&ctx.account, &used_addresses);
+    let file = TransactionFile {
+        network_id: ctx.network_id.to_string(),
+        receive_index,
+        change_index,
+        created: Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        transactions,
+    };
+
+    let review = review(&file, TransactionFileFormat::Json, &ctx.account, Prefix::from(ctx.network_id))?;
+    info!(
+        "Built {} unsigned transaction(s), {} input(s), fees {} VENI",
+        review.transactions,
+        review.inputs.len(),
+        review.fees
+    );
+    Ok(review)
+}
+
+/// Loads a transaction file for review, checking it belongs to the
+/// wallet's network.
+#[command]
+pub async fn review_transaction_file(data: String, state: State<'_, AppState>) -> Result<TransactionReview, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    let (file, format) = parse_file(&data)?;
+    ensure_network(&file, &wallet)?;
+    ensure_derived(&account, &file)?;
+    review(&file, format, &account, Prefix::from(wallet.network_id()?))
+}
+
+/// Second stage: signs every transaction in the file with the selected
+/// account's keys. Needs no node connection.
+#[command]
+pub async fn sign_transaction_file(
+    input: SignTransactionFileInput,
+    state: State<'_, AppState>,
+) -> Result<TransactionReview, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    let (mut file, format) = parse_file(&input.data)?;
+    ensure_network(&file, &wallet)?;
+    ensure_derived(&account, &file)?;
+
+    let signer = load_account_signer(&state, &wallet, &account, input.payment_secret.as_deref()).await?;
+
+    for (i, tx) in file.transactions.iter_mut().enumerate() {
+        let signable = SignableTransaction::with_entries(tx.transaction.clone(), tx.entries.clone());
+        let signed = signer
+            .try_sign(signable, &tx.addresses)
+            .map_err(|e| ErrorResponse { error: format!("Signing failed for tx #{}: {e}", i + 1) })?;
+        tx.transaction = signed.tx;
+    }
+
+    let review = review(&file, format, &account, Prefix::from(wallet.network_id()?))?;
+    info!("Signed {} transaction(s) from file", review.transactions);
+    Ok(review)
+}
+
+/// Final stage: broadcasts a fully signed transaction file in order.
+#[command]
+pub async fn submit_transaction_file(data: String, state: State<'_, AppState>) -> Result<Vec<String>, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let (file, _) = parse_file(&data)?;
+    ensure_network(&file, &wallet)?;
+
+    if let Some(i) = file.transactions.iter().position(|tx| !is_signed(&tx.transaction)) {
+        return Err(ErrorResponse { error: format!("Transaction #{} is not signed yet", i + 1) });
+    }
+
+    let rpc = wallet.rpc_api();
+    let mut txids = Vec::with_capacity(file.transactions.len());
+    for (i, tx) in file.transactions.iter().enumerate() {
+        let txid = rpc
+            .submit_transaction(RpcTransaction::from(&tx.transaction), false)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Submit failed for tx #{}: {e}", i + 1) })?;
+        txids.push(txid.to_string());
+    }
+
+    info!("Submitted {} transaction(s) from file", txids.len());
+    Ok(txids)
+}
+
+/// Writes a transaction file to the downloads folder, or the wallet
+/// folder when there is none, and returns the full path.
+#[command]
+pub async fn save_transaction_file(input: SaveTransactionFileInput, app: AppHandle) -> Result<String, ErrorResponse> {
+    let (file, format) = parse_file(&input.data)?;
+    let data = if format == input.format { input.data.trim().to_string() } else { encode_file(&file, input.format)? };
+
+    let filename = input.filename.trim();
+    if filename.is_empty() || filename.contains(['/', '\\']) || filename.starts_with('.') {
+        return Err(ErrorResponse { error: "Invalid file name".into() });
+    }
+    let extension = match input.format {
+        TransactionFileFormat::Json => ".json",
+        TransactionFileFormat::Borsh => ".borsh",
+    };
+    let filename = if filename.ends_with(extension) { filename.to_string() } else { format!("{}{}", filename, extension) };
+
+    let folder = match app.path().download_dir() {
+        Ok(folder) => folder,
+        Err(_) => application_folder().map_err(|e| ErrorResponse { error: e.to_string() })?,
+    };
+    let path = folder.join(filename);
+
+    std::fs::write(&path, data.as_bytes())
+        .map_err(|e| ErrorResponse { error: format!("Failed to write {}: {}", path.display(), e) })?;
+
+    info!("Saved transaction file to {}", path.display());
+    Ok(path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vecno_addresses::Version;
+    use vecno_consensus_core::network::{NetworkId, NetworkType};
+    use vecno_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+
+    fn file(transactions: Vec<FileTransaction>) -> TransactionFile {
+        TransactionFile {
+            network_id: "mainnet".into(),
+            receive_index: 4,
+            change_index: 2,
+            created: "2024-01-01 00:00:00 UTC".into(),
+            transactions,
+        }
+    }
+
+    /// A transaction without inputs, paired with `addresses` extra
+    /// addresses that have no input behind them.
+    fn transaction(addresses: usize) -> FileTransaction {
+        let prefix = Prefix::from(NetworkId::new(NetworkType::Mainnet));
+        FileTransaction {
+            transaction: Transaction::new(0, vec![], vec![], 0, SUBNETWORK_ID_NATIVE, 0, vec![]),
+            entries: vec![],
+            addresses: (0..addresses).map(|i| Address::new(prefix, Version::PubKey, &[i as u8; 32])).collect(),
+        }
+    }
+
+    fn parsed(data: &str) -> (TransactionFile, TransactionFileFormat) {
+        match parse_file(data) {
+            Ok(parsed) => parsed,
+            Err(e) => panic!("parse failed: {}", e.error),
+        }
+    }
+
+    fn rejected(data: &str) -> String {
+        match parse_file(data) {
+            Ok(_) => panic!("parse should fail"),
+            Err(e) => e.error,
+        }
+    }
+
+    fn encoded(file: &TransactionFile, format: TransactionFileFormat) -> String {
+        match encode_file(file, format) {
+            Ok(data) => data,
+            Err(e) => panic!("encode failed: {}", e.error),
+        }
+    }
+
+    #[test]
+    fn json_round_trips_and_is_detected() {
+        let data = encoded(&file(vec![transaction(0)]), TransactionFileFormat::Json);
+        let (parsed, format) = parsed(&format!("\n  {}\n", data));
+        assert_eq!(format, TransactionFileFormat::Json);
+        assert_eq!((parsed.receive_index, parsed.change_index), (4, 2));
+        assert_eq!(parsed.transactions.len(), 1);
+    }
+
+    #[test]
+    fn hex_borsh_round_trips_and_is_detected() {
+        let data = encoded(&file(vec![transaction(0)]), TransactionFileFormat::Borsh);
+        assert!(data.chars().all(|c| c.is_ascii_hexdigit()));
+        let (parsed, format) = parsed(&data);
+        assert_eq!(format, TransactionFileFormat::Borsh);
+        assert_eq!(parsed.network_id, "mainnet");
+        assert_eq!(parsed.transactions.len(), 1);
+    }
+
+    #[test]
+    fn uppercase_hex_is_accepted() {
+        let data = encoded(&file(vec![transaction(0)]), TransactionFileFormat::Borsh).to_uppercase();
+        assert_eq!(parsed(&data).1, TransactionFileFormat::Borsh);
+    }
+
+    #[test]
+    fn malformed_hex_is_rejected() {
+        let expected = "Invalid transaction file: expected JSON or hex-encoded Borsh";
+        assert_eq!(rejected("not a transaction file"), expected);
+        assert_eq!(rejected("abc"), expected);
+        assert_eq!(rejected("0x00"), expected);
+    }
+
+    #[test]
+    fn hex_that_is_not_a_transaction_file_is_rejected() {
+        let error = rejected("00ff");
+        assert!(error.starts_with("Invalid transaction file: "));
+        assert!(!error.contains("expected JSON"));
+    }
+
+    #[test]
+    fn trailing_borsh_bytes_are_rejected() {
+        let data = encoded(&file(vec![transaction(0)]), TransactionFileFormat::Borsh) + "00";
+        assert!(rejected(&data).starts_with("Invalid transaction file: "));
+    }
+
+    #[test]
+    fn malformed_json_is_rejected() {
+        assert!(rejected("{\"network_id\": ").starts_with("Invalid transaction file: "));
+    }
+
+    #[test]
+    fn empty_files_are_rejected_in_both_formats() {
+        let expected = "The transaction file contains no transactions";
+        assert_eq!(rejected(&encoded(&file(vec![]), TransactionFileFormat::Json)), expected);
+        assert_eq!(rejected(&encoded(&file(vec![]), TransactionFileFormat::Borsh)), expected);
+    }
+
+    #[test]
+    fn inputs_without_matching_addresses_are_rejected() {
+        let data = encoded(&file(vec![transaction(0), transaction(1)]), TransactionFileFormat::Borsh);
+        assert_eq!(rejected(&data), "The transaction file is inconsistent: inputs and entries differ");
+    }
+}
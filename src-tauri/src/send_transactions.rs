@@ -1,21 +1,22 @@
+use crate::accounts::{is_multisig, is_watch_only, scan_account};
 use crate::state::{AppState, ErrorResponse};
 use tauri::{command, State};
-use vecno_addresses::Address;
-use crate::models::SendTransactionInput;
+use vecno_addresses::{Address, Prefix};
+use crate::models::{EstimateSendInput, FeePriority, PaymentOutputInput, SendBatchInput, SendTransactionInput};
 use vecno_wallet_core::prelude::*;
 use vecno_wrpc_client::prelude::RpcApi;
-use vecno_wallet_core::tx::generator::{Generator, GeneratorSettings};
+use vecno_wallet_core::tx::generator::{Generator, GeneratorSettings, GeneratorSummary};
 use vecno_wallet_core::tx::{PaymentDestination, PaymentOutputs, PaymentOutput, Fees};
-use vecno_wallet_core::utxo::{
-    scan::DEFAULT_WINDOW_SIZE, Scan, ScanExtent, balance::AtomicBalance, UtxoContext,
-    UtxoEntryReference,
-};
-use vecno_wallet_core::utxo::UtxoContextBinding;
-use vecno_wallet_core::derivation::AddressManager;
+use vecno_wallet_core::utxo::{UtxoContext, UtxoEntryReference};
+use vecno_rpc_core::RpcFeerateBucket;
+use vecno_consensus_core::network::NetworkId;
+use serde::Serialize;
+use std::collections::HashSet;
 use std::sync::Arc;
 use workflow_core::prelude::Abortable;
 use vecno_wallet_core::tx::generator::signer::Signer;
 use chrono::Utc;
+use log::warn;
 
 async fn get_mature_utxos(ctx: &UtxoContext) -> Result<Vec<UtxoEntryReference>, ErrorResponse> {
     let entries = ctx
@@ -26,15 +27,6 @@ async fn get_mature_utxos(ctx: &UtxoContext) -> Result<Vec<UtxoEntryReference>,
     Ok(entries.into_iter().map(UtxoEntryReference::from).collect())
 }
 
-async fn fetch_current_daa_score(rpc: &dyn RpcApi) -> Result<u64, ErrorResponse> {
-    let info = rpc
-        .get_server_info()
-        .await
-        .map_err(|e| ErrorResponse { error: format!("RPC get_server_info failed: {e}") })?;
-
-    Ok(info.virtual_daa_score)
-}
-
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct SentTxInfo {
     pub txid: String,
@@ -43,96 +35,274 @@ pub struct SentTxInfo {
     pub timestamp: String,
 }
 
-#[command]
-pub async fn send_transaction(
-    input: SendTransactionInput,
-    state: State<'_, AppState>,
-) -> Result<SentTxInfo, ErrorResponse> {
-    let to_address = input.to_address;
-    let amount = input.amount;
-    let payment_secret = input.payment_secret;
+#[derive(Serialize, Clone, Debug)]
+pub struct FeeTier {
+    pub priority: FeePriority,
+    pub feerate: f64,
+    pub estimated_seconds: f64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SendEstimate {
+    /// What the recipient receives; for a sweep this is the balance net of fees.
+    pub amount: u64,
+    pub total_fees: u64,
+    pub mass: u64,
+    pub transactions: usize,
+    pub inputs: usize,
+    pub fee_priority: FeePriority,
+    pub sweep: bool,
+    pub tiers: Vec<FeeTier>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputState {
+    Sent,
+    Invalid,
+    Skipped,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct OutputStatus {
+    pub index: usize,
+    pub to_address: String,
+    pub amount: u64,
+    pub status: OutputState,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BatchSendResult {
+    pub txids: Vec<String>,
+    pub outputs: Vec<OutputStatus>,
+    pub total_amount: u64,
+    pub total_fees: u64,
+    pub timestamp: String,
+}
+
+/// Wallet, selected account and its freshly scanned spendable UTXOs.
+pub(crate) struct SendContext {
+    pub wallet: Arc<Wallet>,
+    pub account: Arc<dyn Account>,
+    pub network_id: NetworkId,
+    pub utxo_entries: Vec<UtxoEntryReference>,
+}
+
+/// `txid:index` key identifying a UTXO in coin control and frozen lists.
+pub(crate) fn outpoint_key(entry: &UtxoEntryReference) -> String {
+    let outpoint = entry.outpoint();
+    format!("{}:{}", outpoint.transaction_id(), outpoint.index())
+}
+
+/// Drops frozen UTXOs and, when the user picked coins, everything else.
+fn select_utxos(
+    entries: Vec<UtxoEntryReference>,
+    frozen: &HashSet<String>,
+    selection: Option<&[String]>,
+) -> Result<Vec<UtxoEntryReference>, ErrorResponse> {
+    let Some(selection) = selection.filter(|s| !s.is_empty()) else {
+        return Ok(entries
+            .into_iter()
+            .filter(|entry| !frozen.contains(&outpoint_key(entry)))
+            .collect());
+    };
+
+    if let Some(outpoint) = selection.iter().find(|o| frozen.contains(*o)) {
+        return Err(ErrorResponse { error: format!("UTXO {outpoint} is frozen") });
+    }
+
+    let wanted: HashSet<&str> = selection.iter().map(String::as_str).collect();
+    let selected: Vec<_> = entries
+        .into_iter()
+        .filter(|entry| wanted.contains(outpoint_key(entry).as_str()))
+        .collect();
+
+    if selected.len() != wanted.len() {
+        return Err(ErrorResponse {
+            error: format!(
+                "{} selected UTXO(s) are no longer spendable",
+                wanted.len() - selected.len()
+            ),
+        });
+    }
+
+    Ok(selected)
+}
 
+pub(crate) async fn load_send_context(state: &AppState, selection: Option<&[String]>) -> Result<SendContext, ErrorResponse> {
     let wallet_guard = state.wallet.lock().await;
     let wallet = wallet_guard
         .as_ref()
         .ok_or(ErrorResponse { error: "Wallet is not open".into() })?
         .clone();
+    drop(wallet_guard);
 
     if !wallet.is_open() {
         return Err(ErrorResponse { error: "Wallet is not open".into() });
     }
 
-    let account_trait: Arc<dyn Account> = wallet
+    let account: Arc<dyn Account> = wallet
         .account()
         .map_err(ErrorResponse::from)?;
-    let account: Arc<dyn Account> = account_trait.clone();
 
-    let wallet_secret_guard = state.wallet_secret.lock().await;
-    let wallet_secret = wallet_secret_guard
-        .as_ref()
-        .ok_or(ErrorResponse { error: "Wallet secret not loaded".into() })?;
+    let utxo_context = scan_account(state, &account).await?;
+    let frozen = state.frozen_utxos.lock().await.clone();
+    let utxo_entries = select_utxos(get_mature_utxos(&utxo_context).await?, &frozen, selection)?;
+    let network_id = wallet.network_id()?;
+
+    Ok(SendContext {
+        wallet,
+        account,
+        network_id,
+        utxo_entries,
+    })
+}
 
-    let prv_key_data_id = account
-        .prv_key_data_id()?
-        .clone();
+pub(crate) fn parse_destination(address: &str, network_id: NetworkId) -> Result<Address, ErrorResponse> {
+    let target_address = Address::try_from(address.trim())
+        .map_err(|e| ErrorResponse { error: format!("Invalid address: {e}") })?;
 
-    let prv_key_data = wallet
-        .get_prv_key_data(wallet_secret, &prv_key_data_id)
-        .await
-        .map_err(|e| ErrorResponse { error: format!("Failed to load PrvKeyData: {e}") })?
-        .ok_or(ErrorResponse { error: "PrvKeyData not found".into() })?;
+    let expected_prefix = Prefix::from(network_id);
+    if target_address.prefix != expected_prefix {
+        return Err(ErrorResponse {
+            error: format!(
+                "Address belongs to a different network: expected '{}:' prefix for {}",
+                expected_prefix, network_id
+            ),
+        });
+    }
 
-    drop(wallet_secret_guard);
+    Ok(target_address)
+}
 
-    let processor = wallet.utxo_processor().clone();
-    let binding = UtxoContextBinding::AccountId(*account.id());
-    let utxo_context = Arc::new(UtxoContext::new(&processor, binding));
+fn tier(priority: FeePriority, bucket: &RpcFeerateBucket) -> FeeTier {
+    FeeTier {
+        priority,
+        feerate: bucket.feerate,
+        estimated_seconds: bucket.estimated_seconds,
+    }
+}
 
-    let derivation = account
-        .clone()
-        .as_derivation_capable()
-        .map_err(|e| ErrorResponse { error: format!("Account is not derivation-capable: {e}") })?;
+/// Maps the node's fee-rate buckets onto low / normal / priority tiers.
+/// Returns an empty list when the node cannot provide an estimate.
+pub(crate) async fn fetch_fee_tiers(rpc: &dyn RpcApi) -> Vec<FeeTier> {
+    let estimate = match rpc.get_fee_estimate().await {
+        Ok(estimate) => estimate,
+        Err(e) => {
+            warn!("Fee estimate unavailable, falling back to minimum fee rate: {e}");
+            return Vec::new();
+        }
+    };
 
-    let receive_manager: Arc<AddressManager> = derivation.derivation().receive_address_manager();
-    let change_manager: Arc<AddressManager> = derivation.derivation().change_address_manager();
+    let priority = &estimate.priority_bucket;
+    let normal = estimate.normal_buckets.first().unwrap_or(priority);
+    let low = estimate
+        .low_buckets
+        .first()
+        .or_else(|| estimate.normal_buckets.last())
+        .unwrap_or(normal);
+
+    vec![
+        tier(FeePriority::Low, low),
+        tier(FeePriority::Normal, normal),
+        tier(FeePriority::Priority, priority),
+    ]
+}
+
+pub(crate) fn fee_rate_for(tiers: &[FeeTier], priority: FeePriority) -> Option<f64> {
+    tiers.iter().find(|t| t.priority == priority).map(|t| t.feerate)
+}
 
-    receive_manager
-        .current_address()
-        .map_err(|e| ErrorResponse { error: format!("Receive address error: {e}") })?;
-    change_manager
-        .current_address()
+pub(crate) fn generator_settings(
+    ctx: &SendContext,
+    destination: PaymentDestination,
+    fee_rate: Option<f64>,
+    priority_fee: Fees,
+) -> Result<GeneratorSettings, ErrorResponse> {
+    let change_address = ctx
+        .account
+        .change_address()
         .map_err(|e| ErrorResponse { error: format!("Change address error: {e}") })?;
 
-    let rpc = wallet.rpc_api();
-    let current_daa_score = fetch_current_daa_score(rpc.as_ref()).await?;
+    let utxo_iterator = ctx.utxo_entries.clone().into_iter();
 
-    let receive_scan = Scan::new_with_address_manager(
-        receive_manager.clone(),
-        &Arc::new(AtomicBalance::default()),
-        current_daa_score,
-        Some(DEFAULT_WINDOW_SIZE),
-        Some(ScanExtent::EmptyWindow),
-    );
-    let change_scan = Scan::new_with_address_manager(
-        change_manager.clone(),
-        &Arc::new(AtomicBalance::default()),
-        current_daa_score,
-        Some(DEFAULT_WINDOW_SIZE),
-        Some(ScanExtent::EmptyWindow),
-    );
+    Ok(GeneratorSettings {
+        network_id: ctx.network_id,
+        multiplexer: None,
+        utxo_iterator: Box::new(utxo_iterator),
+        // Binding the account context lets wallet-core track the spend
+        // and write the outgoing record into the transaction history.
+        source_utxo_context: Some(ctx.account.utxo_context().clone()),
+        priority_utxo_entries: None,
+        sig_op_count: ctx.account.sig_op_count(),
+        minimum_signatures: ctx.account.minimum_signatures(),
+        change_address,
+        fee_rate,
+        final_transaction_priority_fee: priority_fee,
+        final_transaction_destination: destination,
+        final_transaction_payload: None,
+        destination_utxo_context: None,
+    })
+}
 
-    tokio::try_join!(
-        receive_scan.scan(&utxo_context),
-        change_scan.scan(&utxo_context)
-    )
-    .map_err(|e| ErrorResponse { error: format!("Scan failed: {e}") })?;
+/// Runs the generator without a signer so nothing is signed or submitted.
+pub(crate) fn estimate(settings: GeneratorSettings) -> Result<GeneratorSummary, ErrorResponse> {
+    let abortable = Abortable::default();
+    let generator = Generator::try_new(settings, None, Some(&abortable))
+        .map_err(|e| ErrorResponse { error: format!("Generator creation failed: {e}") })?;
 
-    let utxo_entries = get_mature_utxos(&utxo_context).await?;
-    let total_available: u64 = utxo_entries.iter().map(|u| u.amount()).sum();
+    for (i, pending_tx) in generator.iter().enumerate() {
+        pending_tx.map_err(|e| ErrorResponse { error: format!("Generator error at tx #{}: {e}", i + 1) })?;
+    }
+
+    Ok(generator.summary())
+}
+
+pub(crate) fn total_available(ctx: &SendContext) -> u64 {
+    ctx.utxo_entries.iter().map(|u| u.amount()).sum()
+}
+
+/// Destination and fee mode for a send: a sweep pays the whole spendable
+/// balance and lets the receiver cover the fees, so no change is left over.
+pub(crate) fn payment_plan(
+    ctx: &SendContext,
+    target_address: Address,
+    amount: u64,
+    sweep: bool,
+) -> Result<(u64, PaymentDestination, Fees), ErrorResponse> {
+    let (amount, fees) = if sweep {
+        let total = total_available(ctx);
+        if total == 0 {
+            return Err(ErrorResponse { error: "No mature funds to sweep".into() });
+        }
+        (total, Fees::ReceiverPays(0))
+    } else {
+        ensure_funds(ctx, amount)?;
+        (amount, Fees::SenderPays(0))
+    };
+
+    let destination = PaymentDestination::PaymentOutputs(PaymentOutputs {
+        outputs: vec![PaymentOutput::new(target_address, amount)],
+    });
+
+    Ok((amount, destination, fees))
+}
+
+/// Amount the recipient ends up with once the generator has run.
+fn received_amount(amount: u64, fees: &Fees, summary: &GeneratorSummary) -> u64 {
+    match fees {
+        Fees::ReceiverPays(_) => amount.saturating_sub(summary.aggregate_fees()),
+        _ => amount,
+    }
+}
+
+fn ensure_funds(ctx: &SendContext, amount: u64) -> Result<(), ErrorResponse> {
+    let total_available = total_available(ctx);
 
     log::info!(
-        "send_transaction: Using {} UTXOs totaling {} VENI (need: {})",
-        utxo_entries.len(),
+        "Using {} UTXOs totaling {} VENI (need: {})",
+        ctx.utxo_entries.len(),
         total_available,
         amount
     );
@@ -146,50 +316,73 @@ pub async fn send_transaction(
         });
     }
 
-    let utxo_iterator = utxo_entries.into_iter().map(UtxoEntryReference::from);
+    Ok(())
+}
+
+pub(crate) async fn load_signer(
+    state: &AppState,
+    ctx: &SendContext,
+    payment_secret: Option<&str>,
+) -> Result<Arc<Signer>, ErrorResponse> {
+    load_account_signer(state, &ctx.wallet, &ctx.account, payment_secret).await
+}
 
-    let secret_opt: Option<Secret> = payment_secret
+/// Signer holding the account's private key, for callers that sign
+/// without building a send context first.
+pub(crate) async fn load_account_signer(
+    state: &AppState,
+    wallet: &Arc<Wallet>,
+    account: &Arc<dyn Account>,
+    payment_secret: Option<&str>,
+) -> Result<Arc<Signer>, ErrorResponse> {
+    let wallet_secret_guard = state.wallet_secret.lock().await;
+    let wallet_secret = wallet_secret_guard
         .as_ref()
-        .and_then(|s| {
-            let s = s.trim();
-            if s.is_empty() {
-                None
-            } else {
-                Some(Secret::from(s))
-            }
+        .ok_or(ErrorResponse { error: "Wallet secret not loaded".into() })?;
+
+    if is_watch_only(account) {
+        return Err(ErrorResponse {
+            error: "This is a watch-only wallet: it holds no private keys and cannot sign transactions".into(),
         });
+    }
 
-    let signer = Arc::new(Signer::new(
-        account.clone(),
-        prv_key_data,
-        secret_opt,
-    ));
+    if is_multisig(account) {
+        return Err(ErrorResponse {
+            error: "Multisig accounts need cosigner signatures: create a partially signed transaction instead".into(),
+        });
+    }
 
-    let target_address = Address::try_from(to_address.as_str())
-        .map_err(|e| ErrorResponse { error: format!("Invalid address: {e}") })?;
+    let prv_key_data_id = account
+        .prv_key_data_id()?
+        .clone();
 
-    let change_address = account
-        .change_address()
-        .map_err(|e| ErrorResponse { error: format!("Change address error: {e}") })?;
+    let prv_key_data = wallet
+        .get_prv_key_data(wallet_secret, &prv_key_data_id)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to load PrvKeyData: {e}") })?
+        .ok_or(ErrorResponse { error: "PrvKeyData not found".into() })?;
 
-    let settings = GeneratorSettings {
-        network_id: wallet.network_id()?,
-        multiplexer: None,
-        utxo_iterator: Box::new(utxo_iterator),
-        source_utxo_context: None,
-        priority_utxo_entries: None,
-        sig_op_count: account.sig_op_count(),
-        minimum_signatures: account.minimum_signatures(),
-        change_address: change_address.clone(),
-        fee_rate: None,
-        final_transaction_priority_fee: Fees::SenderPays(0),
-        final_transaction_destination: PaymentDestination::PaymentOutputs(PaymentOutputs {
-            outputs: vec![PaymentOutput::new(target_address.clone(), amount)],
-        }),
-        final_transaction_payload: None,
-        destination_utxo_context: None,
-    };
+    drop(wallet_secret_guard);
+
+    let secret_opt: Option<Secret> = payment_secret
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Secret::from);
+
+    Ok(Arc::new(Signer::new(
+        account.clone(),
+        prv_key_data,
+        secret_opt,
+    )))
+}
 
+/// Signs and submits every transaction the generator produces, returning
+/// the submitted txids in order together with the generator summary.
+async fn sign_and_submit(
+    settings: GeneratorSettings,
+    signer: Arc<Signer>,
+    rpc: &Arc<DynRpcApi>,
+) -> Result<(Vec<String>, GeneratorSummary), ErrorResponse> {
     let abortable = Abortable::default();
     let generator = Generator::try_new(settings, Some(signer), Some(&abortable))
         .map_err(|e| ErrorResponse { error: format!("Generator creation failed: {e}") })?;
@@ -205,19 +398,123 @@ pub async fn send_transaction(
             .map_err(|e| ErrorResponse { error: format!("Signing failed for tx #{}: {e}", i + 1) })?;
 
         let rpc_id = pending_tx
-            .try_submit(&rpc)
+            .try_submit(rpc)
             .await
             .map_err(|e| ErrorResponse { error: format!("Submit failed for tx #{}: {e}", i + 1) })?;
 
         tx_ids.push(rpc_id.to_string());
     }
 
+    Ok((tx_ids, generator.summary()))
+}
+
+/// Validates every requested output, keeping the per-output outcome so the
+/// UI can point at the offending rows.
+fn validate_outputs(
+    outputs: &[PaymentOutputInput],
+    network_id: NetworkId,
+) -> (Vec<PaymentOutput>, Vec<OutputStatus>) {
+    let mut payment_outputs = Vec::with_capacity(outputs.len());
+    let mut statuses = Vec::with_capacity(outputs.len());
+
+    for (index, output) in outputs.iter().enumerate() {
+        let result = if output.amount == 0 {
+            Err(ErrorResponse { error: "Amount must be greater than 0".into() })
+        } else {
+            parse_destination(&output.to_address, network_id)
+        };
+
+        let (status, error) = match result {
+            Ok(address) => {
+                payment_outputs.push(PaymentOutput::new(address, output.amount));
+                (OutputState::Sent, None)
+            }
+            Err(e) => (OutputState::Invalid, Some(e.error)),
+        };
+
+        statuses.push(OutputStatus {
+            index,
+            to_address: output.to_address.trim().to_string(),
+            amount: output.amount,
+            status,
+            error,
+        });
+    }
+
+    (payment_outputs, statuses)
+}
+
+#[command]
+pub async fn estimate_send(
+    input: EstimateSendInput,
+    state: State<'_, AppState>,
+) -> Result<SendEstimate, ErrorResponse> {
+    let ctx = load_send_context(&state, input.utxos.as_deref()).await?;
+
+    let target_address = parse_destination(&input.to_address, ctx.network_id)?;
+    let (amount, destination, fees) = payment_plan(&ctx, target_address, input.amount, input.sweep)?;
+    let fee_priority = input.fee_priority.unwrap_or_default();
+    let tiers = fetch_fee_tiers(ctx.wallet.rpc_api().as_ref()).await;
+
+    let settings = generator_settings(&ctx, destination, fee_rate_for(&tiers, fee_priority), fees.clone())?;
+    let summary = estimate(settings)?;
+
+    log::info!(
+        "estimate_send: {} tx(s), {} inputs, fees {} VENI, mass {}",
+        summary.number_of_generated_transactions(),
+        summary.aggregated_utxos(),
+        summary.aggregate_fees(),
+        summary.aggregate_mass()
+    );
+
+    Ok(SendEstimate {
+        amount: received_amount(amount, &fees, &summary),
+        total_fees: summary.aggregate_fees(),
+        mass: summary.aggregate_mass(),
+        transactions: summary.number_of_generated_transactions(),
+        inputs: summary.aggregated_utxos(),
+        fee_priority,
+        sweep: input.sweep,
+        tiers,
+    })
+}
+
+#[command]
+pub async fn send_transaction(
+    input: SendTransactionInput,
+    state: State<'_, AppState>,
+) -> Result<SentTxInfo, ErrorResponse> {
+    let to_address = input.to_address;
+    let payment_secret = input.payment_secret;
+    let fee_priority = input.fee_priority.unwrap_or_default();
+
+    let ctx = load_send_context(&state, input.utxos.as_deref()).await?;
+
+    let target_address = parse_destination(&to_address, ctx.network_id)?;
+    let (amount, destination, fees) = payment_plan(&ctx, target_address, input.amount, input.sweep)?;
+
+    let signer = load_signer(&state, &ctx, payment_secret.as_deref()).await?;
+
+    let rpc = ctx.wallet.rpc_api();
+    let tiers = fetch_fee_tiers(rpc.as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, fee_priority);
+    log::info!(
+        "send_transaction: fee priority {:?} (rate {:?}), sweep {}",
+        fee_priority,
+        fee_rate,
+        input.sweep
+    );
+
+    let settings = generator_settings(&ctx, destination, fee_rate, fees.clone())?;
+
+    let (tx_ids, summary) = sign_and_submit(settings, signer, &rpc).await?;
+
     let last_tx_id = tx_ids.last().cloned().unwrap_or_default();
 
     let sent = SentTxInfo {
         txid: last_tx_id,
         to_address,
-        amount,
+        amount: received_amount(amount, &fees, &summary),
         timestamp: Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
     };
 
@@ -228,4 +525,174 @@ pub async fn send_transaction(
     );
 
     Ok(sent)
-}
\ No newline at end of file
+}
+
+#[command]
+pub async fn send_batch(
+    input: SendBatchInput,
+    state: State<'_, AppState>,
+) -> Result<BatchSendResult, ErrorResponse> {
+    if input.outputs.is_empty() {
+        return Err(ErrorResponse { error: "Batch has no outputs".into() });
+    }
+
+    let fee_priority = input.fee_priority.unwrap_or_default();
+    let ctx = load_send_context(&state, input.utxos.as_deref()).await?;
+
+    let (payment_outputs, mut statuses) = validate_outputs(&input.outputs, ctx.network_id);
+    let total_amount: u64 = payment_outputs.iter().map(|output| output.amount).sum();
+    let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string();
+
+    // A batch is one payment set: if any row is bad nothing is sent, and
+    // the valid rows are reported as skipped.
+    let invalid = statuses.iter().filter(|s| matches!(s.status, OutputState::Invalid)).count();
+    if invalid > 0 {
+        warn!("send_batch: {} of {} output(s) invalid, nothing sent", invalid, statuses.len());
+        for status in statuses.iter_mut().filter(|s| matches!(s.status, OutputState::Sent)) {
+            status.status = OutputState::Skipped;
+        }
+        return Ok(BatchSendResult {
+            txids: Vec::new(),
+            outputs: statuses,
+            total_amount,
+            total_fees: 0,
+            timestamp,
+        });
+    }
+
+    ensure_funds(&ctx, total_amount)?;
+
+    let signer = load_signer(&state, &ctx, input.payment_secret.as_deref()).await?;
+
+    let rpc = ctx.wallet.rpc_api();
+    let tiers = fetch_fee_tiers(rpc.as_ref()).await;
+    let fee_rate = fee_rate_for(&tiers, fee_priority);
+    log::info!(
+        "send_batch: {} output(s), {} VENI, fee priority {:?} (rate {:?})",
+        payment_outputs.len(),
+        total_amount,
+        fee_priority,
+        fee_rate
+    );
+
+    let settings = generator_settings(
+        &ctx,
+        PaymentDestination::PaymentOutputs(PaymentOutputs { outputs: payment_outputs }),
+        fee_rate,
+        Fees::SenderPays(0),
+    )?;
+
+    let (txids, summary) = sign_and_submit(settings, signer, &rpc).await?;
+
+    log::info!(
+        "Batch submitted in {} transaction(s), fees {} VENI. Last TXID: {}",
+        txids.len(),
+        summary.aggregate_fees(),
+        txids.last().map(String::as_str).unwrap_or_default()
+    );
+
+    Ok(BatchSendResult {
+        txids,
+        outputs: statuses,
+        total_amount,
+        total_fees: summary.aggregate_fees(),
+        timestamp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vecno_consensus_core::tx::{ScriptPublicKey, TransactionId, TransactionOutpoint};
+    use vecno_rpc_core::{RpcUtxoEntry, RpcUtxosByAddressesEntry};
+
+    fn entry(byte: u8, index: u32) -> UtxoEntryReference {
+        UtxoEntryReference::from(RpcUtxosByAddressesEntry {
+            address: None,
+            outpoint: TransactionOutpoint::new(TransactionId::from_bytes([byte; 32]), index).into(),
+            utxo_entry: RpcUtxoEntry::new(100_000, ScriptPublicKey::from_vec(0, vec![]), 0, false),
+        })
+    }
+
+    fn selected(result: Result<Vec<UtxoEntryReference>, ErrorResponse>) -> Vec<String> {
+        match result {
+            Ok(entries) => entries.iter().map(outpoint_key).collect(),
+            Err(e) => panic!("selection failed: {}", e.error),
+        }
+    }
+
+    fn rejected(result: Result<Vec<UtxoEntryReference>, ErrorResponse>) -> String {
+        match result {
+            Ok(entries) => panic!("selection should fail, got {} entries", entries.len()),
+            Err(e) => e.error,
+        }
+    }
+
+    #[test]
+    fn outpoint_key_is_txid_and_index() {
+        assert_eq!(outpoint_key(&entry(0xab, 3)), format!("{}:3", "ab".repeat(32)));
+    }
+
+    #[test]
+    fn automatic_selection_skips_frozen_entries() {
+        let entries = vec![entry(1, 0), entry(2, 0), entry(2, 1)];
+        let frozen = HashSet::from([outpoint_key(&entries[1])]);
+        let expected = vec![outpoint_key(&entries[0]), outpoint_key(&entries[2])];
+        assert_eq!(selected(select_utxos(entries, &frozen, None)), expected);
+    }
+
+    #[test]
+    fn all_entries_frozen_leaves_nothing_to_spend() {
+        let entries = vec![entry(1, 0), entry(2, 0)];
+        let frozen: HashSet<String> = entries.iter().map(outpoint_key).collect();
+        assert!(selected(select_utxos(entries, &frozen, None)).is_empty());
+    }
+
+    #[test]
+    fn empty_selection_falls_back_to_automatic() {
+        let entries = vec![entry(1, 0), entry(2, 0)];
+        let frozen = HashSet::from([outpoint_key(&entries[0])]);
+        let expected = vec![outpoint_key(&entries[1])];
+        assert_eq!(selected(select_utxos(entries, &frozen, Some(&[] as &[String]))), expected);
+    }
+
+    #[test]
+    fn explicit_selection_keeps_only_the_picked_entries() {
+        let entries = vec![entry(1, 0), entry(2, 0), entry(3, 0)];
+        let picked = vec![outpoint_key(&entries[2]), outpoint_key(&entries[0])];
+        let expected = vec![outpoint_key(&entries[0]), outpoint_key(&entries[2])];
+        assert_eq!(selected(select_utxos(entries, &HashSet::new(), Some(picked.as_slice()))), expected);
+    }
+
+    #[test]
+    fn picking_the_same_entry_twice_counts_once() {
+        let entries = vec![entry(1, 0), entry(2, 0)];
+        let picked = vec![outpoint_key(&entries[1]), outpoint_key(&entries[1])];
+        let expected = vec![outpoint_key(&entries[1])];
+        assert_eq!(selected(select_utxos(entries, &HashSet::new(), Some(picked.as_slice()))), expected);
+    }
+
+    #[test]
+    fn explicit_selection_refuses_frozen_entries() {
+        let entries = vec![entry(1, 0), entry(2, 0)];
+        let key = outpoint_key(&entries[0]);
+        let frozen = HashSet::from([key.clone()]);
+        let error = rejected(select_utxos(entries, &frozen, Some(std::slice::from_ref(&key))));
+        assert_eq!(error, format!("UTXO {key} is frozen"));
+    }
+
+    #[test]
+    fn explicit_selection_refuses_unknown_outpoints() {
+        let entries = vec![entry(1, 0)];
+        let picked = vec![outpoint_key(&entries[0]), outpoint_key(&entry(9, 7))];
+        let error = rejected(select_utxos(entries, &HashSet::new(), Some(picked.as_slice())));
+        assert_eq!(error, "1 selected UTXO(s) are no longer spendable");
+    }
+
+    #[test]
+    fn explicit_selection_from_no_entries_fails() {
+        let picked = vec![outpoint_key(&entry(1, 0))];
+        let error = rejected(select_utxos(Vec::new(), &HashSet::new(), Some(picked.as_slice())));
+        assert_eq!(error, "1 selected UTXO(s) are no longer spendable");
+    }
+}
@@ -0,0 +1,290 @@
+use crate::state::ErrorResponse;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+use vecno_consensus_core::network::{NetworkId, NetworkType};
+use vecno_wrpc_client::prelude::WrpcEncoding;
+
+pub const SETTINGS_STORE: &str = "settings.json";
+
+const NETWORK_KEY: &str = "network";
+const WALLETS_KEY: &str = "wallets";
+const NODES_KEY: &str = "nodes";
+const LOCK_KEY: &str = "lock";
+
+/// Per-wallet metadata kept alongside the encrypted wallet files,
+/// keyed by the wallet filename stem.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WalletMeta {
+    #[serde(default)]
+    pub network: Option<String>,
+    /// Outpoints (`txid:index`) the user froze in coin control.
+    #[serde(default)]
+    pub frozen_utxos: Vec<String>,
+    /// Id of the account selected when the wallet was last used.
+    #[serde(default)]
+    pub active_account: Option<String>,
+    /// Address labels written by earlier versions, before they moved into
+    /// the wallet file's encrypted address book. Emptied once migrated.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub address_labels: BTreeMap<String, String>,
+    /// Addresses a watch-only wallet monitors in addition to its xpub.
+    #[serde(default)]
+    pub watch_addresses: Vec<String>,
+    /// Set for newly created wallets until the recovery phrase quiz passes.
+    #[serde(default)]
+    pub backup_pending: bool,
+    /// Set when the recovery phrase is outside the English wordlist and
+    /// only its seed was stored, so the words exist only in the session
+    /// that created the wallet.
+    #[serde(default)]
+    pub seed_only: bool,
+}
+
+impl WalletMeta {
+    /// Wallets created before network selection existed were always mainnet.
+    pub fn network_id(&self) -> NetworkId {
+        self.network
+            .as_deref()
+            .and_then(|n| n.parse::<NetworkId>().ok())
+            .unwrap_or_else(|| NetworkId::new(NetworkType::Mainnet))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeEncoding {
+    #[default]
+    Borsh,
+    Json,
+}
+
+impl From<NodeEncoding> for WrpcEncoding {
+    fn from(encoding: NodeEncoding) -> Self {
+        match encoding {
+            NodeEncoding::Borsh => WrpcEncoding::Borsh,
+            NodeEncoding::Json => WrpcEncoding::SerdeJson,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeEndpoint {
+    pub url: String,
+    #[serde(default)]
+    pub encoding: NodeEncoding,
+}
+
+/// User-defined wRPC endpoints for one network, tried in order before
+/// (optionally) falling back to the public resolver.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeSettings {
+    #[serde(default)]
+    pub endpoints: Vec<NodeEndpoint>,
+    #[serde(default = "default_use_resolver")]
+    pub use_resolver: bool,
+}
+
+fn default_use_resolver() -> bool {
+    true
+}
+
+impl Default for NodeSettings {
+    fn default() -> Self {
+        NodeSettings {
+            endpoints: Vec::new(),
+            use_resolver: true,
+        }
+    }
+}
+
+/// When an open wallet locks itself and drops its secrets from memory.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LockSettings {
+    /// Minutes without user activity before locking; 0 disables the timer.
+    #[serde(default = "default_idle_minutes")]
+    pub idle_minutes: u32,
+    #[serde(default)]
+    pub lock_on_blur: bool,
+    #[serde(default = "default_lock_on_suspend")]
+    pub lock_on_suspend: bool,
+}
+
+fn default_idle_minutes() -> u32 {
+    15
+}
+
+fn default_lock_on_suspend() -> bool {
+    true
+}
+
+impl Default for LockSettings {
+    fn default() -> Self {
+        LockSettings {
+            idle_minutes: default_idle_minutes(),
+            lock_on_blur: false,
+            lock_on_suspend: default_lock_on_suspend(),
+        }
+    }
+}
+
+pub fn load_network<R: Runtime>(app: &AppHandle<R>) -> NetworkId {
+    let stored = app
+        .store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(NETWORK_KEY))
+        .and_then(|value| value.as_str().map(str::to_owned));
+
+    match stored.as_deref().map(str::parse::<NetworkId>) {
+        Some(Ok(network_id)) => network_id,
+        Some(Err(e)) => {
+            error!("Ignoring invalid stored network: {}", e);
+            NetworkId::new(NetworkType::Mainnet)
+        }
+        None => NetworkId::new(NetworkType::Mainnet),
+    }
+}
+
+pub fn save_network<R: Runtime>(app: &AppHandle<R>, network_id: NetworkId) -> Result<(), ErrorResponse> {
+    let store = app.store(SETTINGS_STORE)?;
+    store.set(NETWORK_KEY, json!(network_id.to_string()));
+    store.save()?;
+    info!("Persisted network selection: {}", network_id);
+    Ok(())
+}
+
+/// Key a wallet's metadata is stored under: its filename without any
+/// directory or `.wallet` extension, however the name was typed.
+pub fn wallet_meta_key(filename: &str) -> String {
+    let filename = filename.trim();
+    let name = Path::new(filename)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(filename);
+    name.strip_suffix(".wallet").unwrap_or(name).to_string()
+}
+
+pub fn load_wallet_meta<R: Runtime>(app: &AppHandle<R>, wallet_name: &str) -> WalletMeta {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(WALLETS_KEY))
+        .and_then(|wallets| wallets.get(wallet_name).cloned())
+        .and_then(|meta| serde_json::from_value(meta).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_wallet_meta<R: Runtime>(
+    app: &AppHandle<R>,
+    wallet_name: &str,
+    meta: &WalletMeta,
+) -> Result<(), ErrorResponse> {
+    let store = app.store(SETTINGS_STORE)?;
+    let mut wallets = match store.get(WALLETS_KEY) {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+
+    let value = serde_json::to_value(meta).map_err(|e| ErrorResponse { error: e.to_string() })?;
+    wallets.insert(wallet_name.to_string(), value);
+
+    store.set(WALLETS_KEY, Value::Object(wallets));
+    store.save()?;
+    Ok(())
+}
+
+pub fn load_node_settings<R: Runtime>(app: &AppHandle<R>, network_id: NetworkId) -> NodeSettings {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(NODES_KEY))
+        .and_then(|nodes| nodes.get(network_id.to_string()).cloned())
+        .and_then(|settings| serde_json::from_value(settings).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_node_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    network_id: NetworkId,
+    node_settings: &NodeSettings,
+) -> Result<(), ErrorResponse> {
+    let store = app.store(SETTINGS_STORE)?;
+    let mut nodes = match store.get(NODES_KEY) {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+
+    let value = serde_json::to_value(node_settings).map_err(|e| ErrorResponse { error: e.to_string() })?;
+    nodes.insert(network_id.to_string(), value);
+
+    store.set(NODES_KEY, Value::Object(nodes));
+    store.save()?;
+    info!(
+        "Persisted {} node endpoint(s) for {} (resolver: {})",
+        node_settings.endpoints.len(),
+        network_id,
+        node_settings.use_resolver
+    );
+    Ok(())
+}
+
+pub fn load_lock_settings<R: Runtime>(app: &AppHandle<R>) -> LockSettings {
+    app.store(SETTINGS_STORE)
+        .ok()
+        .and_then(|store| store.get(LOCK_KEY))
+        .and_then(|settings| serde_json::from_value(settings).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_lock_settings<R: Runtime>(app: &AppHandle<R>, lock_settings: &LockSettings) -> Result<(), ErrorResponse> {
+    let store = app.store(SETTINGS_STORE)?;
+    let value = serde_json::to_value(lock_settings).map_err(|e| ErrorResponse { error: e.to_string() })?;
+    store.set(LOCK_KEY, value);
+    store.save()?;
+    info!(
+        "Persisted lock settings: idle {} min, on blur: {}, on suspend: {}",
+        lock_settings.idle_minutes,
+        lock_settings.lock_on_blur,
+        lock_settings.lock_on_suspend
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_names_are_kept() {
+        assert_eq!(wallet_meta_key("savings"), "savings");
+    }
+
+    #[test]
+    fn wallet_extension_is_stripped_once() {
+        assert_eq!(wallet_meta_key("savings.wallet"), "savings");
+        assert_eq!(wallet_meta_key("savings.wallet.wallet"), "savings.wallet");
+        assert_eq!(wallet_meta_key("savings.backup"), "savings.backup");
+    }
+
+    #[test]
+    fn directories_are_dropped() {
+        assert_eq!(wallet_meta_key("/home/user/.vecno/savings.wallet"), "savings");
+        assert_eq!(wallet_meta_key("wallets/savings"), "savings");
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert_eq!(wallet_meta_key("  savings.wallet\n"), "savings");
+    }
+
+    #[test]
+    fn every_spelling_shares_one_key() {
+        let keys: Vec<String> = ["savings", "savings.wallet", " /tmp/savings.wallet "]
+            .iter()
+            .map(|name| wallet_meta_key(name))
+            .collect();
+        assert!(keys.iter().all(|key| key == "savings"));
+    }
+}
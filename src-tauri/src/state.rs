@@ -1,11 +1,17 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
-use tauri::async_runtime::Mutex;
+use std::time::Instant;
+use tauri::async_runtime::{JoinHandle, Mutex};
 use vecno_wallet_core::prelude::*;
 use vecno_wrpc_client::prelude::Resolver;
 use vecno_wallet_core::error::Error as WalletError;
 use vecno_wrpc_client::error::Error as WrpcError;
+use vecno_consensus_core::network::NetworkId;
+use workflow_core::prelude::Abortable;
+use crate::settings::NodeEncoding;
 use std::io;
+use zeroize::Zeroizing;
 
 #[derive(Serialize)]
 pub struct ErrorResponse {
@@ -15,20 +21,70 @@ pub struct ErrorResponse {
 #[derive(Serialize, Deserialize)]
 pub struct NodeInfo {
     pub url: String,
+    pub network: String,
+    pub encoding: Option<NodeEncoding>,
+    pub from_resolver: bool,
+    pub server_version: Option<String>,
+    pub is_synced: bool,
+    pub connected: bool,
 }
 
-#[derive(Default)]
+impl NodeInfo {
+    pub fn from_cache(cache: &NodeCache, network_id: NetworkId) -> Self {
+        NodeInfo {
+            url: cache.url.clone().unwrap_or_default(),
+            network: cache.network.clone().unwrap_or_else(|| network_id.to_string()),
+            encoding: cache.encoding,
+            from_resolver: cache.from_resolver,
+            server_version: cache.server_version.clone(),
+            is_synced: cache.is_synced,
+            connected: cache.connected,
+        }
+    }
+}
+
+/// Live record of the node endpoint the wallet is bound to.
+#[derive(Default, Clone, Debug)]
 pub struct NodeCache {
     pub url: Option<String>,
+    pub encoding: Option<NodeEncoding>,
+    pub from_resolver: bool,
+    pub server_version: Option<String>,
+    pub network: Option<String>,
+    pub is_synced: bool,
+    pub connected: bool,
 }
 
 pub struct AppState {
     pub wallet: Mutex<Option<Arc<Wallet>>>,
     pub resolver: Mutex<Option<Resolver>>,
     pub wallet_secret: Mutex<Option<Secret>>,
-    pub mnemonic: Mutex<Option<String>>,
+    /// Recovery phrase of the open wallet; only leaves the backend through
+    /// `reveal_mnemonic`.
+    pub mnemonic: Mutex<Option<Zeroizing<String>>>,
     pub node_cache: Mutex<NodeCache>,
-    pub bip39_seed: Mutex<Option<String>>,
+    /// Hex seed of wallets stored as a raw BIP39 seed rather than a phrase.
+    pub bip39_seed: Mutex<Option<Zeroizing<String>>>,
+    pub network_id: Mutex<NetworkId>,
+    pub node_monitor: Mutex<Option<JoinHandle<()>>>,
+    pub wallet_events: Mutex<Option<JoinHandle<()>>>,
+    /// Settings key (filename stem) of the open wallet.
+    pub wallet_name: Mutex<Option<String>>,
+    /// Outpoints (`txid:index`) excluded from coin selection.
+    pub frozen_utxos: Mutex<HashSet<String>>,
+    /// Abort handle of the compound run in progress, if any.
+    pub compound_abort: Mutex<Option<Abortable>>,
+    /// Accounts whose UTXO context has covered the full derivation window
+    /// on the current node connection.
+    pub scanned_accounts: Mutex<HashSet<AccountId>>,
+    /// Extra addresses a watch-only wallet monitors beyond its xpub.
+    pub watch_addresses: Mutex<Vec<Address>>,
+    /// Set while the open wallet is auto-locked with its secrets wiped.
+    pub locked: Mutex<bool>,
+    /// Last user activity reported by the frontend, for the idle lock.
+    pub last_activity: Mutex<Instant>,
+    /// 1-based word positions the recovery phrase quiz is asking for.
+    pub backup_challenge: Mutex<Option<Vec<usize>>>,
 }
 
 #[derive(Serialize, Debug, Deserialize)]
@@ -43,6 +99,7 @@ pub struct WalletAddress {
 pub struct WalletFile {
     pub name: String,
     pub path: String,
+    pub network: String,
 }
 
 impl From<WalletError> for ErrorResponse {
@@ -61,4 +118,10 @@ impl From<WrpcError> for ErrorResponse {
     fn from(err: WrpcError) -> Self {
         ErrorResponse { error: err.to_string() }
     }
+}
+
+impl From<tauri_plugin_store::Error> for ErrorResponse {
+    fn from(err: tauri_plugin_store::Error) -> Self {
+        ErrorResponse { error: err.to_string() }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,235 @@
+use crate::accounts::open_wallet;
+use crate::models::{FeePriority, SweepPrivateKeyInput};
+use crate::send_transactions::{estimate, fee_rate_for, fetch_fee_tiers};
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::private_key::{key_address, parse_private_key};
+use chrono::Utc;
+use log::info;
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{command, State};
+use vecno_addresses::Prefix;
+use vecno_bip32::secp256k1::SecretKey;
+use vecno_consensus_core::network::NetworkId;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::tx::generator::signer::{KeydataSigner, SignerT};
+use vecno_wallet_core::tx::generator::{Generator, GeneratorSettings};
+use vecno_wallet_core::tx::{Fees, PaymentDestination, PaymentOutput, PaymentOutputs};
+use vecno_wallet_core::utxo::{Maturity, UtxoEntryReference, UtxoEntryReferenceExtension};
+use vecno_wrpc_client::prelude::RpcApi;
+use workflow_core::prelude::Abortable;
+
+/// What an external key holds and what the open wallet would receive
+/// from sweeping it, shown before anything is signed.
+#[derive(Serialize, Clone, Debug)]
+pub struct SweepPreview {
+    pub address: String,
+    pub destination: String,
+    pub utxos: usize,
+    /// Mature funds that can be swept now.
+    pub balance: u64,
+    /// Coinbase or otherwise immature funds left behind by a sweep.
+    pub pending: u64,
+    pub amount: u64,
+    pub fees: u64,
+    pub transactions: usize,
+    pub fee_priority: FeePriority,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SweepResult {
+    pub txids: Vec<String>,
+    pub address: String,
+    pub destination: String,
+    pub amount: u64,
+    pub fees: u64,
+    pub timestamp: String,
+}
+
+/// The external key, its address and spendable UTXOs, and the open
+/// account's receive address the funds go to.
+struct SweepPlan {
+    wallet: Arc<Wallet>,
+    network_id: NetworkId,
+    secret_key: SecretKey,
+    source: Address,
+    destination: Address,
+    entries: Vec<UtxoEntryReference>,
+    pending: u64,
+}
+
+impl SweepPlan {
+    fn balance(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.amount()).sum()
+    }
+}
+
+async fn load_sweep(state: &AppState, private_key: &str) -> Result<SweepPlan, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let network_id = wallet.network_id()?;
+
+    let secret_key = parse_private_key(private_key)?;
+    let source = key_address(&secret_key, Prefix::from(network_id));
+
+    let account = wallet.account().map_err(ErrorResponse::from)?;
+    let destination = account.receive_address().map_err(ErrorResponse::from)?;
+    if destination == source {
+        return Err(ErrorResponse { error: "This key already belongs to the open account".into() });
+    }
+
+    let entries = wallet
+        .rpc_api()
+        .get_utxos_by_addresses(vec![source.clone()])
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to fetch UTXOs: {}", e) })?;
+
+    let params = wallet.utxo_processor().network_params()?;
+    let current_daa_score = wallet.current_daa_score().unwrap_or_default();
+
+    let (entries, immature): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .map(UtxoEntryReference::from)
+        .partition(|entry| matches!(entry.maturity(params, current_daa_score), Maturity::Confirmed));
+
+    Ok(SweepPlan {
+        wallet,
+        network_id,
+        secret_key,
+        source,
+        destination,
+        entries,
+        pending: immature.iter().map(|entry| entry.amount()).sum(),
+    })
+}
+
+/// Generator settings paying the whole mature balance to the destination,
+/// with fees deducted from the swept amount so no change is produced.
+fn sweep_settings(plan: &SweepPlan, fee_rate: Option<f64>) -> GeneratorSettings {
+    let destination = PaymentDestination::PaymentOutputs(PaymentOutputs {
+        outputs: vec![PaymentOutput::new(plan.destination.clone(), plan.balance())],
+    });
+
+    GeneratorSettings {
+        network_id: plan.network_id,
+        multiplexer: None,
+        utxo_iterator: Box::new(plan.entries.clone().into_iter()),
+        source_utxo_context: None,
+        priority_utxo_entries: None,
+        sig_op_count: 1,
+        minimum_signatures: 1,
+        change_address: plan.destination.clone(),
+        fee_rate,
+        final_transaction_priority_fee: Fees::ReceiverPays(0),
+        final_transaction_destination: destination,
+        final_transaction_payload: None,
+        destination_utxo_context: None,
+    }
+}
+
+/// Looks up the funds held by an external private key and estimates what a
+/// sweep into the open wallet would deliver.
+#[command]
+pub async fn scan_private_key(
+    input: SweepPrivateKeyInput,
+    state: State<'_, AppState>,
+) -> Result<SweepPreview, ErrorResponse> {
+    if *state.locked.lock().await {
+        return Err(ErrorResponse { error: "Unlock the wallet first".into() });
+    }
+    let plan = load_sweep(&state, &input.private_key).await?;
+    let fee_priority = input.fee_priority.unwrap_or_default();
+    let balance = plan.balance();
+
+    let (amount, fees, transactions) = if balance > 0 {
+        let tiers = fetch_fee_tiers(plan.wallet.rpc_api().as_ref()).await;
+        let summary = estimate(sweep_settings(&plan, fee_rate_for(&tiers, fee_priority)))?;
+        (
+            balance.saturating_sub(summary.aggregate_fees()),
+            summary.aggregate_fees(),
+            summary.number_of_generated_transactions(),
+        )
+    } else {
+        (0, 0, 0)
+    };
+
+    info!(
+        "scan_private_key: {} holds {} VENI in {} UTXO(s), {} VENI pending",
+        plan.source,
+        balance,
+        plan.entries.len(),
+        plan.pending
+    );
+
+    Ok(SweepPreview {
+        address: plan.source.to_string(),
+        destination: plan.destination.to_string(),
+        utxos: plan.entries.len(),
+        balance,
+        pending: plan.pending,
+        amount,
+        fees,
+        transactions,
+        fee_priority,
+    })
+}
+
+/// Moves everything an external private key holds to the open account's
+/// current receive address. The key is only held by a one-off signer for
+/// the duration of the call and is never written to the wallet.
+#[command]
+pub async fn sweep_private_key(
+    input: SweepPrivateKeyInput,
+    state: State<'_, AppState>,
+) -> Result<SweepResult, ErrorResponse> {
+    let plan = load_sweep(&state, &input.private_key).await?;
+    let fee_priority = input.fee_priority.unwrap_or_default();
+    let balance = plan.balance();
+    if balance == 0 {
+        return Err(ErrorResponse { error: "No mature funds to sweep".into() });
+    }
+
+    let rpc = plan.wallet.rpc_api();
+    let tiers = fetch_fee_tiers(rpc.as_ref()).await;
+    let settings = sweep_settings(&plan, fee_rate_for(&tiers, fee_priority));
+
+    let signer: Arc<dyn SignerT> = Arc::new(KeydataSigner::new(vec![(plan.source.clone(), plan.secret_key)]));
+    let abortable = Abortable::default();
+    let generator = Generator::try_new(settings, Some(signer), Some(&abortable))
+        .map_err(|e| ErrorResponse { error: format!("Generator creation failed: {e}") })?;
+
+    let mut txids = Vec::new();
+    for (i, pending_tx) in generator.iter().enumerate() {
+        let pending_tx = pending_tx
+            .map_err(|e| ErrorResponse { error: format!("Generator error at tx #{}: {e}", i + 1) })?;
+
+        pending_tx
+            .try_sign()
+            .map_err(|e| ErrorResponse { error: format!("Signing failed for tx #{}: {e}", i + 1) })?;
+
+        let txid = pending_tx
+            .try_submit(&rpc)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Submit failed for tx #{}: {e}", i + 1) })?;
+
+        txids.push(txid.to_string());
+    }
+
+    let fees = generator.summary().aggregate_fees();
+
+    info!(
+        "Swept {} VENI from {} to {} in {} transaction(s)",
+        balance,
+        plan.source,
+        plan.destination,
+        txids.len()
+    );
+
+    Ok(SweepResult {
+        txids,
+        address: plan.source.to_string(),
+        destination: plan.destination.to_string(),
+        amount: balance.saturating_sub(fees),
+        fees,
+        timestamp: Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+    })
+}
@@ -0,0 +1,115 @@
+use crate::get_transactions::maturity_label;
+use crate::models::FreezeUtxosInput;
+use crate::accounts::scan_account;
+use crate::send_transactions::outpoint_key;
+use crate::settings;
+use crate::state::{AppState, ErrorResponse};
+use log::info;
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{command, AppHandle, State};
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::utxo::{UtxoEntryReference, UtxoEntryReferenceExtension};
+use vecno_wrpc_client::prelude::RpcApi;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct UtxoInfo {
+    pub outpoint: String,
+    pub amount: u64,
+    pub address: Option<String>,
+    pub block_daa_score: u64,
+    pub maturity: String,
+    pub is_coinbase: bool,
+    pub frozen: bool,
+}
+
+/// Every UTXO held by the selected account, largest first, including
+/// pending and coinbase outputs that cannot be spent yet.
+#[command]
+pub async fn list_utxos(state: State<'_, AppState>) -> Result<Vec<UtxoInfo>, ErrorResponse> {
+    let wallet_guard = state.wallet.lock().await;
+    let wallet = wallet_guard.as_ref().ok_or(ErrorResponse {
+        error: "Wallet is not open".to_string(),
+    })?.clone();
+    drop(wallet_guard);
+
+    if !wallet.is_open() {
+        return Err(ErrorResponse {
+            error: "Wallet is not open".to_string(),
+        });
+    }
+
+    let account: Arc<dyn Account> = wallet.account().map_err(ErrorResponse::from)?;
+    let utxo_context = scan_account(&state, &account).await?;
+    let params = utxo_context.processor().network_params()?;
+
+    let addresses: Vec<Address> = utxo_context
+        .addresses()
+        .iter()
+        .map(|address| address.as_ref().clone())
+        .collect();
+    let entries = wallet
+        .rpc_api()
+        .get_utxos_by_addresses(addresses)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to fetch UTXOs: {}", e) })?;
+
+    let current_daa_score = wallet.current_daa_score().unwrap_or_default();
+    let frozen = state.frozen_utxos.lock().await.clone();
+
+    let mut utxos: Vec<UtxoInfo> = entries
+        .into_iter()
+        .map(UtxoEntryReference::from)
+        .map(|entry| {
+            let outpoint = outpoint_key(&entry);
+            UtxoInfo {
+                frozen: frozen.contains(&outpoint),
+                outpoint,
+                amount: entry.amount(),
+                address: entry.utxo.address.as_ref().map(|a| a.to_string()),
+                block_daa_score: entry.block_daa_score(),
+                maturity: maturity_label(entry.maturity(params, current_daa_score)).to_string(),
+                is_coinbase: entry.is_coinbase(),
+            }
+        })
+        .collect();
+
+    utxos.sort_by(|a, b| b.amount.cmp(&a.amount).then_with(|| a.outpoint.cmp(&b.outpoint)));
+
+    info!("Listed {} UTXO(s), {} frozen", utxos.len(), utxos.iter().filter(|u| u.frozen).count());
+    Ok(utxos)
+}
+
+/// Freezes or unfreezes outpoints and persists the frozen set with the
+/// wallet's settings. Returns the updated frozen set.
+#[command]
+pub async fn set_utxos_frozen(
+    input: FreezeUtxosInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<Vec<String>, ErrorResponse> {
+    let wallet_name = state.wallet_name.lock().await.clone().ok_or(ErrorResponse {
+        error: "Wallet is not open".to_string(),
+    })?;
+
+    let mut frozen_guard = state.frozen_utxos.lock().await;
+    for outpoint in input.outpoints {
+        let outpoint = outpoint.trim().to_string();
+        if input.frozen {
+            frozen_guard.insert(outpoint);
+        } else {
+            frozen_guard.remove(&outpoint);
+        }
+    }
+
+    let mut frozen: Vec<String> = frozen_guard.iter().cloned().collect();
+    frozen.sort();
+    drop(frozen_guard);
+
+    let mut meta = settings::load_wallet_meta(&app, &wallet_name);
+    meta.frozen_utxos = frozen.clone();
+    settings::save_wallet_meta(&app, &wallet_name, &meta)?;
+
+    info!("Wallet '{}' now has {} frozen UTXO(s)", wallet_name, frozen.len());
+    Ok(frozen)
+}
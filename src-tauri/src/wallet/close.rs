@@ -1,3 +1,5 @@
+use crate::events;
+use crate::monitor;
 use crate::state::{AppState, ErrorResponse, NodeCache};
 use tauri::{command, AppHandle, State, Manager};
 use log::{error, info};
@@ -9,6 +11,13 @@ pub async fn close_wallet(
 ) -> Result<(), ErrorResponse> {
     info!("Closing wallet and exiting app");
 
+    monitor::stop(&state).await;
+    events::stop(&state).await;
+
+    if let Some(abortable) = state.compound_abort.lock().await.as_ref() {
+        abortable.abort();
+    }
+
     {
         let wallet_guard = state.wallet.lock().await;
         if let Some(wallet) = wallet_guard.as_ref() {
@@ -18,6 +27,10 @@ pub async fn close_wallet(
                 }
             }
 
+            if let Err(e) = wallet.stop().await {
+                error!("Failed to stop wallet services: {}", e);
+            }
+
             if let Some(wrpc_client) = wallet.try_wrpc_client() {
                 if let Err(e) = wrpc_client.disconnect().await {
                     error!("Failed to disconnect wRPC client: {}", e);
@@ -42,6 +55,11 @@ pub async fn close_wallet(
     let mut node_cache = state.node_cache.lock().await;
     *node_cache = NodeCache::default();
 
+    *state.wallet_name.lock().await = None;
+    state.frozen_utxos.lock().await.clear();
+    state.scanned_accounts.lock().await.clear();
+    state.watch_addresses.lock().await.clear();
+
     info!("Wallet closed. Requesting graceful shutdown...");
 
     if let Some(window) = app.get_webview_window("main") {
@@ -1,22 +1,26 @@
+use crate::accounts;
 use crate::models::CreateWalletInput;
+use crate::events;
+use crate::monitor;
+use crate::node;
+use crate::settings::{self, WalletMeta};
 use crate::state::{AppState, ErrorResponse};
-use bip39::{Language, Mnemonic};
-use log::{info, error};
-use rand::RngCore;
+use crate::wallet::mnemonic::{generate_phrase, key_data_args, language_code};
+use bip39::Language;
+use log::{info, warn};
 use std::sync::Arc;
-use tauri::{command, State};
-use vecno_consensus_core::network::{NetworkId, NetworkType};
+use zeroize::Zeroizing;
+use tauri::{command, AppHandle, State};
 use vecno_wallet_core::prelude::*;
 use vecno_wallet_core::storage::interface::CreateArgs;
-use vecno_wallet_core::wallet::args::{AccountCreateArgs, PrvKeyDataCreateArgs};
-use vecno_wallet_core::storage::keydata::PrvKeyDataVariantKind;
-use vecno_wrpc_client::prelude::{ConnectOptions, ConnectStrategy, Resolver, WrpcEncoding};
+use vecno_wallet_core::wallet::args::AccountCreateArgs;
 use vecno_wallet_core::settings::application_folder;
 
 #[command]
 pub async fn create_wallet(
     input: CreateWalletInput,
     state: State<'_, AppState>,
+    app: AppHandle,
 ) -> Result<String, ErrorResponse> {
     let secret = input.secret.trim();
     let filename = input.filename.trim();
@@ -35,13 +39,14 @@ pub async fn create_wallet(
         return Err(ErrorResponse { error: "Wallet filename is required".into() });
     }
 
-    let mut entropy = [0u8; 32];
-    rand::thread_rng().fill_bytes(&mut entropy);
-    let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
-        .map_err(|e| ErrorResponse { error: e.to_string() })?
-        .to_string();
+    let phrase = generate_phrase(input.word_count, input.language.as_deref())?;
+    let mnemonic = Zeroizing::new(phrase.to_string());
 
-    info!("Generated 24-word mnemonic");
+    info!(
+        "Generated {}-word mnemonic ({})",
+        phrase.word_count(),
+        language_code(phrase.language())
+    );
 
     let payment_secret_opt = payment_passphrase
         .filter(|s| !s.is_empty())
@@ -72,48 +77,22 @@ pub async fn create_wallet(
         .await
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
 
-    let network_id = NetworkId::new(NetworkType::Mainnet);
-    let resolver = Resolver::default();
+    let network_id = *state.network_id.lock().await;
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let (wallet, node) = node::connect_wallet(store.clone(), &node_settings, network_id).await?;
+    info!("Connected to node {:?}", node.url);
 
-    let wallet = Arc::new(
-        Wallet::try_new(store.clone(), Some(resolver.clone()), Some(network_id))
-            .map_err(|e| ErrorResponse { error: format!("Wallet init failed: {}", e) })?,
-    );
-
-    if let Some(wrpc) = wallet.try_wrpc_client().as_ref() {
-        let url = resolver
-            .get_url(WrpcEncoding::Borsh, network_id)
-            .await
-            .map_err(|e| ErrorResponse { error: format!("Node resolve failed: {}", e) })?;
-
-        let opts = ConnectOptions {
-            block_async_connect: true,
-            strategy: ConnectStrategy::Fallback,
-            url: Some(url),
-            ..Default::default()
-        };
-
-        wrpc.connect(Some(opts)).await
-            .map_err(|e| {
-                error!("Node connection failed: {}", e);
-                ErrorResponse {
-                    error: "Failed to connect to Vecno node. Check your internet connection or try again later.".into()
-                }
-            })?;
-        info!("Connected to node");
-    } else {
-        return Err(ErrorResponse { error: "No wRPC client".into() });
-    }
+    wallet.start().await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start wallet services: {}", e) })?;
 
     if !wallet.is_open() {
         return Err(ErrorResponse { error: "Wallet failed to open".into() });
     }
-    let prv_key_data_args = PrvKeyDataCreateArgs {
-        name: None,
-        payment_secret: payment_secret_opt.clone(),
-        secret: Secret::from(mnemonic.clone()),
-        kind: PrvKeyDataVariantKind::Mnemonic,
-    };
+    let prv_key_data_args = key_data_args(
+        &phrase,
+        payment_passphrase.unwrap_or(""),
+        payment_secret_opt.clone(),
+    );
 
     let key_id = wallet
         .create_prv_key_data(&wallet_secret, prv_key_data_args)
@@ -146,19 +125,37 @@ pub async fn create_wallet(
     store.flush(&wallet_secret).await
         .map_err(|e| ErrorResponse { error: format!("Flush failed: {}", e) })?;
 
+    let wallet_name = settings::wallet_meta_key(filename);
+    settings::save_wallet_meta(&app, &wallet_name, &WalletMeta {
+        network: Some(network_id.to_string()),
+        backup_pending: true,
+        seed_only: phrase.language() != Language::English,
+        ..Default::default()
+    })?;
+
     {
         let mut w = state.wallet.lock().await;
-        let mut r = state.resolver.lock().await;
         let mut s = state.wallet_secret.lock().await;
         let mut m = state.mnemonic.lock().await;
+        let mut n = state.node_cache.lock().await;
 
         *w = Some(wallet.clone());
-        *r = Some(resolver);
         *s = Some(wallet_secret);
-        *m = Some(mnemonic.clone());
+        *m = Some(mnemonic);
+        *n = node;
+        *state.wallet_name.lock().await = Some(wallet_name);
+        state.frozen_utxos.lock().await.clear();
+        state.scanned_accounts.lock().await.clear();
+    }
+
+    if let Err(e) = accounts::scan_account(&state, &account).await {
+        warn!("Initial scan of account {} failed: {}", account.name_or_id(), e.error);
     }
+    monitor::start(&app, &wallet, network_id).await;
+    events::start(&app, &wallet).await;
 
     info!("Wallet successfully created at {}", storage_path.display());
 
-    Ok(format!("Success: Wallet created at {} with mnemonic: {}", storage_path.display(), mnemonic))
+    // The phrase is fetched separately through `reveal_mnemonic`.
+    Ok(format!("Success: Wallet created at {}", storage_path.display()))
 }
\ No newline at end of file
@@ -1,25 +1,29 @@
+use crate::accounts;
+use crate::events;
+use crate::monitor;
+use crate::node;
+use crate::settings::{self, WalletMeta};
 use crate::state::{AppState, ErrorResponse};
-use tauri::{command, State};
+use tauri::{command, AppHandle, State};
 use vecno_wallet_core::prelude::*;
 use crate::models::ImportWalletInput;
 use vecno_wallet_core::storage::local::{Storage, Payload};
 use vecno_wallet_core::storage::interface::CreateArgs;
-use vecno_wallet_core::wallet::args::{AccountCreateArgsBip32, PrvKeyDataCreateArgs};
+use vecno_wallet_core::wallet::args::AccountCreateArgsBip32;
 use vecno_wallet_core::storage::local::WalletStorage;
-use vecno_wallet_core::storage::keydata::PrvKeyDataVariantKind;
-use bip39::Mnemonic;
-use log::{error, info};
-use std::sync::Arc;
-use vecno_wrpc_client::prelude::{Resolver, WrpcEncoding, ConnectOptions, ConnectStrategy};
-use vecno_consensus_core::network::{NetworkId, NetworkType};
+use crate::wallet::mnemonic::{key_data_args, language_code, parse_phrase};
+use zeroize::Zeroizing;
+use log::{info, warn};
 use vecno_wallet_core::settings::application_folder;
 
 #[command]
 pub async fn import_wallets(
     input: ImportWalletInput,
     state: State<'_, AppState>,
+    app: AppHandle,
 ) -> Result<String, ErrorResponse> {
-    let mnemonic = input.mnemonic.trim().to_string();
+    let input_mnemonic = Zeroizing::new(input.mnemonic);
+    let mnemonic = Zeroizing::new(input_mnemonic.trim().to_string());
     let secret = input.secret;
     let payment_secret = input.payment_secret;
     let filename = input.filename;
@@ -34,20 +38,13 @@ pub async fn import_wallets(
         return Err(ErrorResponse { error: "Wallet filename is required".into() });
     }
 
-    let words: Vec<&str> = mnemonic.split_whitespace().collect();
-    if words.len() != 12 && words.len() != 24 {
-        return Err(ErrorResponse { error: "Mnemonic must be exactly 12 or 24 words".into() });
-    }
-
-    let mnemonic = Mnemonic::parse(&mnemonic).map_err(|e| {
-        error!("Invalid mnemonic: {}", e);
-        ErrorResponse { error: "Invalid mnemonic format".into() }
-    })?;
+    let mnemonic = parse_phrase(mnemonic.as_str())?;
+    let language = language_code(mnemonic.language());
+    info!("Importing {}-word mnemonic ({})", mnemonic.word_count(), language);
 
     let passphrase = payment_secret.as_deref().unwrap_or("").trim();
-    let _ = mnemonic.to_seed(passphrase);
 
-    let network_id = NetworkId::new(NetworkType::Mainnet);
+    let network_id = *state.network_id.lock().await;
     let wallet_dir = application_folder().map_err(|e| ErrorResponse { error: e.to_string() })?;
     let storage_path = wallet_dir.join(&filename);
 
@@ -69,32 +66,12 @@ pub async fn import_wallets(
     store.create(&wallet_secret, create_args).await
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
 
-    let resolver = Resolver::default();
-    let wallet = Arc::new(
-        Wallet::try_new(store.clone(), Some(resolver.clone()), Some(network_id))
-            .map_err(|e| ErrorResponse { error: format!("Wallet init failed: {}", e) })?
-    );
-
-    if let Some(wrpc_client) = wallet.try_wrpc_client().as_ref() {
-        let url = resolver.get_url(WrpcEncoding::Borsh, network_id).await
-            .map_err(|e| ErrorResponse { error: format!("Node resolve failed: {}", e) })?;
-
-        let options = ConnectOptions {
-            block_async_connect: true,
-            strategy: ConnectStrategy::Fallback,
-            url: Some(url),
-            ..Default::default()
-        };
-        wrpc_client.connect(Some(options)).await
-            .map_err(|e| {
-                error!("Node connection failed: {}", e);
-                ErrorResponse {
-                    error: "Failed to connect to Vecno node. Check your internet connection or try again later.".into()
-                }
-            })?;
-    } else {
-        return Err(ErrorResponse { error: "No wRPC client".into() });
-    }
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let (wallet, node) = node::connect_wallet(store.clone(), &node_settings, network_id).await?;
+    info!("Connected to node {:?}", node.url);
+
+    wallet.start().await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start wallet services: {}", e) })?;
 
     if !wallet.is_open() {
         return Err(ErrorResponse { error: "Wallet not open after init".into() });
@@ -102,12 +79,7 @@ pub async fn import_wallets(
 
     let stored_payment_secret = (!passphrase.is_empty()).then(|| Secret::new(passphrase.as_bytes().to_vec()));
 
-    let prv_key_data = PrvKeyDataCreateArgs {
-        name: None,
-        payment_secret: stored_payment_secret.clone(),
-        secret: Secret::new(mnemonic.to_string().into_bytes()),
-        kind: PrvKeyDataVariantKind::Mnemonic,
-    };
+    let prv_key_data = key_data_args(&mnemonic, passphrase, stored_payment_secret.clone());
 
     let key_id = wallet.create_prv_key_data(&wallet_secret, prv_key_data).await
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
@@ -141,18 +113,33 @@ pub async fn import_wallets(
     wallet_storage.try_store(&storage).await
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
 
+    let wallet_name = settings::wallet_meta_key(&filename);
+    settings::save_wallet_meta(&app, &wallet_name, &WalletMeta {
+        network: Some(network_id.to_string()),
+        ..Default::default()
+    })?;
+
     {
         let mut wallet_state = state.wallet.lock().await;
-        let mut resolver_state = state.resolver.lock().await;
         let mut secret_state = state.wallet_secret.lock().await;
         let mut mnemonic_state = state.mnemonic.lock().await;
+        let mut node_state = state.node_cache.lock().await;
 
         *wallet_state = Some(wallet.clone());
-        *resolver_state = Some(resolver);
         *secret_state = Some(wallet_secret);
-        *mnemonic_state = Some(mnemonic.to_string());
+        *mnemonic_state = Some(Zeroizing::new(mnemonic.to_string()));
+        *node_state = node;
+        *state.wallet_name.lock().await = Some(wallet_name);
+        state.frozen_utxos.lock().await.clear();
+        state.scanned_accounts.lock().await.clear();
+    }
+
+    if let Err(e) = accounts::scan_account(&state, &account).await {
+        warn!("Initial scan of account {} failed: {}", account.name_or_id(), e.error);
     }
+    monitor::start(&app, &wallet, network_id).await;
+    events::start(&app, &wallet).await;
 
     info!("Wallet imported successfully at {}", storage_path.display());
-    Ok(format!("Success: Wallet imported at {}", storage_path.display()))
+    Ok(format!("Success: Wallet imported at {} ({} wordlist)", storage_path.display(), language))
 }
\ No newline at end of file
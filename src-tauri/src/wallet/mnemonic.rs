@@ -0,0 +1,351 @@
+use crate::accounts::open_wallet;
+use crate::models::{ConfirmMnemonicBackupInput, DetectMnemonicInput, RevealMnemonicInput};
+use crate::settings;
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::password::{verify_password, wallet_file};
+use bip39::{Language, Mnemonic};
+use futures_lite::stream::StreamExt;
+use log::{error, info, warn};
+use rand::seq::index;
+use rand::RngCore;
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{command, AppHandle, State};
+use unicode_normalization::UnicodeNormalization;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::storage::keydata::{PrvKeyDataVariant, PrvKeyDataVariantKind};
+use vecno_wallet_core::wallet::args::PrvKeyDataCreateArgs;
+use zeroize::Zeroizing;
+
+/// Number of words the backup quiz asks for.
+const CHALLENGE_WORDS: usize = 4;
+
+/// BIP39 wordlists a phrase can use, keyed by the code the frontend sends.
+const LANGUAGES: [(&str, Language); 10] = [
+    ("english", Language::English),
+    ("spanish", Language::Spanish),
+    ("french", Language::French),
+    ("italian", Language::Italian),
+    ("portuguese", Language::Portuguese),
+    ("czech", Language::Czech),
+    ("japanese", Language::Japanese),
+    ("korean", Language::Korean),
+    ("chinese-simplified", Language::SimplifiedChinese),
+    ("chinese-traditional", Language::TraditionalChinese),
+];
+
+/// Wordlist and length of a phrase, as reported to the import screen.
+#[derive(Serialize, Clone, Debug)]
+pub struct MnemonicLanguage {
+    pub language: &'static str,
+    pub word_count: usize,
+}
+
+/// Looks up a wordlist by code; no code means English.
+pub(crate) fn parse_language(code: Option<&str>) -> Result<Language, ErrorResponse> {
+    let Some(code) = code.map(str::trim).filter(|c| !c.is_empty()) else {
+        return Ok(Language::English);
+    };
+    LANGUAGES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(code))
+        .map(|(_, language)| *language)
+        .ok_or_else(|| ErrorResponse { error: format!("Unsupported wordlist: {}", code) })
+}
+
+pub(crate) fn language_code(language: Language) -> &'static str {
+    LANGUAGES
+        .iter()
+        .find(|(_, l)| *l == language)
+        .map(|(name, _)| *name)
+        .unwrap_or("english")
+}
+
+/// Generates a new 12 or 24 word phrase; 24 words and English unless asked
+/// otherwise.
+pub(crate) fn generate_phrase(word_count: Option<usize>, language: Option<&str>) -> Result<Mnemonic, ErrorResponse> {
+    let entropy_len = match word_count.unwrap_or(24) {
+        12 => 16,
+        24 => 32,
+        n => return Err(ErrorResponse { error: format!("Recovery phrases have 12 or 24 words, not {}", n) }),
+    };
+    let language = parse_language(language)?;
+
+    let mut entropy = Zeroizing::new([0u8; 32]);
+    rand::thread_rng().fill_bytes(&mut entropy[..entropy_len]);
+    Mnemonic::from_entropy_in(language, &entropy[..entropy_len])
+        .map_err(|e| ErrorResponse { error: e.to_string() })
+}
+
+/// Parses a typed or pasted phrase in any BIP39 wordlist, detecting which.
+pub(crate) fn parse_phrase(phrase: &str) -> Result<Mnemonic, ErrorResponse> {
+    let word_count = phrase.split_whitespace().count();
+    if word_count != 12 && word_count != 24 {
+        return Err(ErrorResponse { error: "Mnemonic must be exactly 12 or 24 words".into() });
+    }
+    Mnemonic::parse(phrase).map_err(|e| {
+        error!("Invalid mnemonic: {}", e);
+        ErrorResponse { error: "Invalid mnemonic format".into() }
+    })
+}
+
+/// Key data for a recovery phrase. wallet-core only reads English phrases
+/// back, so phrases in other wordlists are stored as the BIP39 seed they
+/// derive with `passphrase`, the same seed an English phrase would give
+/// under that payment secret. Only the seed survives in the file, so
+/// those words cannot be revealed again once the session ends.
+pub(crate) fn key_data_args(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    payment_secret: Option<Secret>,
+) -> PrvKeyDataCreateArgs {
+    let (secret, kind) = if mnemonic.language() == Language::English {
+        let phrase = Zeroizing::new(mnemonic.to_string());
+        (Secret::from(phrase.as_str()), PrvKeyDataVariantKind::Mnemonic)
+    } else {
+        let seed = Zeroizing::new(mnemonic.to_seed(passphrase));
+        let seed = Zeroizing::new(hex::encode(seed.as_ref()));
+        (Secret::from(seed.as_str()), PrvKeyDataVariantKind::Bip39Seed)
+    };
+    PrvKeyDataCreateArgs { name: None, payment_secret, secret, kind }
+}
+
+/// Reports which wordlist a phrase uses so the import screen can show it
+/// before the wallet is created.
+#[command]
+pub async fn detect_mnemonic_language(input: DetectMnemonicInput) -> Result<MnemonicLanguage, ErrorResponse> {
+    let phrase = Zeroizing::new(input.mnemonic);
+    let mnemonic = parse_phrase(phrase.trim())?;
+    Ok(MnemonicLanguage {
+        language: language_code(mnemonic.language()),
+        word_count: mnemonic.word_count(),
+    })
+}
+
+/// Decrypts the recovery phrase from the wallet's key data. Phrases
+/// stored under a payment secret only open with that secret. `None` means
+/// the key holds only the seed of a phrase outside the English wordlist.
+async fn decrypt_mnemonic(
+    wallet: &Arc<Wallet>,
+    wallet_secret: &Secret,
+    payment_secret: Option<&Secret>,
+) -> Result<Option<Zeroizing<String>>, ErrorResponse> {
+    let prv_store = wallet
+        .store()
+        .as_prv_key_data_store()
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    // A key that does not open may still be the phrase, so report that
+    // rather than "no recovery phrase" when nothing else matches.
+    let mut undecryptable = false;
+    let mut seeded = false;
+    let mut keys = prv_store.iter().await.map_err(|e| ErrorResponse { error: e.to_string() })?;
+    while let Some(info) = keys.try_next().await.map_err(|e| ErrorResponse { error: e.to_string() })? {
+        let Some(key) = prv_store
+            .load_key_data(wallet_secret, &info.id)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Failed to load key data: {}", e) })?
+        else {
+            continue;
+        };
+
+        let Ok(payload) = key.payload.decrypt(payment_secret) else {
+            undecryptable = true;
+            continue;
+        };
+
+        match &*payload.as_variant() {
+            PrvKeyDataVariant::Mnemonic(phrase) => return Ok(Some(Zeroizing::new(phrase.trim().to_owned()))),
+            PrvKeyDataVariant::Bip39Seed(_) => seeded = true,
+            _ => {}
+        }
+    }
+
+    if seeded {
+        return Ok(None);
+    }
+
+    let error = match (undecryptable, payment_secret.is_some()) {
+        (true, true) => "Incorrect payment secret",
+        (true, false) => "This recovery phrase is protected by a payment secret",
+        (false, _) => "This wallet has no recovery phrase",
+    };
+    Err(ErrorResponse { error: error.into() })
+}
+
+/// Returns the open wallet's recovery phrase after checking the wallet
+/// password against the file. The phrase is decrypted for this call only
+/// and this is the only command that sends it to the frontend.
+#[command]
+pub async fn reveal_mnemonic(
+    input: RevealMnemonicInput,
+    state: State<'_, AppState>,
+) -> Result<Zeroizing<String>, ErrorResponse> {
+    let input_secret = Zeroizing::new(input.secret);
+    if input_secret.is_empty() {
+        return Err(ErrorResponse { error: "Wallet password is required".into() });
+    }
+    if *state.locked.lock().await {
+        return Err(ErrorResponse { error: "Unlock the wallet first".into() });
+    }
+    let payment_secret = input
+        .payment_secret
+        .map(Zeroizing::new)
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| Secret::new(s.trim().as_bytes().to_vec()));
+
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let path = wallet_file(&wallet)?;
+    let wallet_secret = Secret::new(input_secret.as_bytes().to_vec());
+    verify_password(&path, &wallet_secret).await?;
+
+    let mnemonic = match decrypt_mnemonic(&wallet, &wallet_secret, payment_secret.as_ref()).await? {
+        Some(mnemonic) => mnemonic,
+        // The words of a seed-stored phrase are only held by the session
+        // that created or imported the wallet.
+        None => state.mnemonic.lock().await.clone().ok_or(ErrorResponse {
+            error: "This wallet only keeps the seed of its recovery phrase, so the words cannot be shown again".into(),
+        })?,
+    };
+
+    info!("Recovery phrase revealed for {}", path.display());
+    Ok(mnemonic)
+}
+
+/// Whether the open wallet was created here and its recovery phrase has
+/// not been confirmed yet. A phrase stored only as its seed can no longer
+/// be checked once the session that created it is gone, so it stops
+/// counting as pending then.
+#[command]
+pub async fn mnemonic_backup_pending(state: State<'_, AppState>, app: AppHandle) -> Result<bool, ErrorResponse> {
+    let Some(wallet_name) = state.wallet_name.lock().await.clone() else {
+        return Ok(false);
+    };
+    let meta = settings::load_wallet_meta(&app, &wallet_name);
+    if meta.seed_only && state.mnemonic.lock().await.is_none() {
+        return Ok(false);
+    }
+    Ok(meta.backup_pending)
+}
+
+/// Word positions the backup quiz asks for, and whether the answers must
+/// come with the payment secret the phrase is stored under.
+#[derive(Serialize, Clone, Debug)]
+pub struct BackupChallenge {
+    pub positions: Vec<usize>,
+    pub payment_secret_required: bool,
+}
+
+/// Recovery phrase the backup quiz is checked against: the one this
+/// session created, or else the one decrypted from the wallet's key data.
+async fn backup_phrase(state: &AppState, payment_secret: Option<&Secret>) -> Result<Zeroizing<String>, ErrorResponse> {
+    if let Some(mnemonic) = state.mnemonic.lock().await.clone() {
+        return Ok(mnemonic);
+    }
+    if *state.locked.lock().await {
+        return Err(ErrorResponse { error: "Unlock the wallet first".into() });
+    }
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let wallet_secret = state.wallet_secret.lock().await.clone().ok_or(ErrorResponse {
+        error: "Wallet secret missing".into(),
+    })?;
+    decrypt_mnemonic(&wallet, &wallet_secret, payment_secret).await?.ok_or(ErrorResponse {
+        error: "This wallet only keeps the seed of its recovery phrase, so the words cannot be checked".into(),
+    })
+}
+
+/// Whether any of the open wallet's keys is stored under a payment secret.
+async fn has_payment_secret(state: &AppState) -> Result<bool, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let prv_store = wallet
+        .store()
+        .as_prv_key_data_store()
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let mut keys = prv_store.iter().await.map_err(|e| ErrorResponse { error: e.to_string() })?;
+    while let Some(info) = keys.try_next().await.map_err(|e| ErrorResponse { error: e.to_string() })? {
+        if info.is_encrypted() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Picks the word positions the backup quiz asks for. The positions are
+/// kept until the quiz passes, so reloading the screen does not reroll them.
+#[command]
+pub async fn get_backup_challenge(state: State<'_, AppState>) -> Result<BackupChallenge, ErrorResponse> {
+    let (word_count, payment_secret_required) = match backup_phrase(&state, None).await {
+        Ok(mnemonic) => (mnemonic.split_whitespace().count(), false),
+        // A phrase under a payment secret stays unreadable until the quiz
+        // is answered. Every phrase has at least 12 words, so positions
+        // up to 12 suit it whatever its length.
+        Err(_) if has_payment_secret(&state).await? => (12, true),
+        Err(e) => return Err(e),
+    };
+
+    let mut challenge = state.backup_challenge.lock().await;
+    if let Some(positions) = challenge.as_ref().filter(|positions| positions.iter().all(|p| *p <= word_count)) {
+        return Ok(BackupChallenge { positions: positions.clone(), payment_secret_required });
+    }
+
+    let mut positions: Vec<usize> = index::sample(&mut rand::thread_rng(), word_count, CHALLENGE_WORDS.min(word_count))
+        .into_iter()
+        .map(|i| i + 1)
+        .collect();
+    positions.sort_unstable();
+    *challenge = Some(positions.clone());
+    Ok(BackupChallenge { positions, payment_secret_required })
+}
+
+/// Checks the quiz answers against the recovery phrase and, when they all
+/// match, records the wallet as backed up.
+#[command]
+pub async fn confirm_mnemonic_backup(
+    input: ConfirmMnemonicBackupInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<(), ErrorResponse> {
+    let wallet_name = state.wallet_name.lock().await.clone().ok_or(ErrorResponse {
+        error: "Wallet is not open".into(),
+    })?;
+    let positions = state.backup_challenge.lock().await.clone().ok_or(ErrorResponse {
+        error: "No backup check in progress".into(),
+    })?;
+    if input.words.len() != positions.len() {
+        return Err(ErrorResponse {
+            error: format!("Enter all {} requested words", positions.len()),
+        });
+    }
+    let payment_secret = input
+        .payment_secret
+        .map(Zeroizing::new)
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| Secret::new(s.trim().as_bytes().to_vec()));
+
+    let mnemonic = backup_phrase(&state, payment_secret.as_ref()).await?;
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let matches = positions
+        .iter()
+        .zip(&input.words)
+        .all(|(position, answer)| words.get(position - 1).is_some_and(|word| same_word(answer, word)));
+    if !matches {
+        warn!("Recovery phrase check failed for {}", wallet_name);
+        return Err(ErrorResponse {
+            error: "Those words do not match your recovery phrase".into(),
+        });
+    }
+
+    let mut meta = settings::load_wallet_meta(&app, &wallet_name);
+    meta.backup_pending = false;
+    settings::save_wallet_meta(&app, &wallet_name, &meta)?;
+    *state.backup_challenge.lock().await = None;
+
+    info!("Recovery phrase backup confirmed for {}", wallet_name);
+    Ok(())
+}
+
+/// Compares a typed word with the phrase, ignoring case and whether
+/// accents were typed composed or decomposed.
+fn same_word(answer: &str, word: &str) -> bool {
+    answer.trim().nfkd().flat_map(char::to_lowercase).eq(word.nfkd().flat_map(char::to_lowercase))
+}
@@ -2,4 +2,8 @@ pub mod close;
 pub mod create;
 pub mod open;
 pub mod import;
-pub mod switch;
\ No newline at end of file
+pub mod switch;
+pub mod watch_only;
+pub mod private_key;
+pub mod password;
+pub mod mnemonic;
\ No newline at end of file
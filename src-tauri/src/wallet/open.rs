@@ -1,22 +1,26 @@
+use crate::accounts;
 use crate::models::OpenWalletInput;
+use crate::events;
+use crate::monitor;
+use crate::node;
+use crate::settings;
 use crate::state::{AppState, ErrorResponse};
-use log::{info, error};
+use log::{info, warn};
 use std::path::Path;
 use std::sync::Arc;
-use tauri::{command, State};
-use vecno_consensus_core::network::{NetworkId, NetworkType};
+use tauri::{command, AppHandle, State};
 use vecno_wallet_core::prelude::*;
 use vecno_wallet_core::storage::interface::OpenArgs;
 use vecno_wallet_core::storage::local::{Storage, WalletStorage};
 use vecno_wallet_core::storage::keydata::PrvKeyDataVariant;
-use vecno_wrpc_client::prelude::{ConnectOptions, ConnectStrategy, Resolver, WrpcEncoding};
 use futures_lite::stream::StreamExt;
-use bip39::Mnemonic;
+use zeroize::Zeroizing;
 
 #[command]
 pub async fn open_wallet(
     input: OpenWalletInput,
     state: State<'_, AppState>,
+    app: AppHandle,
 ) -> Result<String, ErrorResponse> {
     let filename = input.filename.trim();
     let secret = input.secret.trim();
@@ -73,45 +77,92 @@ pub async fn open_wallet(
         .await
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
 
-    let network_id = NetworkId::new(NetworkType::Mainnet);
-    let resolver = Resolver::default();
+    let wallet_name = settings::wallet_meta_key(filename);
+    let meta = settings::load_wallet_meta(&app, &wallet_name);
+    let network_id = meta.network_id();
+    info!("Wallet '{}' belongs to network {}", filename_stem, network_id);
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let (wallet, node) = node::connect_wallet(store.clone(), &node_settings, network_id).await?;
+    info!("Connected to node {:?}", node.url);
 
-    let wallet = Arc::new(
-        Wallet::try_new(store.clone(), Some(resolver.clone()), Some(network_id))
-            .map_err(|e| ErrorResponse { error: format!("Wallet initialization failed: {}", e) })?,
-    );
+    wallet.start().await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start wallet services: {}", e) })?;
 
-    if let Some(wrpc) = wallet.try_wrpc_client().as_ref() {
-        let url = resolver
-            .get_url(WrpcEncoding::Borsh, network_id)
-            .await
-            .map_err(|e| ErrorResponse { error: format!("Failed to resolve node URL: {}", e) })?;
+    if !wallet.is_open() {
+        return Err(ErrorResponse { error: "Wallet failed to open".into() });
+    }
 
-        let opts = ConnectOptions {
-            block_async_connect: true,
-            strategy: ConnectStrategy::Fallback,
-            url: Some(url),
-            ..Default::default()
-        };
+    let (mnemonic, bip39_seed) = load_key_material(&wallet, &wallet_secret).await?;
 
-        wrpc.connect(Some(opts)).await
-            .map_err(|e| {
-                error!("Node connection failed: {}", e);
-                ErrorResponse {
-                    error: "Failed to connect to Vecno node. Check your internet connection or try again later.".into()
-                }
-            })?;
-        info!("Connected to node");
-    } else {
-        return Err(ErrorResponse { error: "No wRPC client available".into() });
+    // Reopen on the account used last time, falling back to the first one.
+    let mut accounts = accounts::load_accounts(&wallet).await?;
+    if accounts.is_empty() {
+        return Err(ErrorResponse { error: "No accounts found in wallet".into() });
     }
+    let position = meta
+        .active_account
+        .as_deref()
+        .and_then(|id| accounts.iter().position(|account| account.id().to_string() == id))
+        .unwrap_or(0);
+    let account = accounts.swap_remove(position);
 
-    if !wallet.is_open() {
-        return Err(ErrorResponse { error: "Wallet failed to open".into() });
+    wallet
+        .select(Some(&account))
+        .await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    account
+        .start()
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
+    accounts::activate_all(&wallet).await;
+
+    {
+        let mut w = state.wallet.lock().await;
+        let mut s = state.wallet_secret.lock().await;
+        let mut m = state.mnemonic.lock().await;
+        let mut seed_state = state.bip39_seed.lock().await;
+
+        *w = Some(wallet.clone());
+        *s = Some(wallet_secret);
+        *m = mnemonic;
+        *seed_state = bip39_seed;
+        *state.network_id.lock().await = network_id;
+        *state.node_cache.lock().await = node;
+        *state.wallet_name.lock().await = Some(wallet_name);
+        *state.frozen_utxos.lock().await = meta.frozen_utxos.clone().into_iter().collect();
+        *state.watch_addresses.lock().await = meta
+            .watch_addresses
+            .iter()
+            .filter_map(|address| Address::try_from(address.as_str()).ok())
+            .collect();
+        state.scanned_accounts.lock().await.clear();
+
+        info!("AppState updated: wallet opened, mnemonic/seed loaded if available");
     }
 
-    let mut mnemonic: Option<String> = None;
-    let mut bip39_seed: Option<String> = None;
+    settings::save_network(&app, network_id)?;
+    // The event forwarder starts after the UTXO processor and misses its
+    // first `UtxoProcStart`, so the opening account is scanned here.
+    if let Err(e) = accounts::scan_account(&state, &account).await {
+        warn!("Initial scan of account {} failed: {}", account.name_or_id(), e.error);
+    }
+    monitor::start(&app, &wallet, network_id).await;
+    events::start(&app, &wallet).await;
+
+    let msg = format!("Success: Wallet opened from {}", storage_path.display());
+    info!("{}", msg);
+    Ok(msg)
+}
+
+/// Decrypts the wallet's private key data and returns the recovery phrase
+/// or raw BIP39 seed it holds, if any.
+pub(crate) async fn load_key_material(
+    wallet: &Arc<Wallet>,
+    wallet_secret: &Secret,
+) -> Result<(Option<Zeroizing<String>>, Option<Zeroizing<String>>), ErrorResponse> {
+    let mut mnemonic = None;
+    let mut bip39_seed = None;
 
     let mut keys = wallet
         .store()
@@ -122,10 +173,11 @@ pub async fn open_wallet(
         .map_err(|e| ErrorResponse { error: e.to_string() })?;
 
     while let Some(info) = keys.try_next().await.map_err(|e| ErrorResponse { error: e.to_string() })? {
-        let encrypted = match store
+        let encrypted = match wallet
+            .store()
             .as_prv_key_data_store()
             .map_err(|e| ErrorResponse { error: e.to_string() })?
-            .load_key_data(&wallet_secret, &info.id)
+            .load_key_data(wallet_secret, &info.id)
             .await
         {
             Ok(Some(e)) => e,
@@ -136,7 +188,7 @@ pub async fn open_wallet(
             }
         };
 
-        let decrypted = match encrypted.payload.decrypt(Some(&wallet_secret)) {
+        let decrypted = match encrypted.payload.decrypt(Some(wallet_secret)) {
             Ok(d) => d,
             Err(e) => {
                 info!("Failed to decrypt key data (ID: {}): {}", info.id, e);
@@ -146,83 +198,17 @@ pub async fn open_wallet(
 
         match &*decrypted.as_variant() {
             PrvKeyDataVariant::Mnemonic(s) => {
-                let cleaned = s.trim().to_owned();
-                let word_count = cleaned.split_whitespace().count();
-                info!("Mnemonic loaded: {} words", word_count);
-                mnemonic = Some(cleaned.clone());
-
-                if let Some(payment_secret) = payment_secret_opt {
-                    if let Ok(mnemonic_obj) = Mnemonic::parse(&cleaned) {
-                        let seed = mnemonic_obj.to_seed(payment_secret);
-                        let seed_hex = hex::encode(seed);
-                        info!("Derived Bip39Seed from mnemonic + payment_secret");
-                        bip39_seed = Some(seed_hex);
-                    }
-                }
+                let cleaned = Zeroizing::new(s.trim().to_owned());
+                info!("Mnemonic loaded: {} words", cleaned.split_whitespace().count());
+                mnemonic = Some(cleaned);
             }
             PrvKeyDataVariant::Bip39Seed(s) => {
-                let cleaned = s.trim().to_owned();
                 info!("Bip39Seed loaded directly");
-                bip39_seed = Some(cleaned);
+                bip39_seed = Some(Zeroizing::new(s.trim().to_owned()));
             }
             _ => {}
         }
     }
 
-    let mut account_id: Option<AccountId> = None;
-    let mut accounts = wallet
-        .store()
-        .as_account_store()
-        .map_err(|e| ErrorResponse { error: e.to_string() })?
-        .iter(None)
-        .await
-        .map_err(|e| ErrorResponse { error: e.to_string() })?;
-
-    while let Some((acc, _)) = accounts.try_next().await.map_err(|e| ErrorResponse { error: e.to_string() })? {
-        account_id = Some(*acc.id());
-        break;
-    }
-
-    let account = if let Some(id) = account_id {
-        let guard_mutex = wallet.guard();
-        let guard = guard_mutex.lock().await;
-
-        wallet
-            .get_account_by_id(&id, &guard)
-            .await
-            .map_err(|e| ErrorResponse { error: e.to_string() })?
-            .ok_or_else(|| ErrorResponse { error: "Account not found".into() })?
-    } else {
-        return Err(ErrorResponse { error: "No accounts found in wallet".into() });
-    };
-
-    wallet
-        .select(Some(&account))
-        .await
-        .map_err(|e| ErrorResponse { error: e.to_string() })?;
-
-    account
-        .start()
-        .await
-        .map_err(|e| ErrorResponse { error: format!("Failed to start account: {}", e) })?;
-
-    {
-        let mut w = state.wallet.lock().await;
-        let mut r = state.resolver.lock().await;
-        let mut s = state.wallet_secret.lock().await;
-        let mut m = state.mnemonic.lock().await;
-        let mut seed_state = state.bip39_seed.lock().await;
-
-        *w = Some(wallet.clone());
-        *r = Some(resolver);
-        *s = Some(wallet_secret);
-        *m = mnemonic;
-        *seed_state = bip39_seed;
-
-        info!("AppState updated: wallet opened, mnemonic/seed loaded if available");
-    }
-
-    let msg = format!("Success: Wallet opened from {}", storage_path.display());
-    info!("{}", msg);
-    Ok(msg)
-}
\ No newline at end of file
+    Ok((mnemonic, bip39_seed))
+}
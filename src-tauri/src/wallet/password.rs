@@ -0,0 +1,294 @@
+use crate::accounts::{is_watch_only, load_accounts, open_wallet};
+use crate::models::{ChangePaymentSecretInput, ChangeWalletPasswordInput};
+use crate::state::{AppState, ErrorResponse};
+use log::{error, info, warn};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{command, State};
+use vecno_wallet_core::encryption::Encryptable;
+use vecno_wallet_core::prelude::*;
+use vecno_wallet_core::settings::application_folder;
+use vecno_wallet_core::storage::interface::OpenArgs;
+use vecno_wallet_core::storage::keydata::{PrvKeyData, PrvKeyDataVariantKind};
+use vecno_wallet_core::storage::local::{Payload, Storage, WalletStorage};
+
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// The open wallet's file, as written by the local store.
+pub(crate) fn wallet_file(wallet: &Arc<Wallet>) -> Result<PathBuf, ErrorResponse> {
+    let descriptor = wallet.store().descriptor().ok_or(ErrorResponse {
+        error: "Wallet is not open".into(),
+    })?;
+    let wallet_dir = application_folder().map_err(|e| ErrorResponse { error: e.to_string() })?;
+    Ok(wallet_dir.join(format!("{}.wallet", descriptor.filename)))
+}
+
+pub(crate) async fn load_file(path: &Path) -> Result<WalletStorage, ErrorResponse> {
+    let storage = Storage::try_new(path.to_str().ok_or_else(|| ErrorResponse { error: "Invalid path".into() })?)
+        .map_err(|e| ErrorResponse { error: format!("Failed to initialize storage: {}", e) })?;
+    WalletStorage::try_load(&storage)
+        .await
+        .map_err(|e| ErrorResponse { error: format!("Failed to load wallet: {}", e) })
+}
+
+/// Checks the wallet password against the file on disk, the same way
+/// `verify_wallet_password` does, and returns the decrypted payload.
+pub(crate) async fn verify_password(path: &Path, secret: &Secret) -> Result<Payload, ErrorResponse> {
+    load_file(path)
+        .await?
+        .payload(secret)
+        .map(|payload| payload.unwrap())
+        .map_err(|_| ErrorResponse { error: "Incorrect wallet password".into() })
+}
+
+/// Reads the open wallet's file back and checks that `account_id` was
+/// written to it, so an import only succeeds once a reopen would find the
+/// account.
+pub(crate) async fn verify_account_stored(
+    wallet: &Arc<Wallet>,
+    secret: &Secret,
+    account_id: &AccountId,
+) -> Result<(), ErrorResponse> {
+    let payload = verify_password(&wallet_file(wallet)?, secret).await?;
+    if !payload.accounts.iter().any(|account| &account.id == account_id) {
+        return Err(ErrorResponse { error: "The account was not saved to the wallet file".into() });
+    }
+    Ok(())
+}
+
+/// Writes `wallet_storage` to a temporary file next to `path`, syncs it to
+/// disk and renames it over `path`, so a crash leaves either the previous
+/// or the new file and never a partly written one. `check` runs against
+/// the temporary file, decrypted with `secret`, before it replaces anything.
+async fn install_wallet_file(
+    path: &Path,
+    wallet_storage: &WalletStorage,
+    secret: &Secret,
+    check: impl FnOnce(&Payload) -> Result<(), ErrorResponse>,
+) -> Result<(), ErrorResponse> {
+    let temp = Storage::try_new(
+        path.with_extension("wallet.tmp")
+            .to_str()
+            .ok_or_else(|| ErrorResponse { error: "Invalid path".into() })?,
+    )
+    .map_err(|e| ErrorResponse { error: format!("Failed to initialize storage: {}", e) })?;
+    let temp_path = PathBuf::from(temp.filename());
+
+    let result = async {
+        wallet_storage
+            .try_store(&temp)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Failed to write wallet: {}", e) })?;
+        File::open(&temp_path)
+            .and_then(|file| file.sync_all())
+            .map_err(|e| ErrorResponse { error: format!("Failed to sync wallet file: {}", e) })?;
+
+        let payload = load_file(&temp_path)
+            .await?
+            .payload(secret)
+            .map_err(|e| ErrorResponse { error: format!("Rewritten wallet file does not decrypt: {}", e) })?;
+        check(payload.as_ref())?;
+
+        fs::rename(&temp_path, path)
+            .map_err(|e| ErrorResponse { error: format!("Failed to replace the wallet file: {}", e) })
+    }
+    .await;
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return result;
+    }
+    // Make the rename itself durable; not every platform can sync a folder.
+    if let Some(folder) = path.parent() {
+        if let Err(e) = File::open(folder).and_then(|folder| folder.sync_all()) {
+            warn!("Failed to sync {}: {}", folder.display(), e);
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the open wallet file with `payload` encrypted under `secret`
+/// and reloads the open store from it, so memory and disk never disagree.
+/// When the reload fails the previous file goes back in place and the
+/// store is reloaded from it with `previous_secret`.
+pub(crate) async fn rewrite_wallet_file(
+    wallet: &Arc<Wallet>,
+    path: &Path,
+    previous: &WalletStorage,
+    previous_secret: &Secret,
+    secret: &Secret,
+    payload: Payload,
+    check: impl FnOnce(&Payload) -> Result<(), ErrorResponse>,
+) -> Result<(), ErrorResponse> {
+    let descriptor = wallet.store().descriptor().ok_or(ErrorResponse {
+        error: "Wallet is not open".into(),
+    })?;
+    let replacement = WalletStorage::try_new(
+        previous.title.clone(),
+        previous.user_hint.clone(),
+        secret,
+        previous.encryption_kind,
+        payload,
+        previous.metadata.clone(),
+    )
+    .map_err(|e| ErrorResponse { error: format!("Failed to encrypt wallet: {}", e) })?;
+
+    install_wallet_file(path, &replacement, secret, check).await?;
+
+    let reload = |secret: &Secret| {
+        let store = wallet.store().clone();
+        let args = OpenArgs { filename: Some(descriptor.filename.clone()) };
+        let secret = secret.clone();
+        async move { store.open(&secret, args).await }
+    };
+    if let Err(e) = reload(secret).await {
+        error!("Reloading {} failed, restoring the previous file: {}", path.display(), e);
+        install_wallet_file(path, previous, previous_secret, |_| Ok(())).await?;
+        reload(previous_secret)
+            .await
+            .map_err(|e| ErrorResponse { error: format!("Failed to reload the wallet: {}", e) })?;
+        return Err(ErrorResponse {
+            error: format!("Failed to reload the rewritten wallet ({}); it was left unchanged", e),
+        });
+    }
+    Ok(())
+}
+
+fn optional_secret(value: Option<&str>) -> Option<String> {
+    value.map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+/// Re-encrypts the open wallet file, including all private key data, with a
+/// new password.
+#[command]
+pub async fn change_wallet_password(
+    input: ChangeWalletPasswordInput,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    if input.old_secret.is_empty() {
+        return Err(ErrorResponse { error: "Current password is required".into() });
+    }
+    if input.new_secret.len() < MIN_PASSWORD_LENGTH {
+        return Err(ErrorResponse {
+            error: format!("New password must be at least {} characters", MIN_PASSWORD_LENGTH),
+        });
+    }
+    if input.new_secret == input.old_secret {
+        return Err(ErrorResponse { error: "New password must differ from the current one".into() });
+    }
+
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let path = wallet_file(&wallet)?;
+
+    let old_secret = Secret::new(input.old_secret.as_bytes().to_vec());
+    let new_secret = Secret::new(input.new_secret.as_bytes().to_vec());
+
+    let previous = load_file(&path).await?;
+    let payload = previous
+        .payload(&old_secret)
+        .map(|payload| payload.unwrap())
+        .map_err(|_| ErrorResponse { error: "Incorrect wallet password".into() })?;
+    let (key_count, account_count) = (payload.prv_key_data.len(), payload.accounts.len());
+
+    rewrite_wallet_file(&wallet, &path, &previous, &old_secret, &new_secret, payload, |payload| {
+        if payload.prv_key_data.len() != key_count || payload.accounts.len() != account_count {
+            return Err(ErrorResponse { error: "Rewritten wallet file is missing keys or accounts".into() });
+        }
+        Ok(())
+    })
+    .await?;
+
+    *state.wallet_secret.lock().await = Some(new_secret);
+
+    info!("Wallet password changed for {}", path.display());
+    Ok(())
+}
+
+/// Whether the open wallet can change its payment secret. Accounts derived
+/// from a recovery phrase use it as their BIP39 passphrase, so only wallets
+/// whose signing accounts each hold a single key qualify.
+#[command]
+pub async fn payment_secret_changeable(state: State<'_, AppState>) -> Result<bool, ErrorResponse> {
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let accounts = load_accounts(&wallet).await?;
+    let mut signing = accounts.iter().filter(|account| !is_watch_only(account)).peekable();
+    Ok(signing.peek().is_some() && signing.all(|account| account.clone().as_derivation_capable().is_err()))
+}
+
+/// Re-encrypts every private key in the open wallet with a new payment
+/// secret, or removes it when the new secret is empty. Recovery phrases are
+/// refused: their payment secret doubles as the BIP39 passphrase, so
+/// changing it would derive a different set of addresses.
+#[command]
+pub async fn change_payment_secret(
+    input: ChangePaymentSecretInput,
+    state: State<'_, AppState>,
+) -> Result<(), ErrorResponse> {
+    if input.secret.is_empty() {
+        return Err(ErrorResponse { error: "Wallet password is required".into() });
+    }
+
+    let old_payment_secret = optional_secret(input.old_payment_secret.as_deref());
+    let new_payment_secret = optional_secret(input.new_payment_secret.as_deref());
+    if old_payment_secret == new_payment_secret {
+        return Err(ErrorResponse { error: "New payment secret must differ from the current one".into() });
+    }
+    let old_payment_secret = old_payment_secret.map(|s| Secret::new(s.into_bytes()));
+    let new_payment_secret = new_payment_secret.map(|s| Secret::new(s.into_bytes()));
+
+    let wallet = open_wallet(state.wallet.lock().await.as_ref())?;
+    let path = wallet_file(&wallet)?;
+
+    let wallet_secret = Secret::new(input.secret.as_bytes().to_vec());
+    let previous = load_file(&path).await?;
+    let mut payload = previous
+        .payload(&wallet_secret)
+        .map(|payload| payload.unwrap())
+        .map_err(|_| ErrorResponse { error: "Incorrect wallet password".into() })?;
+    if payload.prv_key_data.is_empty() {
+        return Err(ErrorResponse { error: "This wallet holds no private keys".into() });
+    }
+
+    for key in payload.prv_key_data.iter_mut() {
+        let variant = key
+            .payload
+            .decrypt(old_payment_secret.as_ref())
+            .map_err(|_| ErrorResponse { error: "Incorrect payment secret".into() })?;
+
+        if matches!(variant.as_variant().kind(), PrvKeyDataVariantKind::Mnemonic) {
+            return Err(ErrorResponse {
+                error: "The payment secret of a recovery phrase is also its BIP39 passphrase; changing it would \
+                        derive different addresses. Create a new wallet and move the funds instead"
+                    .into(),
+            });
+        }
+
+        let mut replacement = PrvKeyData::new(key.id, key.name.clone(), Encryptable::Plain(variant.unwrap()));
+        if let Some(new_payment_secret) = new_payment_secret.as_ref() {
+            replacement
+                .encrypt(new_payment_secret, EncryptionKind::XChaCha20Poly1305)
+                .map_err(|e| ErrorResponse { error: format!("Failed to encrypt key data: {}", e) })?;
+        }
+        *key = replacement;
+    }
+    let ids: Vec<_> = payload.prv_key_data.iter().map(|key| key.id).collect();
+
+    rewrite_wallet_file(&wallet, &path, &previous, &wallet_secret, &wallet_secret, payload, |payload| {
+        for id in &ids {
+            let key = payload
+                .prv_key_data
+                .iter()
+                .find(|key| &key.id == id)
+                .ok_or_else(|| ErrorResponse { error: format!("Key data {} missing after rewrite", id) })?;
+            key.payload
+                .decrypt(new_payment_secret.as_ref())
+                .map_err(|_| ErrorResponse { error: format!("Key data {} does not open with the new payment secret", id) })?;
+        }
+        Ok(())
+    })
+    .await?;
+
+    info!("Payment secret changed for {} key(s) in {}", ids.len(), path.display());
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use crate::accounts;
+use crate::events;
+use crate::monitor;
+use crate::node;
+use crate::settings::{self, WalletMeta};
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::password::verify_account_stored;
+use tauri::{command, AppHandle, State};
+use vecno_wallet_core::prelude::*;
+use crate::models::ImportPrivateKeyInput;
+use vecno_wallet_core::account::keypair::Keypair;
+use vecno_wallet_core::storage::interface::CreateArgs;
+use vecno_wallet_core::wallet::args::PrvKeyDataCreateArgs;
+use vecno_wallet_core::storage::keydata::PrvKeyDataVariantKind;
+use vecno_wallet_core::settings::application_folder;
+use vecno_addresses::{Prefix, Version};
+use vecno_bip32::secp256k1::{PublicKey, SecretKey};
+use log::{error, info, warn};
+use std::sync::Arc;
+
+/// Parses a raw secp256k1 private key given as 64 hex characters, with or
+/// without a `0x` prefix.
+pub(crate) fn parse_private_key(private_key: &str) -> Result<SecretKey, ErrorResponse> {
+    let private_key = private_key.trim();
+    let private_key = private_key.strip_prefix("0x").unwrap_or(private_key);
+
+    if private_key.is_empty() {
+        return Err(ErrorResponse { error: "Private key is required".into() });
+    }
+    if private_key.len() != 64 {
+        return Err(ErrorResponse { error: "Private key must be exactly 64 hex characters".into() });
+    }
+
+    let bytes = hex::decode(private_key)
+        .map_err(|_| ErrorResponse { error: "Private key must contain only hex characters".into() })?;
+
+    SecretKey::from_slice(&bytes)
+        .map_err(|_| ErrorResponse { error: "Private key is outside the valid secp256k1 range".into() })
+}
+
+/// The Schnorr (P2PK) address a keypair account receives on.
+pub(crate) fn key_address(secret_key: &SecretKey, prefix: Prefix) -> Address {
+    let (xonly_public_key, _) = PublicKey::from_secret_key_global(secret_key).x_only_public_key();
+    Address::new(prefix, Version::PubKey, &xonly_public_key.serialize())
+}
+
+/// Shows which address a private key controls on the current network so the
+/// user can check it before any wallet file is written.
+#[command]
+pub async fn preview_private_key(
+    private_key: String,
+    state: State<'_, AppState>,
+) -> Result<String, ErrorResponse> {
+    let secret_key = parse_private_key(&private_key)?;
+    let network_id = *state.network_id.lock().await;
+    Ok(key_address(&secret_key, Prefix::from(network_id)).to_string())
+}
+
+/// Creates a wallet file holding a single keypair account backed by a raw
+/// private key. The key is stored as `SecretKey` key data and encrypted with
+/// the wallet password and optional payment secret, as mnemonic imports are.
+#[command]
+pub async fn import_private_key(
+    input: ImportPrivateKeyInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<String, ErrorResponse> {
+    let secret = input.secret;
+    let payment_secret = input.payment_secret;
+    let filename = input.filename;
+
+    if secret.is_empty() {
+        return Err(ErrorResponse { error: "Wallet password is required".into() });
+    }
+    if filename.is_empty() {
+        return Err(ErrorResponse { error: "Wallet filename is required".into() });
+    }
+
+    let secret_key = parse_private_key(&input.private_key)?;
+    let public_key = PublicKey::from_secret_key_global(&secret_key);
+
+    let network_id = *state.network_id.lock().await;
+    let wallet_dir = application_folder().map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let storage_path = wallet_dir.join(&filename);
+
+    let store = Wallet::local_store().map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let wallet_secret = Secret::new(secret.as_bytes().to_vec());
+
+    let create_args = CreateArgs {
+        title: Some("Private Key Wallet".into()),
+        filename: Some(storage_path.to_str().ok_or_else(|| ErrorResponse { error: "Invalid path".into() })?.to_string()),
+        encryption_kind: EncryptionKind::XChaCha20Poly1305,
+        user_hint: None,
+        overwrite_wallet: true,
+    };
+
+    store.create(&wallet_secret, create_args).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let (wallet, node) = node::connect_wallet(store.clone(), &node_settings, network_id).await?;
+    info!("Connected to node {:?}", node.url);
+
+    wallet.start().await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start wallet services: {}", e) })?;
+
+    if !wallet.is_open() {
+        return Err(ErrorResponse { error: "Wallet not open after init".into() });
+    }
+
+    let passphrase = payment_secret.as_deref().unwrap_or("").trim();
+    let stored_payment_secret = (!passphrase.is_empty()).then(|| Secret::new(passphrase.as_bytes().to_vec()));
+
+    let prv_key_data = PrvKeyDataCreateArgs {
+        name: None,
+        payment_secret: stored_payment_secret,
+        secret: Secret::new(secret_key.display_secret().to_string().into_bytes()),
+        kind: PrvKeyDataVariantKind::SecretKey,
+    };
+
+    let key_id = wallet.create_prv_key_data(&wallet_secret, prv_key_data).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    let account: Arc<dyn Account> = Arc::new(
+        Keypair::try_new(&wallet, Some("keypair-account".into()), public_key, key_id, false)
+            .await
+            .map_err(|e| {
+                error!("Keypair account creation failed: {}", e);
+                ErrorResponse { error: format!("Account creation failed: {}", e) }
+            })?,
+    );
+
+    let account_store = wallet.store().clone().as_account_store()
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let account_storage = account.to_storage()
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    account_store.store_single(&account_storage, None).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    wallet.store().commit(&wallet_secret).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+    verify_account_stored(&wallet, &wallet_secret, account.id()).await?;
+
+    wallet.select(Some(&account)).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    account.start().await
+        .map_err(|e| ErrorResponse { error: format!("Account start failed: {}", e) })?;
+
+    let wallet_name = settings::wallet_meta_key(&filename);
+    settings::save_wallet_meta(&app, &wallet_name, &WalletMeta {
+        network: Some(network_id.to_string()),
+        ..Default::default()
+    })?;
+
+    {
+        let mut wallet_state = state.wallet.lock().await;
+        let mut secret_state = state.wallet_secret.lock().await;
+        let mut mnemonic_state = state.mnemonic.lock().await;
+        let mut node_state = state.node_cache.lock().await;
+
+        *wallet_state = Some(wallet.clone());
+        *secret_state = Some(wallet_secret);
+        *mnemonic_state = None;
+        *node_state = node;
+        *state.wallet_name.lock().await = Some(wallet_name);
+        state.frozen_utxos.lock().await.clear();
+        state.scanned_accounts.lock().await.clear();
+        state.watch_addresses.lock().await.clear();
+    }
+
+    if let Err(e) = accounts::scan_account(&state, &account).await {
+        warn!("Initial scan of account {} failed: {}", account.name_or_id(), e.error);
+    }
+    monitor::start(&app, &wallet, network_id).await;
+    events::start(&app, &wallet).await;
+
+    info!("Private key wallet created at {}", storage_path.display());
+    Ok(format!("Success: Private key wallet created at {}", storage_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vecno_consensus_core::network::{NetworkId, NetworkType};
+
+    const ONE: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    /// x coordinate of the secp256k1 generator, the public key of `ONE`.
+    const GENERATOR_X: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn parsed(private_key: &str) -> SecretKey {
+        match parse_private_key(private_key) {
+            Ok(secret_key) => secret_key,
+            Err(e) => panic!("{:?} should parse: {}", private_key, e.error),
+        }
+    }
+
+    fn rejected(private_key: &str) -> String {
+        match parse_private_key(private_key) {
+            Ok(_) => panic!("{:?} should be rejected", private_key),
+            Err(e) => e.error,
+        }
+    }
+
+    #[test]
+    fn hex_keys_parse_with_or_without_prefix() {
+        let expected = parsed(ONE).secret_bytes();
+        assert_eq!(expected[31], 1);
+        assert_eq!(parsed(&format!("0x{}", ONE)).secret_bytes(), expected);
+        assert_eq!(parsed(&format!("  {}\n", ONE)).secret_bytes(), expected);
+        assert_eq!(parsed(&GENERATOR_X.to_uppercase()).secret_bytes(), parsed(GENERATOR_X).secret_bytes());
+    }
+
+    #[test]
+    fn empty_keys_are_rejected() {
+        assert_eq!(rejected(""), "Private key is required");
+        assert_eq!(rejected("   "), "Private key is required");
+        assert_eq!(rejected("0x"), "Private key is required");
+    }
+
+    #[test]
+    fn wif_keys_are_rejected() {
+        let expected = "Private key must be exactly 64 hex characters";
+        assert_eq!(rejected("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dpScjjgGGeVUzFfVT"), expected);
+        assert_eq!(rejected("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"), expected);
+    }
+
+    #[test]
+    fn wrong_lengths_are_rejected() {
+        let expected = "Private key must be exactly 64 hex characters";
+        assert_eq!(rejected(&ONE[1..]), expected);
+        assert_eq!(rejected(&format!("{}00", ONE)), expected);
+        assert_eq!(rejected(&format!("0x0x{}", &ONE[4..])), expected);
+    }
+
+    #[test]
+    fn non_hex_characters_are_rejected() {
+        let key = format!("{}zz", &ONE[..62]);
+        assert_eq!(rejected(&key), "Private key must contain only hex characters");
+    }
+
+    #[test]
+    fn keys_outside_the_curve_order_are_rejected() {
+        let expected = "Private key is outside the valid secp256k1 range";
+        assert_eq!(rejected(&"0".repeat(64)), expected);
+        assert_eq!(
+            rejected("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+            expected
+        );
+    }
+
+    #[test]
+    fn key_address_uses_the_x_only_public_key() {
+        let prefix = Prefix::from(NetworkId::new(NetworkType::Mainnet));
+        let x = hex::decode(GENERATOR_X).unwrap();
+        assert_eq!(key_address(&parsed(ONE), prefix), Address::new(prefix, Version::PubKey, &x));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::events;
+use crate::monitor;
 use crate::state::{AppState, ErrorResponse, NodeCache};
 use tauri::{command, State};
 use log::{error, info};
@@ -6,6 +8,13 @@ use log::{error, info};
 pub async fn switch_wallet(state: State<'_, AppState>) -> Result<(), ErrorResponse> {
     info!("Switching wallet — closing current session safely");
 
+    monitor::stop(&state).await;
+    events::stop(&state).await;
+
+    if let Some(abortable) = state.compound_abort.lock().await.as_ref() {
+        abortable.abort();
+    }
+
     {
         let wallet_guard = state.wallet.lock().await;
         if let Some(wallet) = wallet_guard.as_ref() {
@@ -15,6 +24,10 @@ pub async fn switch_wallet(state: State<'_, AppState>) -> Result<(), ErrorRespon
                 }
             }
 
+            if let Err(e) = wallet.stop().await {
+                error!("Failed to stop wallet services: {}", e);
+            }
+
             if let Some(wrpc_client) = wallet.try_wrpc_client() {
                 if let Err(e) = wrpc_client.disconnect().await {
                     error!("Failed to disconnect wRPC client: {}", e);
@@ -43,6 +56,13 @@ pub async fn switch_wallet(state: State<'_, AppState>) -> Result<(), ErrorRespon
     let mut node_cache = state.node_cache.lock().await;
     *node_cache = NodeCache::default();
 
+    *state.wallet_name.lock().await = None;
+    state.frozen_utxos.lock().await.clear();
+    state.scanned_accounts.lock().await.clear();
+    state.watch_addresses.lock().await.clear();
+    *state.locked.lock().await = false;
+    *state.backup_challenge.lock().await = None;
+
     info!("Wallet session cleared. Ready to open a new wallet.");
 
     Ok(())
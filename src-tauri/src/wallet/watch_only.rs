@@ -0,0 +1,137 @@
+use crate::accounts;
+use crate::events;
+use crate::monitor;
+use crate::node;
+use crate::settings::{self, WalletMeta};
+use crate::state::{AppState, ErrorResponse};
+use crate::wallet::password::verify_account_stored;
+use tauri::{command, AppHandle, State};
+use vecno_wallet_core::prelude::*;
+use crate::models::ImportWatchOnlyInput;
+use vecno_wallet_core::storage::interface::CreateArgs;
+use vecno_wallet_core::wallet::args::AccountCreateArgsBip32Watch;
+use vecno_wallet_core::settings::application_folder;
+use log::{error, info, warn};
+
+/// Creates a wallet file holding a single watch-only account built from an
+/// extended public key. No private key is ever stored, so balance, history
+/// and receive addresses work while signing is refused.
+#[command]
+pub async fn import_watch_only(
+    input: ImportWatchOnlyInput,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<String, ErrorResponse> {
+    let xpub = input.xpub.trim().to_string();
+    let secret = input.secret;
+    let filename = input.filename;
+
+    if secret.is_empty() {
+        return Err(ErrorResponse { error: "Wallet password is required".into() });
+    }
+    if xpub.is_empty() {
+        return Err(ErrorResponse { error: "Extended public key is required".into() });
+    }
+    if filename.is_empty() {
+        return Err(ErrorResponse { error: "Wallet filename is required".into() });
+    }
+
+    let network_id = *state.network_id.lock().await;
+    let prefix = AddressPrefix::from(network_id);
+
+    let mut watch_addresses = Vec::new();
+    for address in input.addresses.unwrap_or_default().iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+        let address = Address::try_from(address)
+            .map_err(|e| ErrorResponse { error: format!("Invalid address '{}': {}", address, e) })?;
+        if address.prefix != prefix {
+            return Err(ErrorResponse {
+                error: format!("Address {} belongs to a different network than {}", address, network_id),
+            });
+        }
+        if !watch_addresses.contains(&address) {
+            watch_addresses.push(address);
+        }
+    }
+
+    let wallet_dir = application_folder().map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let storage_path = wallet_dir.join(&filename);
+
+    let store = Wallet::local_store().map_err(|e| ErrorResponse { error: e.to_string() })?;
+    let wallet_secret = Secret::new(secret.as_bytes().to_vec());
+
+    let create_args = CreateArgs {
+        title: Some("Watch-only Wallet".into()),
+        filename: Some(storage_path.to_str().ok_or_else(|| ErrorResponse { error: "Invalid path".into() })?.to_string()),
+        encryption_kind: EncryptionKind::XChaCha20Poly1305,
+        user_hint: None,
+        overwrite_wallet: true,
+    };
+
+    store.create(&wallet_secret, create_args).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    let node_settings = settings::load_node_settings(&app, network_id);
+    let (wallet, node) = node::connect_wallet(store.clone(), &node_settings, network_id).await?;
+    info!("Connected to node {:?}", node.url);
+
+    wallet.start().await
+        .map_err(|e| ErrorResponse { error: format!("Failed to start wallet services: {}", e) })?;
+
+    if !wallet.is_open() {
+        return Err(ErrorResponse { error: "Wallet not open after init".into() });
+    }
+
+    let account_args = AccountCreateArgsBip32Watch {
+        account_name: Some("watch-only".into()),
+        xpub_keys: vec![xpub],
+    };
+
+    let account = wallet
+        .create_account_bip32_watch(&wallet_secret, account_args)
+        .await
+        .map_err(|e| {
+            error!("Watch-only account creation failed: {}", e);
+            ErrorResponse { error: format!("Invalid extended public key: {}", e) }
+        })?;
+
+    wallet.select(Some(&account)).await
+        .map_err(|e| ErrorResponse { error: e.to_string() })?;
+
+    account.start().await
+        .map_err(|e| ErrorResponse { error: format!("Account start failed: {}", e) })?;
+
+    // Account creation commits the store; read the file back to be sure.
+    verify_account_stored(&wallet, &wallet_secret, account.id()).await?;
+
+    let wallet_name = settings::wallet_meta_key(&filename);
+    settings::save_wallet_meta(&app, &wallet_name, &WalletMeta {
+        network: Some(network_id.to_string()),
+        watch_addresses: watch_addresses.iter().map(|a| a.to_string()).collect(),
+        ..Default::default()
+    })?;
+
+    {
+        let mut wallet_state = state.wallet.lock().await;
+        let mut secret_state = state.wallet_secret.lock().await;
+        let mut mnemonic_state = state.mnemonic.lock().await;
+        let mut node_state = state.node_cache.lock().await;
+
+        *wallet_state = Some(wallet.clone());
+        *secret_state = Some(wallet_secret);
+        *mnemonic_state = None;
+        *node_state = node;
+        *state.wallet_name.lock().await = Some(wallet_name);
+        state.frozen_utxos.lock().await.clear();
+        state.scanned_accounts.lock().await.clear();
+        *state.watch_addresses.lock().await = watch_addresses;
+    }
+
+    if let Err(e) = accounts::scan_account(&state, &account).await {
+        warn!("Initial scan of account {} failed: {}", account.name_or_id(), e.error);
+    }
+    monitor::start(&app, &wallet, network_id).await;
+    events::start(&app, &wallet).await;
+
+    info!("Watch-only wallet created at {}", storage_path.display());
+    Ok(format!("Success: Watch-only wallet created at {}", storage_path.display()))
+}
@@ -8,12 +8,42 @@ use yew::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen::prelude::*;
 use log::{error, info};
-use gloo_timers::callback::{Interval, Timeout};
 use js_sys::Date;
 
+const TX_PAGE_SIZE: usize = 25;
+/// Minimum gap between activity reports to the backend idle lock.
+const ACTIVITY_THROTTLE_MS: f64 = 30_000.0;
+
+async fn fetch_transaction_page(offset: usize) -> Result<TransactionPage, String> {
+    let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+        "offset": offset,
+        "limit": TX_PAGE_SIZE
+    })).unwrap_or(JsValue::NULL);
+    let res = safe_invoke("list_transactions", args).await?;
+    serde_wasm_bindgen::from_value::<TransactionPage>(res)
+        .map_err(|_| "Failed to parse transactions".to_string())
+}
+
+async fn fetch_addresses() -> Result<Vec<WalletAddress>, String> {
+    let res = safe_invoke("get_address", JsValue::NULL).await?;
+    serde_wasm_bindgen::from_value::<Vec<WalletAddress>>(res)
+        .map_err(|_| "Failed to parse addresses".to_string())
+}
+
+fn last_updated_label(timestamp: i64) -> String {
+    let js_date = Date::new(&js_sys::Number::from(timestamp as f64 * 1000.0));
+    format!(
+        "Last updated: {:02}:{:02}:{:02}",
+        js_date.get_hours(),
+        js_date.get_minutes(),
+        js_date.get_seconds()
+    )
+}
+
 async fn fetch_balance(
     addresses: UseStateHandle<Vec<WalletAddress>>,
     balance: UseStateHandle<String>,
+    balance_detail: UseStateHandle<Option<BalanceResponse>>,
     is_loading: UseStateHandle<bool>,
     push_toast: Callback<(String, ToastKind)>,
     last_refreshed: UseStateHandle<String>,
@@ -50,14 +80,8 @@ async fn fetch_balance(
         Ok(response) => {
             info!("Balance: {} VE (from backend)", response.balance);
             balance.set(format_balance(response.balance));
-
-            let js_date = Date::new(&js_sys::Number::from(response.timestamp as f64 * 1000.0));
-            let hours = js_date.get_hours();
-            let minutes = js_date.get_minutes();
-            let seconds = js_date.get_seconds();
-
-            let time_str = format!("Last updated: {:02}:{:02}:{:02}", hours, minutes, seconds);
-            last_refreshed.set(time_str);
+            last_refreshed.set(last_updated_label(response.timestamp));
+            balance_detail.set(Some(response));
 
             is_loading.set(false);
         }
@@ -96,22 +120,52 @@ pub fn app() -> Html {
     let wallet_created = use_state(|| false);
     let addresses = use_state(|| Vec::<WalletAddress>::new());
     let balance = use_state(|| String::new());
+    let balance_detail = use_state(|| Option::<BalanceResponse>::None);
     let transaction_status = use_state(|| String::new());
     let is_loading = use_state(|| false);
     let available_wallets = use_state(|| Vec::<WalletFile>::new());
     let node_connected = use_state(|| false);
-    let node_info = use_state(|| NodeInfo { url: String::new() });
+    let node_syncing = use_state(|| false);
+    let node_info = use_state(NodeInfo::default);
     let transactions = use_state(|| Vec::<Transaction>::new());
+    let tx_total = use_state(|| 0usize);
     let last_txid = use_state(|| String::new());
     let selected_tx = use_state(|| Option::<Transaction>::None);
     let show_modal = use_state(|| false);
     let last_sent = use_state(|| Option::<SentTxInfo>::None);
     let sent_transactions = use_state(|| Vec::<SentTxInfo>::new());
+    let batch_result = use_state(|| Option::<BatchSendResult>::None);
     let payment_secret_required = use_state(|| false);
+    let watch_only = use_state(|| false);
+    let backup_pending = use_state(|| false);
     let last_refreshed = use_state(|| "Last updated: Never".to_string());
+    let network = use_state(|| "mainnet".to_string());
+    let networks = use_state(Vec::<String>::new);
+    // Reason the open wallet auto-locked; `Some` while it is locked.
+    let lock_reason = use_state(|| Option::<String>::None);
 
     const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+    {
+        let network = network.clone();
+        let networks = networks.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(res) = safe_invoke("list_networks", JsValue::NULL).await {
+                    if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<String>>(res) {
+                        networks.set(list);
+                    }
+                }
+                if let Ok(res) = safe_invoke("get_network", JsValue::NULL).await {
+                    if let Some(n) = res.as_string() {
+                        network.set(n);
+                    }
+                }
+            });
+            || {}
+        });
+    }
+
     {
         let last_txid = last_txid.clone();
         let screen = screen.clone();
@@ -126,50 +180,117 @@ pub fn app() -> Html {
     {
         let node_connected = node_connected.clone();
         let node_info = node_info.clone();
-        let push_toast = push_toast.clone();
+        let node_syncing = node_syncing.clone();
         use_effect_with(wallet_created.clone(), move |created| {
             if **created {
                 let node_connected = node_connected.clone();
                 let node_info = node_info.clone();
-                let push_toast = push_toast.clone();
+                let node_syncing = node_syncing.clone();
                 spawn_local(async move {
-                    match safe_invoke("is_node_connected", JsValue::NULL).await {
-                        Ok(conn) => {
-                            if conn.as_bool() == Some(true) {
-                                node_connected.set(true);
-                                match safe_invoke("get_node_info", JsValue::NULL).await {
-                                    Ok(info_res) => {
-                                        if let Ok(info) = serde_wasm_bindgen::from_value::<NodeInfo>(info_res) {
-                                            node_info.set(info);
-                                        } else {
-                                            node_info.set(NodeInfo { url: "Unknown".into() });
-                                        }
-                                    }
-                                    Err(_) => {
-                                        node_info.set(NodeInfo { url: "Unknown".into() });
-                                    }
-                                }
-                            } else {
-                                node_connected.set(false);
-                                node_info.set(NodeInfo { url: "Not connected".into() });
-                                push_toast.emit(("Not connected to Vecno node — balance & transactions unavailable".into(), ToastKind::Warning));
+                    match safe_invoke("get_node_info", JsValue::NULL).await {
+                        Ok(info_res) => match serde_wasm_bindgen::from_value::<NodeInfo>(info_res) {
+                            Ok(info) => {
+                                node_connected.set(info.connected);
+                                node_syncing.set(info.connected && !info.is_synced);
+                                node_info.set(info);
                             }
-                        }
+                            Err(_) => node_info.set(NodeInfo { url: "Unknown".into(), ..Default::default() }),
+                        },
                         Err(_) => {
                             node_connected.set(false);
-                            node_info.set(NodeInfo { url: "Not connected".into() });
-                            push_toast.emit(("Not connected to Vecno node — balance & transactions unavailable".into(), ToastKind::Warning));
+                            node_info.set(NodeInfo { url: "Not connected".into(), ..Default::default() });
                         }
                     }
                 });
             } else {
                 node_connected.set(false);
-                node_info.set(NodeInfo { url: "".into() });
+                node_syncing.set(false);
+                node_info.set(NodeInfo { url: "".into(), ..Default::default() });
             }
             || {}
         });
     }
 
+    {
+        let node_connected = node_connected.clone();
+        let node_info = node_info.clone();
+        let node_syncing = node_syncing.clone();
+        let push_toast = push_toast.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let on_status = {
+                    let node_connected = node_connected.clone();
+                    let node_info = node_info.clone();
+                    let node_syncing = node_syncing.clone();
+                    move |payload: JsValue| {
+                        if let Ok(status) = serde_wasm_bindgen::from_value::<NodeStatusEvent>(payload) {
+                            node_connected.set(true);
+                            node_syncing.set(!status.is_synced);
+                            // The backend updates its node record before emitting,
+                            // so re-read it to pick up encoding/resolver details.
+                            let node_info = node_info.clone();
+                            spawn_local(async move {
+                                if let Ok(res) = safe_invoke("get_node_info", JsValue::NULL).await {
+                                    if let Ok(info) = serde_wasm_bindgen::from_value::<NodeInfo>(res) {
+                                        node_info.set(info);
+                                    }
+                                }
+                            });
+                        }
+                    }
+                };
+
+                for event in ["node://connected", "node://syncing"] {
+                    if let Err(e) = listen(event, on_status.clone()).await {
+                        error!("Failed to listen for {}: {}", event, e);
+                    }
+                }
+
+                let on_disconnected = move |payload: JsValue| {
+                    if let Ok(evt) = serde_wasm_bindgen::from_value::<NodeDisconnectedEvent>(payload) {
+                        node_connected.set(false);
+                        node_syncing.set(false);
+                        node_info.set(NodeInfo {
+                            url: evt.url.unwrap_or_else(|| "Not connected".into()),
+                            ..Default::default()
+                        });
+                        if evt.attempt == 1 {
+                            push_toast.emit((
+                                "Lost connection to Vecno node — reconnecting…".into(),
+                                ToastKind::Warning
+                            ));
+                        }
+                    }
+                };
+
+                if let Err(e) = listen("node://disconnected", on_disconnected).await {
+                    error!("Failed to listen for node://disconnected: {}", e);
+                }
+            });
+            || {}
+        });
+    }
+
+    {
+        let lock_reason = lock_reason.clone();
+        let show_modal = show_modal.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let on_locked = move |payload: JsValue| {
+                    let reason = serde_wasm_bindgen::from_value::<LockedEvent>(payload)
+                        .map(|evt| evt.reason)
+                        .unwrap_or_default();
+                    show_modal.set(false);
+                    lock_reason.set(Some(reason));
+                };
+                if let Err(e) = listen("wallet://locked", on_locked).await {
+                    error!("Failed to listen for wallet://locked: {}", e);
+                }
+            });
+            || {}
+        });
+    }
+
     {
         let screen = screen.clone();
         let available_wallets = available_wallets.clone();
@@ -210,15 +331,19 @@ pub fn app() -> Html {
         let is_loading = is_loading.clone();
         let push_toast = push_toast.clone();
         let payment_secret_required = payment_secret_required.clone();
+        let watch_only = watch_only.clone();
+        let backup_pending = backup_pending.clone();
 
         use_effect_with((screen.clone(), wallet_created.clone()), move |(s, created)| {
-            if **created && matches!(**s, Screen::Wallet | Screen::Receive | Screen::Send | Screen::Transactions) {
+            if **created && matches!(**s, Screen::Wallet | Screen::Receive | Screen::Send | Screen::Transactions | Screen::Multisig | Screen::Offline) {
                 let addr = addresses.clone();
                 let loading = is_loading.clone();
                 let push_toast = push_toast.clone();
                 let scr = screen.clone();
                 let wc = wallet_created.clone();
                 let req = payment_secret_required.clone();
+                let wo = watch_only.clone();
+                let bp = backup_pending.clone();
 
                 spawn_local(async move {
                     loading.set(true);
@@ -277,6 +402,20 @@ pub fn app() -> Html {
                         }
                     }
 
+                    let is_watch_only = safe_invoke("is_watch_only", JsValue::NULL)
+                        .await
+                        .ok()
+                        .and_then(|res| res.as_bool())
+                        .unwrap_or(false);
+                    wo.set(is_watch_only);
+
+                    let is_backup_pending = safe_invoke("mnemonic_backup_pending", JsValue::NULL)
+                        .await
+                        .ok()
+                        .and_then(|res| res.as_bool())
+                        .unwrap_or(false);
+                    bp.set(is_backup_pending);
+
                     match safe_invoke("is_node_connected", JsValue::NULL).await {
                         Ok(conn) if conn.as_bool() != Some(true) => {
                             push_toast.emit((
@@ -291,6 +430,8 @@ pub fn app() -> Html {
                 });
             } else if !**created {
                 payment_secret_required.set(false);
+                watch_only.set(false);
+                backup_pending.set(false);
                 addresses.set(vec![]);
             }
             || {}
@@ -300,95 +441,125 @@ pub fn app() -> Html {
     {
         let addresses = addresses.clone();
         let balance = balance.clone();
+        let balance_detail = balance_detail.clone();
         let is_loading = is_loading.clone();
         let push_toast = push_toast.clone();
         let last_refreshed = last_refreshed.clone();
         let wallet_created = wallet_created.clone();
 
-        let interval_handle = use_state(|| Option::<Interval>::None);
-        let interval_handle_clone = interval_handle.clone();
-
         use_effect_with((wallet_created.clone(), addresses.clone()), move |(created, addrs)| {
-            let cleanup = move || {
-                interval_handle_clone.set(None);
-            };
-
             if **created && !addrs.is_empty() {
+                // One initial read; after that the backend pushes wallet://balance.
                 let a = addrs.clone();
                 let b = balance.clone();
+                let bd = balance_detail.clone();
                 let l = is_loading.clone();
                 let pt = push_toast.clone();
                 let lr = last_refreshed.clone();
-                let interval_handle = interval_handle.clone();
 
                 l.set(true);
                 lr.set("Last updated: —".to_string());
 
-                spawn_local({
-                    let a = a.clone();
-                    let b = b.clone();
-                    let l = l.clone();
-                    let pt = pt.clone();
-                    let lr = lr.clone();
-                    async move {
-                        gloo_timers::future::TimeoutFuture::new(5_000).await;
-                        fetch_balance(a, b, l, pt, lr).await;
-                    }
-                });
-
-                let tick = move || {
-                    let a = a.clone();
-                    let b = b.clone();
-                    let l = l.clone();
-                    let pt = pt.clone();
-                    let lr = lr.clone();
-                    spawn_local(async move {
-                        l.set(true);
-                        fetch_balance(a, b, l, pt, lr).await;
-                    });
-                };
-
-                let first_tick_timeout = Timeout::new(30_000, move || {
-                    tick();
-                    let interval = Interval::new(30_000, tick);
-                    interval_handle.set(Some(interval));
+                spawn_local(async move {
+                    fetch_balance(a, b, bd, l, pt, lr).await;
                 });
-
-                std::mem::forget(first_tick_timeout);
             } else {
                 balance.set(String::new());
+                balance_detail.set(None);
                 last_refreshed.set("Last updated: Never".to_string());
                 is_loading.set(false);
-                cleanup();
             }
+            || {}
+        });
+    }
+
+    {
+        let balance = balance.clone();
+        let balance_detail = balance_detail.clone();
+        let last_refreshed = last_refreshed.clone();
+        let transactions = transactions.clone();
+        let tx_total = tx_total.clone();
+        let push_toast = push_toast.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let on_balance = move |payload: JsValue| {
+                    if let Ok(response) = serde_wasm_bindgen::from_value::<BalanceResponse>(payload) {
+                        info!("Balance pushed: {} VE", response.balance);
+                        balance.set(format_balance(response.balance));
+                        last_refreshed.set(last_updated_label(response.timestamp));
+                        balance_detail.set(Some(response));
+                    }
+                };
+                if let Err(e) = listen("wallet://balance", on_balance).await {
+                    error!("Failed to listen for wallet://balance: {}", e);
+                }
+
+                let reload_transactions = move || {
+                    let txs = transactions.clone();
+                    let tx_total = tx_total.clone();
+                    spawn_local(async move {
+                        if let Ok(page) = fetch_transaction_page(0).await {
+                            tx_total.set(page.total);
+                            txs.set(page.transactions);
+                        }
+                    });
+                };
 
-            cleanup
+                let tx_events: [(&str, ToastKind); 3] = [
+                    ("wallet://pending", ToastKind::Info),
+                    ("wallet://maturity", ToastKind::Success),
+                    ("wallet://reorg", ToastKind::Warning),
+                ];
+                for (event, kind) in tx_events {
+                    let push_toast = push_toast.clone();
+                    let reload_transactions = reload_transactions.clone();
+                    let handler = move |payload: JsValue| {
+                        let Ok(tx) = serde_wasm_bindgen::from_value::<TransactionEvent>(payload) else {
+                            return;
+                        };
+                        let msg = match (event, tx.kind.as_str()) {
+                            ("wallet://pending", "incoming") => format!("Incoming {} (pending)", format_amount(tx.amount)),
+                            ("wallet://maturity", "incoming") => format!("Received {}", format_amount(tx.amount)),
+                            ("wallet://maturity", _) => format!("Transaction {}… confirmed", &tx.txid[..tx.txid.len().min(12)]),
+                            ("wallet://reorg", _) => format!("Transaction {}… was reorged out", &tx.txid[..tx.txid.len().min(12)]),
+                            _ => String::new(),
+                        };
+                        if !msg.is_empty() {
+                            push_toast.emit((msg, kind.clone()));
+                        }
+                        reload_transactions();
+                    };
+                    if let Err(e) = listen(event, handler).await {
+                        error!("Failed to listen for {}: {}", event, e);
+                    }
+                }
+            });
+            || {}
         });
     }
 
     {
         let screen = screen.clone();
         let transactions = transactions.clone();
+        let tx_total = tx_total.clone();
         let is_loading = is_loading.clone();
         let push_toast = push_toast.clone();
         use_effect_with(screen.clone(), move |s| {
             if matches!(**s, Screen::Transactions) {
                 let txs = transactions.clone();
+                let tx_total = tx_total.clone();
                 let l = is_loading.clone();
                 let pt = push_toast.clone();
                 spawn_local(async move {
                     l.set(true);
-                    match safe_invoke("list_transactions", JsValue::NULL).await {
-                        Ok(res) => {
-                            if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<Transaction>>(res) {
-                                txs.set(list);
-                            } else {
-                                pt.emit(("Failed to parse transactions".into(), ToastKind::Error));
-                                txs.set(vec![]);
-                            }
+                    match fetch_transaction_page(0).await {
+                        Ok(page) => {
+                            tx_total.set(page.total);
+                            txs.set(page.transactions);
                         }
                         Err(e) => {
                             pt.emit((e, ToastKind::Error));
+                            tx_total.set(0);
                             txs.set(vec![]);
                         }
                     }
@@ -399,6 +570,99 @@ pub fn app() -> Html {
         });
     }
 
+    let load_more_transactions = {
+        let transactions = transactions.clone();
+        let tx_total = tx_total.clone();
+        let is_loading = is_loading.clone();
+        let push_toast = push_toast.clone();
+        Callback::from(move |_: ()| {
+            let txs = transactions.clone();
+            let tx_total = tx_total.clone();
+            let l = is_loading.clone();
+            let pt = push_toast.clone();
+            spawn_local(async move {
+                l.set(true);
+                match fetch_transaction_page(txs.len()).await {
+                    Ok(page) => {
+                        let mut current = (*txs).clone();
+                        current.extend(
+                            page.transactions
+                                .into_iter()
+                                .filter(|tx| !current.iter().any(|c| c.txid == tx.txid))
+                                .collect::<Vec<_>>(),
+                        );
+                        tx_total.set(page.total);
+                        txs.set(current);
+                    }
+                    Err(e) => pt.emit((e, ToastKind::Error)),
+                }
+                l.set(false);
+            });
+        })
+    };
+
+    // Balance, history and the receive address all follow the selected
+    // account, so reload them whenever the account changes.
+    let on_account_changed = {
+        let addresses = addresses.clone();
+        let transactions = transactions.clone();
+        let tx_total = tx_total.clone();
+        let sent_transactions = sent_transactions.clone();
+        let last_sent = last_sent.clone();
+        let batch_result = batch_result.clone();
+        let payment_secret_required = payment_secret_required.clone();
+        let watch_only = watch_only.clone();
+        let push_toast = push_toast.clone();
+        Callback::from(move |_: ()| {
+            let addr = addresses.clone();
+            let txs = transactions.clone();
+            let tx_total = tx_total.clone();
+            let req = payment_secret_required.clone();
+            let wo = watch_only.clone();
+            let pt = push_toast.clone();
+            sent_transactions.set(vec![]);
+            last_sent.set(None);
+            batch_result.set(None);
+            spawn_local(async move {
+                match fetch_addresses().await {
+                    Ok(list) => addr.set(list),
+                    Err(e) => pt.emit((e, ToastKind::Error)),
+                }
+                if let Ok(needs) = safe_invoke("wallet_needs_payment_secret", JsValue::NULL).await {
+                    req.set(needs.as_bool().unwrap_or(false));
+                }
+                if let Ok(res) = safe_invoke("is_watch_only", JsValue::NULL).await {
+                    wo.set(res.as_bool().unwrap_or(false));
+                }
+                match fetch_transaction_page(0).await {
+                    Ok(page) => {
+                        tx_total.set(page.total);
+                        txs.set(page.transactions);
+                    }
+                    Err(_) => {
+                        tx_total.set(0);
+                        txs.set(vec![]);
+                    }
+                }
+            });
+        })
+    };
+
+    let reload_addresses = {
+        let addresses = addresses.clone();
+        let push_toast = push_toast.clone();
+        Callback::from(move |_: ()| {
+            let addr = addresses.clone();
+            let pt = push_toast.clone();
+            spawn_local(async move {
+                match fetch_addresses().await {
+                    Ok(list) => addr.set(list),
+                    Err(e) => pt.emit((e, ToastKind::Error)),
+                }
+            });
+        })
+    };
+
     let set_screen = |s: Screen| {
         let scr = screen.clone();
         Callback::from(move |_| scr.set(s.clone()))
@@ -424,6 +688,44 @@ pub fn app() -> Html {
         let wc = wallet_created.clone();
         Callback::from(move |_| if *wc { scr.set(Screen::Send) })
     };
+    let to_multisig = {
+        let scr = screen.clone();
+        let wc = wallet_created.clone();
+        Callback::from(move |_| if *wc { scr.set(Screen::Multisig) })
+    };
+    let to_offline = {
+        let scr = screen.clone();
+        let wc = wallet_created.clone();
+        Callback::from(move |_| if *wc { scr.set(Screen::Offline) })
+    };
+    let to_settings = set_screen(Screen::Settings);
+
+    let on_network_change = {
+        let network = network.clone();
+        let pt = push_toast.clone();
+        let l = is_loading.clone();
+        Callback::from(move |selected: String| {
+            let network = network.clone();
+            let pt = pt.clone();
+            let l = l.clone();
+            spawn_local(async move {
+                l.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "network": selected }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("set_network", args).await {
+                    Ok(res) => {
+                        let active = res.as_string().unwrap_or(selected);
+                        pt.emit((format!("Network set to {}", network_label(&active)), ToastKind::Success));
+                        network.set(active);
+                    }
+                    Err(e) => {
+                        pt.emit((e, ToastKind::Error));
+                    }
+                }
+                l.set(false);
+            });
+        })
+    };
 
     let switch_wallet_callback = {
         let scr = screen.clone();
@@ -450,7 +752,7 @@ pub fn app() -> Html {
                         pt.emit(("Wallet closed. Select a new one.".into(), ToastKind::Success));
                         wc.set(false);
                         nc.set(false);
-                        ni.set(NodeInfo { url: "".into() });
+                        ni.set(NodeInfo { url: "".into(), ..Default::default() });
                         scr.set(Screen::Home);
                     }
                     Err(e) => {
@@ -462,6 +764,25 @@ pub fn app() -> Html {
         })
     };
 
+    let lock_wallet_callback = Callback::from(|_: MouseEvent| {
+        spawn_local(async {
+            if let Err(e) = safe_invoke("lock_wallet", JsValue::NULL).await {
+                error!("Failed to lock wallet: {}", e);
+            }
+        });
+    });
+
+    let on_unlocked = {
+        let lock_reason = lock_reason.clone();
+        let on_account_changed = on_account_changed.clone();
+        let push_toast = push_toast.clone();
+        Callback::from(move |_: ()| {
+            lock_reason.set(None);
+            push_toast.emit(("Wallet unlocked".into(), ToastKind::Success));
+            on_account_changed.emit(());
+        })
+    };
+
     let exit_app_callback = {
         let pt = push_toast.clone();
         let l = is_loading.clone();
@@ -483,6 +804,7 @@ pub fn app() -> Html {
         let scr = screen.clone();
         let l = is_loading.clone();
         let pt = push_toast.clone();
+        let network = network.clone();
         Callback::from(move |(filename, secret): (String, String)| {
             if filename.is_empty() {
                 pt.emit(("Select a Wallet".into(), ToastKind::Error));
@@ -503,6 +825,7 @@ pub fn app() -> Html {
             let scr = scr.clone();
             let l = l.clone();
             let pt = pt.clone();
+            let network = network.clone();
 
             spawn_local(async move {
                 l.set(true);
@@ -535,6 +858,12 @@ pub fn app() -> Html {
                                         wc.set(true);
                                         scr.set(Screen::Wallet);
 
+                                        if let Ok(res) = safe_invoke("get_network", JsValue::NULL).await {
+                                            if let Some(n) = res.as_string() {
+                                                network.set(n);
+                                            }
+                                        }
+
                                         let pt2 = pt.clone();
                                         spawn_local(async move {
                                             match safe_invoke("is_node_connected", JsValue::NULL).await {
@@ -575,7 +904,7 @@ pub fn app() -> Html {
         let scr = screen.clone();
         let l = is_loading.clone();
         let pt = push_toast.clone();
-        Callback::from(move |(filename, secret, payment_secret): (String, String, Option<String>)| {
+        Callback::from(move |(filename, secret, payment_secret, word_count, language): (String, String, Option<String>, usize, String)| {
             if filename.is_empty() {
                 pt.emit(("Wallet filename is required".into(), ToastKind::Error));
                 return;
@@ -605,7 +934,9 @@ pub fn app() -> Html {
                     "input": {
                         "filename": filename,
                         "secret": secret,
-                        "payment_secret": payment_secret
+                        "payment_secret": payment_secret,
+                        "word_count": word_count,
+                        "language": language
                     }
                 })) {
                     Ok(a) => a,
@@ -623,10 +954,12 @@ pub fn app() -> Html {
                                 pt.emit(("Wallet created!".into(), ToastKind::Success));
                                 wc.set(true);
 
-                                if let Some(mnemonic) = msg.split("with mnemonic: ").nth(1) {
-                                    scr.set(Screen::MnemonicDisplay(mnemonic.to_string()));
-                                } else {
-                                    scr.set(Screen::Wallet);
+                                match reveal_mnemonic(&secret, payment_secret.as_deref()).await {
+                                    Ok(mnemonic) => scr.set(Screen::MnemonicDisplay(mnemonic)),
+                                    Err(e) => {
+                                        pt.emit((format!("Could not show the recovery phrase: {}", e), ToastKind::Error));
+                                        scr.set(Screen::Wallet);
+                                    }
                                 }
 
                                 let pt2 = pt.clone();
@@ -753,16 +1086,92 @@ pub fn app() -> Html {
         })
     };
 
+    let import_watch_only = {
+        let wc = wallet_created.clone();
+        let scr = screen.clone();
+        let l = is_loading.clone();
+        let pt = push_toast.clone();
+        Callback::from(move |request: WatchOnlyRequest| {
+            let WatchOnlyRequest { xpub, addresses, secret, filename } = request;
+            let wc = wc.clone();
+            let scr = scr.clone();
+            let l = l.clone();
+            let pt = pt.clone();
+
+            spawn_local(async move {
+                l.set(true);
+
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "input": {
+                        "xpub": xpub,
+                        "addresses": addresses,
+                        "secret": secret,
+                        "filename": filename
+                    }
+                })).unwrap_or(JsValue::NULL);
+
+                match safe_invoke("import_watch_only", args).await {
+                    Ok(_) => {
+                        pt.emit(("Watch-only wallet created!".into(), ToastKind::Success));
+                        wc.set(true);
+                        scr.set(Screen::Wallet);
+                    }
+                    Err(e) => pt.emit((e, ToastKind::Error)),
+                }
+                l.set(false);
+            });
+        })
+    };
+
+    let import_private_key = {
+        let wc = wallet_created.clone();
+        let scr = screen.clone();
+        let l = is_loading.clone();
+        let pt = push_toast.clone();
+        Callback::from(move |request: PrivateKeyRequest| {
+            let PrivateKeyRequest { private_key, secret, payment_secret, filename } = request;
+            let wc = wc.clone();
+            let scr = scr.clone();
+            let l = l.clone();
+            let pt = pt.clone();
+
+            spawn_local(async move {
+                l.set(true);
+
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "input": {
+                        "private_key": private_key,
+                        "secret": secret,
+                        "payment_secret": payment_secret,
+                        "filename": filename
+                    }
+                })).unwrap_or(JsValue::NULL);
+
+                match safe_invoke("import_private_key", args).await {
+                    Ok(_) => {
+                        pt.emit(("Private key imported!".into(), ToastKind::Success));
+                        wc.set(true);
+                        scr.set(Screen::Wallet);
+                    }
+                    Err(e) => pt.emit((e, ToastKind::Error)),
+                }
+                l.set(false);
+            });
+        })
+    };
+
     let send_transaction = {
         let l = is_loading.clone();
         let txs = transactions.clone();
+        let tx_total = tx_total.clone();
         let last = last_txid.clone();
         let wc = wallet_created.clone();
         let pt = push_toast.clone();
         let last_sent = last_sent.clone();
         let sent_transactions = sent_transactions.clone();
 
-        Callback::from(move |(to_addr, amount_veni, payment_secret): (String, u64, Option<String>)| {
+        Callback::from(move |request: SendRequest| {
+            let SendRequest { to_address: to_addr, amount: amount_veni, payment_secret, fee_priority, sweep, utxos } = request;
             if to_addr.is_empty() {
                 pt.emit(("Recipient address is required".into(), ToastKind::Error));
                 return;
@@ -778,6 +1187,7 @@ pub fn app() -> Html {
 
             let l = l.clone();
             let txs = txs.clone();
+            let tx_total = tx_total.clone();
             let last = last.clone();
             let pt = pt.clone();
             let last_sent = last_sent.clone();
@@ -789,7 +1199,10 @@ pub fn app() -> Html {
                     "input": {
                         "to_address": to_addr,
                         "amount": amount_veni,
-                        "payment_secret": payment_secret
+                        "payment_secret": payment_secret,
+                        "fee_priority": fee_priority,
+                        "sweep": sweep,
+                        "utxos": utxos
                     }
                 })).unwrap_or(JsValue::NULL);
 
@@ -802,6 +1215,7 @@ pub fn app() -> Html {
                     }
                 };
                 let res_clone = res.clone();
+                let mut optimistic_entry = None;
                 if let Ok(sent) = serde_wasm_bindgen::from_value::<SentTxInfo>(res) {
                     last.set(sent.txid.clone());
                     last_sent.set(Some(sent.clone()));
@@ -817,45 +1231,111 @@ pub fn app() -> Html {
                     let mut current_txs = (*txs).clone();
                     let optimistic = Transaction {
                         txid: sent.txid.clone(),
+                        kind: "outgoing".into(),
                         to_address: sent.to_address.clone(),
                         amount: sent.amount,
                         timestamp: sent.timestamp.clone(),
+                        maturity: "pending".into(),
+                        ..Default::default()
                     };
-                    current_txs.insert(0, optimistic);
+                    current_txs.insert(0, optimistic.clone());
                     txs.set(current_txs);
+                    optimistic_entry = Some(optimistic);
                 } else {
                     let msg = get_error_message(res_clone);
                     pt.emit((msg, ToastKind::Error));
                 }
-                match safe_invoke("list_transactions", JsValue::NULL).await {
-                    Ok(list_res) => {
-                        if let Ok(list) = serde_wasm_bindgen::from_value::<Vec<Transaction>>(list_res) {
-                            txs.set(list);
+                if let Ok(page) = fetch_transaction_page(0).await {
+                    let mut list = page.transactions;
+                    // The outgoing record is written once wallet-core sees the
+                    // spend, so keep the optimistic entry until then.
+                    if let Some(optimistic) = optimistic_entry {
+                        if !list.iter().any(|tx| tx.txid == optimistic.txid) {
+                            list.insert(0, optimistic);
                         }
                     }
-                    Err(_) => {}
+                    tx_total.set(page.total);
+                    txs.set(list);
                 }
+                l.set(false);
             });
         })
     };
 
-    let copy_mnemonic = {
+    let send_batch = {
+        let l = is_loading.clone();
+        let txs = transactions.clone();
+        let tx_total = tx_total.clone();
+        let last = last_txid.clone();
+        let wc = wallet_created.clone();
         let pt = push_toast.clone();
-        Callback::from(move |mnemonic: String| {
+        let batch_result = batch_result.clone();
+
+        Callback::from(move |request: BatchRequest| {
+            if !*wc {
+                pt.emit(("No wallet open".into(), ToastKind::Error));
+                return;
+            }
+
+            let l = l.clone();
+            let txs = txs.clone();
+            let tx_total = tx_total.clone();
+            let last = last.clone();
             let pt = pt.clone();
+            let batch_result = batch_result.clone();
+
             spawn_local(async move {
-                if let Some(nav) = web_sys::window().and_then(|w| Some(w.navigator())) {
-                    if let Err(e) = wasm_bindgen_futures::JsFuture::from(nav.clipboard().write_text(&mnemonic)).await {
-                        error!("Clipboard error: {:?}", e);
-                        pt.emit(("Copy failed".into(), ToastKind::Error));
-                    } else {
-                        pt.emit(("Mnemonic copied!".into(), ToastKind::Success));
+                l.set(true);
+                let outputs: Vec<_> = request
+                    .rows
+                    .iter()
+                    .map(|row| serde_json::json!({ "to_address": row.to_address, "amount": row.amount }))
+                    .collect();
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "input": {
+                        "outputs": outputs,
+                        "payment_secret": request.payment_secret,
+                        "fee_priority": request.fee_priority,
+                        "utxos": request.utxos
                     }
+                })).unwrap_or(JsValue::NULL);
+
+                match safe_invoke("send_batch", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<BatchSendResult>(res) {
+                        Ok(result) => {
+                            if result.txids.is_empty() {
+                                let invalid = result.outputs.iter().filter(|o| o.status == "invalid").count();
+                                pt.emit((format!("Batch not sent: {} invalid output(s)", invalid), ToastKind::Error));
+                            } else {
+                                if let Some(txid) = result.txids.last() {
+                                    last.set(txid.clone());
+                                }
+                                pt.emit((
+                                    format!("Batch sent in {} transaction(s)", result.txids.len()),
+                                    ToastKind::Success,
+                                ));
+                            }
+                            batch_result.set(Some(result));
+                        }
+                        Err(e) => pt.emit((format!("Unexpected batch response: {}", e), ToastKind::Error)),
+                    },
+                    Err(e) => pt.emit((e, ToastKind::Error)),
                 }
+
+                if let Ok(page) = fetch_transaction_page(0).await {
+                    tx_total.set(page.total);
+                    txs.set(page.transactions);
+                }
+                l.set(false);
             });
         })
     };
 
+    let copy_mnemonic = {
+        let pt = push_toast.clone();
+        Callback::from(move |mnemonic: String| copy_secret(mnemonic, pt.clone()))
+    };
+
     let open_modal = {
         let selected = selected_tx.clone();
         let show = show_modal.clone();
@@ -875,167 +1355,295 @@ pub fn app() -> Html {
             <div class="node-status node-status-fixed" aria-live="polite">
                 <div class={classes!(
                     "node-indicator",
-                    if *node_connected { "connected" } else { "disconnected" }
+                    if !*node_connected { "disconnected" } else if *node_syncing { "syncing" } else { "connected" }
                 )}></div>
                 <span class="node-status-text">
-                    { if *node_connected { "Connected" } else { "Disconnected" } }
+                    { if !*node_connected { "Disconnected" } else if *node_syncing { "Syncing" } else { "Connected" } }
                 </span>
+                if !is_mainnet(&network) {
+                    <span class="network-badge">{ network_label(&network) }</span>
+                }
                 { 
-                    if !*node_connected {
+                    if !*wallet_created {
                         html! {
                             <span class="node-tooltip">{"Open, import or create a wallet to connect!"}</span>
                         }
+                    } else if !*node_connected {
+                        html! {
+                            <span class="node-tooltip">{ format!("Reconnecting to {}…", node_info.url) }</span>
+                        }
                     } else {
                         html! {
-                            <span class="node-tooltip">{ &node_info.url }</span>
+                            <span class="node-tooltip">
+                                { &node_info.url }
+                                if let Some(version) = &node_info.server_version {
+                                    { format!(" · v{}{}", version, if node_info.is_synced { "" } else { " (syncing)" }) }
+                                }
+                            </span>
                         }
                     }
                 }
             </div>
             <div class="app-title">{ format!("Vecno Wallet v{}", VERSION) }</div>
-            <div class="layout">
-                <aside class="sidebar">
-                    <nav class="nav">
-                        <button class={classes!("nav-item", if *screen == Screen::Home { "active" } else { "" })} onclick={to_home.clone()}>
-                            <span aria-hidden="true"></span>
-                            {"Home"}
-                        </button>
-                        <button class={classes!("nav-item", if *screen == Screen::Wallet { "active" } else { "" })} onclick={to_wallet} disabled={!*wallet_created}>
-                            <span aria-hidden="true"></span>
-                            {"Wallet"}
-                        </button>
-                        <button class={classes!("nav-item", if *screen == Screen::Transactions { "active" } else { "" })} onclick={to_transactions} disabled={!*wallet_created}>
-                            <span aria-hidden="true"></span>
-                            {"Transactions"}
-                        </button>
-                        <button class={classes!("nav-item", if *screen == Screen::Receive { "active" } else { "" })} onclick={to_receive} disabled={!*wallet_created}>
-                            <span aria-hidden="true"></span>
-                            {"Receive"}
-                        </button>
-                        <button class={classes!("nav-item", if *screen == Screen::Send { "active" } else { "" })} onclick={to_send} disabled={!*wallet_created}>
-                            <span aria-hidden="true"></span>
-                            {"Send"}
-                        </button>
-                    </nav>
-                    <div class="sidebar-footer">
-                        <button onclick={exit_app_callback} class="exit-btn">
-                            {"Exit"}
-                        </button>
-                    </div>
-                </aside>
-                <main class="main-content">
-                    { match &*screen {
-                        Screen::Intro => html! { <Intro /> },
-                        Screen::Home => {
+            if lock_reason.is_some() {
+                <UnlockScreen
+                    reason={(*lock_reason).clone()}
+                    on_unlocked={on_unlocked}
+                    push_toast={push_toast.clone()}
+                />
+            } else {
+                <div class="layout">
+                    <aside class="sidebar">
+                        <nav class="nav">
+                            <button class={classes!("nav-item", if *screen == Screen::Home { "active" } else { "" })} onclick={to_home.clone()}>
+                                <span aria-hidden="true"></span>
+                                {"Home"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Wallet { "active" } else { "" })} onclick={to_wallet} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Wallet"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Transactions { "active" } else { "" })} onclick={to_transactions} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Transactions"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Receive { "active" } else { "" })} onclick={to_receive} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Receive"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Send { "active" } else { "" })} onclick={to_send} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Send"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Multisig { "active" } else { "" })} onclick={to_multisig} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Multisig"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Offline { "active" } else { "" })} onclick={to_offline} disabled={!*wallet_created}>
+                                <span aria-hidden="true"></span>
+                                {"Offline"}
+                            </button>
+                            <button class={classes!("nav-item", if *screen == Screen::Settings { "active" } else { "" })} onclick={to_settings}>
+                                <span aria-hidden="true"></span>
+                                {"Settings"}
+                            </button>
+                        </nav>
+                        <AccountSwitcher
+                            enabled={*wallet_created}
+                            payment_secret_required={*payment_secret_required}
+                            push_toast={push_toast.clone()}
+                            on_changed={on_account_changed.clone()}
+                        />
+                        <div class="sidebar-footer">
                             if *wallet_created {
-                                html! {
-                                    <>
-                                        <div class="screen-container home-centered" role="main">
-                                            <div class="home-inner">
-                                                <p class="home-title">{"A wallet is currently open."}</p>
-                                                <button onclick={switch_wallet_callback} class="btn btn-primary btn-large">
-                                                    {"Switch Wallet"}
-                                                </button>
-                                                <p class="home-hint">
-                                                    {"Click to close the current wallet and choose another."}
-                                                </p>
+                                <button onclick={lock_wallet_callback} class="lock-btn">
+                                    {"Lock"}
+                                </button>
+                            }
+                            <button onclick={exit_app_callback} class="exit-btn">
+                                {"Exit"}
+                            </button>
+                        </div>
+                    </aside>
+                    <main class="main-content">
+                        { match &*screen {
+                            Screen::Intro => html! { <Intro /> },
+                            Screen::Home => {
+                                if *wallet_created {
+                                    html! {
+                                        <>
+                                            <div class="screen-container home-centered" role="main">
+                                                <div class="home-inner">
+                                                    <p class="home-title">{"A wallet is currently open."}</p>
+                                                    <button onclick={switch_wallet_callback} class="btn btn-primary btn-large">
+                                                        {"Switch Wallet"}
+                                                    </button>
+                                                    <p class="home-hint">
+                                                        {"Click to close the current wallet and choose another."}
+                                                    </p>
+                                                </div>
                                             </div>
-                                        </div>
-                                    </>
+                                        </>
+                                    }
+                                } else {
+                                    html! {
+                                        <Home
+                                            available_wallets={(*available_wallets).clone()}
+                                            is_loading={*is_loading}
+                                            on_open_wallet={open_wallet}
+                                            on_create={set_screen(Screen::CreateWallet)}
+                                            on_import={set_screen(Screen::ImportWallet)}
+                                        />
+                                    }
                                 }
-                            } else {
+                            },
+                            Screen::CreateWallet => html! {
+                                <CreateWallet
+                                    on_submit={create_wallet}
+                                    is_loading={*is_loading}
+                                    on_import={set_screen(Screen::ImportWallet)}
+                                    push_toast={push_toast.clone()}
+                                />
+                            },
+                            Screen::ImportWallet => html! {
+                                <ImportWallet
+                                    on_submit={import_wallets}
+                                    is_loading={*is_loading}
+                                    on_create={set_screen(Screen::CreateWallet)}
+                                    on_watch_only={set_screen(Screen::ImportWatchOnly)}
+                                    on_private_key={set_screen(Screen::ImportPrivateKey)}
+                                    push_toast={push_toast.clone()}
+                                />
+                            },
+                            Screen::ImportWatchOnly => html! {
+                                <ImportWatchOnly
+                                    on_submit={import_watch_only}
+                                    is_loading={*is_loading}
+                                    on_import={set_screen(Screen::ImportWallet)}
+                                    push_toast={push_toast.clone()}
+                                />
+                            },
+                            Screen::ImportPrivateKey => html! {
+                                <ImportPrivateKey
+                                    on_submit={import_private_key}
+                                    is_loading={*is_loading}
+                                    on_import={set_screen(Screen::ImportWallet)}
+                                    push_toast={push_toast.clone()}
+                                />
+                            },
+                            Screen::MnemonicDisplay(m) => html! {
+                                <MnemonicDisplay
+                                    mnemonic={m.clone()}
+                                    on_copy={copy_mnemonic.clone()}
+                                    on_proceed={set_screen(Screen::MnemonicQuiz(Some(m.clone())))}
+                                />
+                            },
+                            Screen::MnemonicQuiz(m) => {
+                                let on_back = match m {
+                                    Some(m) => set_screen(Screen::MnemonicDisplay(m.clone())),
+                                    None => set_screen(Screen::Wallet),
+                                };
+                                let on_confirmed = {
+                                    let scr = screen.clone();
+                                    let bp = backup_pending.clone();
+                                    Callback::from(move |_: ()| {
+                                        bp.set(false);
+                                        scr.set(Screen::Wallet);
+                                    })
+                                };
                                 html! {
-                                    <Home
-                                        available_wallets={(*available_wallets).clone()}
-                                        is_loading={*is_loading}
-                                        on_open_wallet={open_wallet}
-                                        on_create={set_screen(Screen::CreateWallet)}
-                                        on_import={set_screen(Screen::ImportWallet)}
+                                    <MnemonicQuiz
+                                        {on_confirmed}
+                                        {on_back}
+                                        on_skip={set_screen(Screen::Wallet)}
+                                        push_toast={push_toast.clone()}
                                     />
                                 }
-                            }
-                        },
-                        Screen::CreateWallet => html! {
-                            <CreateWallet
-                                on_submit={create_wallet}
-                                is_loading={*is_loading}
-                                on_import={set_screen(Screen::ImportWallet)}
-                                push_toast={push_toast.clone()}
-                            />
-                        },
-                        Screen::ImportWallet => html! {
-                            <ImportWallet
-                                on_submit={import_wallets}
-                                is_loading={*is_loading}
-                                on_create={set_screen(Screen::CreateWallet)}
-                                push_toast={push_toast.clone()}
-                            />
-                        },
-                        Screen::MnemonicDisplay(m) => html! {
-                            <MnemonicDisplay
-                                mnemonic={m.clone()}
-                                on_copy={copy_mnemonic.clone()}
-                                on_proceed={set_screen(Screen::Wallet)}
-                            />
-                        },
-                        Screen::Wallet => html! {
-                            <Dashboard
-                                balance={(*balance).clone()}
-                                is_loading={*is_loading}
-                                last_refreshed={(*last_refreshed).clone()}
-                            />
-                        },
-                        Screen::Receive => html! {
-                            <Receive
-                                addresses={(*addresses).clone()}
-                                is_loading={*is_loading}
-                            />
-                        },
-                        Screen::Transactions => {
-                            let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
-                            html! {
-                                <Transactions
-                                    transactions={(*transactions).clone()}
+                            },
+                            Screen::Wallet => html! {
+                                <Dashboard
                                     balance={(*balance).clone()}
+                                    breakdown={(*balance_detail).clone()}
                                     is_loading={*is_loading}
-                                    our_receive_address={recv.clone()}
-                                    on_tx_click={open_modal.clone()}
+                                    last_refreshed={(*last_refreshed).clone()}
+                                    backup_pending={*backup_pending}
+                                    on_verify_backup={set_screen(Screen::MnemonicQuiz(None))}
                                 />
-                            }
-                        },
-                        Screen::Send => {
-                            let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
-                            html! {
-                                <Send
-                                    on_send={send_transaction}
-                                    transaction_status={(*transaction_status).clone()}
-                                    last_sent={(*last_sent).clone()}
-                                    balance={(*balance).clone()}
+                            },
+                            Screen::Receive => html! {
+                                <Receive
+                                    addresses={(*addresses).clone()}
                                     is_loading={*is_loading}
-                                    wallet_created={*wallet_created}
-                                    sent_transactions={(*sent_transactions).clone()}
-                                    on_tx_click={open_modal.clone()}
-                                    our_receive_address={recv}
                                     push_toast={push_toast.clone()}
+                                    on_new_address={reload_addresses}
+                                />
+                            },
+                            Screen::Transactions => {
+                                let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
+                                html! {
+                                    <Transactions
+                                        transactions={(*transactions).clone()}
+                                        total={*tx_total}
+                                        on_load_more={load_more_transactions}
+                                        balance={(*balance).clone()}
+                                        is_loading={*is_loading}
+                                        our_receive_address={recv.clone()}
+                                        on_tx_click={open_modal.clone()}
+                                    />
+                                }
+                            },
+                            Screen::Send => {
+                                let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
+                                html! {
+                                    <Send
+                                        on_send={send_transaction}
+                                        on_send_batch={send_batch}
+                                        batch_result={(*batch_result).clone()}
+                                        transaction_status={(*transaction_status).clone()}
+                                        last_sent={(*last_sent).clone()}
+                                        balance={(*balance).clone()}
+                                        is_loading={*is_loading}
+                                        wallet_created={*wallet_created}
+                                        sent_transactions={(*sent_transactions).clone()}
+                                        on_tx_click={open_modal.clone()}
+                                        our_receive_address={recv}
+                                        push_toast={push_toast.clone()}
+                                        payment_secret_required={*payment_secret_required}
+                                        watch_only={*watch_only}
+                                        node_connected={*node_connected}
+                                    />
+                                }
+                            },
+                            Screen::Multisig => {
+                                let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
+                                html! {
+                                    <Multisig
+                                        enabled={*wallet_created && *node_connected}
+                                        receive_address={recv}
+                                        payment_secret_required={*payment_secret_required}
+                                        push_toast={push_toast.clone()}
+                                        on_changed={on_account_changed}
+                                    />
+                                }
+                            },
+                            Screen::Offline => html! {
+                                <OfflineSigning
+                                    wallet_created={*wallet_created}
+                                    node_connected={*node_connected}
+                                    watch_only={*watch_only}
                                     payment_secret_required={*payment_secret_required}
+                                    push_toast={push_toast.clone()}
                                 />
-                            }
-                        },
-                    }}
-                    { if *show_modal {
-                        if let Some(ref tx) = *selected_tx {
-                            let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
-                            html! {
-                                <TxDetailModal
-                                    tx={tx.clone()}
-                                    our_address={recv}
-                                    on_close={close_modal}
+                            },
+                            Screen::Settings => html! {
+                                <Settings
+                                    network={(*network).clone()}
+                                    networks={(*networks).clone()}
+                                    wallet_open={*wallet_created}
+                                    is_loading={*is_loading}
+                                    on_network_change={on_network_change}
+                                    on_wallet_changed={on_account_changed.clone()}
+                                    payment_secret_required={*payment_secret_required}
+                                    push_toast={push_toast.clone()}
                                 />
-                            }
-                        } else { html!{} }
-                    } else { html!{} }}
-                </main>
-            </div>
+                            },
+                        }}
+                        { if *show_modal {
+                            if let Some(ref tx) = *selected_tx {
+                                let recv = addresses.first().map(|a| a.receive_address.clone()).unwrap_or_default();
+                                html! {
+                                    <TxDetailModal
+                                        tx={tx.clone()}
+                                        our_address={recv}
+                                        network={(*network).clone()}
+                                        on_close={close_modal}
+                                    />
+                                }
+                            } else { html!{} }
+                        } else { html!{} }}
+                    </main>
+                </div>
+            }
         </div>
     }
 }
@@ -1073,6 +1681,26 @@ pub fn run_app() {
             .unwrap();
         contextmenu.forget();
 
+        // Keep the backend idle lock from firing while the user is active,
+        // reporting at most once per ACTIVITY_THROTTLE_MS.
+        let last_reported = std::rc::Rc::new(std::cell::Cell::new(0.0));
+        let activity = Closure::<dyn FnMut(_)>::new(move |_: web_sys::Event| {
+            let now = Date::now();
+            if now - last_reported.get() < ACTIVITY_THROTTLE_MS {
+                return;
+            }
+            last_reported.set(now);
+            spawn_local(async {
+                let _ = safe_invoke("record_activity", JsValue::NULL).await;
+            });
+        });
+        for event in ["mousedown", "mousemove", "keydown", "wheel", "touchstart"] {
+            document
+                .add_event_listener_with_callback(event, activity.as_ref().unchecked_ref())
+                .unwrap();
+        }
+        activity.forget();
+
         if let Ok(tauri) = js_sys::Reflect::get(&window, &"__TAURI__".into()) {
             if let Ok(window_obj) = js_sys::Reflect::get(&tauri, &"window".into()) {
                 if let Ok(current_fn) = js_sys::Reflect::get(&window_obj, &"getCurrent".into()) {
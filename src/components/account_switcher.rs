@@ -0,0 +1,265 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use crate::models::{AccountInfo, ToastKind};
+use crate::utils::{format_amount, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct AccountSwitcherProps {
+    pub enabled: bool,
+    pub payment_secret_required: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+    pub on_changed: Callback<()>,
+}
+
+#[derive(Clone, PartialEq)]
+enum Editor {
+    Closed,
+    Create,
+    Rename,
+}
+
+async fn fetch_accounts() -> Result<Vec<AccountInfo>, String> {
+    let res = safe_invoke("list_accounts", JsValue::NULL).await?;
+    serde_wasm_bindgen::from_value::<Vec<AccountInfo>>(res)
+        .map_err(|_| "Failed to parse accounts".to_string())
+}
+
+#[function_component(AccountSwitcher)]
+pub fn account_switcher(props: &AccountSwitcherProps) -> Html {
+    let accounts = use_state(Vec::<AccountInfo>::new);
+    let editor = use_state(|| Editor::Closed);
+    let name = use_state(String::new);
+    let payment_secret = use_state(String::new);
+    let busy = use_state(|| false);
+
+    let reload = {
+        let accounts = accounts.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_: ()| {
+            let accounts = accounts.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                match fetch_accounts().await {
+                    Ok(list) => accounts.set(list),
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        let accounts = accounts.clone();
+        use_effect_with(props.enabled, move |enabled| {
+            if *enabled {
+                reload.emit(());
+            } else {
+                accounts.set(Vec::new());
+            }
+            || {}
+        });
+    }
+
+    let active = accounts.iter().find(|a| a.active).cloned();
+
+    let on_select = {
+        let reload = reload.clone();
+        let on_changed = props.on_changed.clone();
+        let push_toast = props.push_toast.clone();
+        let busy = busy.clone();
+        Callback::from(move |e: Event| {
+            let Some(el) = e.target_dyn_into::<HtmlSelectElement>() else { return };
+            let account_id = el.value();
+            let reload = reload.clone();
+            let on_changed = on_changed.clone();
+            let push_toast = push_toast.clone();
+            let busy = busy.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "accountId": account_id }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("select_account", args).await {
+                    Ok(res) => {
+                        if let Ok(account) = serde_wasm_bindgen::from_value::<AccountInfo>(res) {
+                            push_toast.emit((format!("Switched to {}", account.name), ToastKind::Info));
+                        }
+                        on_changed.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                reload.emit(());
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_refresh = {
+        let reload = reload.clone();
+        Callback::from(move |_: FocusEvent| reload.emit(()))
+    };
+
+    let open_editor = {
+        let editor = editor.clone();
+        let name = name.clone();
+        let payment_secret = payment_secret.clone();
+        let current = active.as_ref().map(|a| a.name.clone()).unwrap_or_default();
+        move |mode: Editor| {
+            let editor = editor.clone();
+            let name = name.clone();
+            let payment_secret = payment_secret.clone();
+            let current = current.clone();
+            Callback::from(move |_| {
+                name.set(if mode == Editor::Rename { current.clone() } else { String::new() });
+                payment_secret.set(String::new());
+                editor.set(if *editor == mode { Editor::Closed } else { mode.clone() });
+            })
+        }
+    };
+
+    let on_name = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                name.set(i.value());
+            }
+        })
+    };
+
+    let on_payment_secret = {
+        let payment_secret = payment_secret.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                payment_secret.set(i.value());
+            }
+        })
+    };
+
+    let on_submit = {
+        let editor = editor.clone();
+        let name = name.clone();
+        let payment_secret = payment_secret.clone();
+        let busy = busy.clone();
+        let reload = reload.clone();
+        let on_changed = props.on_changed.clone();
+        let push_toast = props.push_toast.clone();
+        let active_id = active.as_ref().map(|a| a.id.clone()).unwrap_or_default();
+        let payment_secret_required = props.payment_secret_required;
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mode = (*editor).clone();
+            let value = (*name).trim().to_string();
+            let secret = (*payment_secret).trim().to_string();
+
+            let (cmd, args) = match mode {
+                Editor::Create => {
+                    if payment_secret_required && secret.is_empty() {
+                        push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
+                        return;
+                    }
+                    ("create_account", serde_json::json!({
+                        "input": {
+                            "name": (!value.is_empty()).then_some(value),
+                            "payment_secret": (!secret.is_empty()).then_some(secret)
+                        }
+                    }))
+                }
+                Editor::Rename => {
+                    if value.is_empty() {
+                        push_toast.emit(("Account name is required".into(), ToastKind::Error));
+                        return;
+                    }
+                    ("rename_account", serde_json::json!({
+                        "input": { "account_id": active_id, "name": value }
+                    }))
+                }
+                Editor::Closed => return,
+            };
+
+            let editor = editor.clone();
+            let busy = busy.clone();
+            let reload = reload.clone();
+            let on_changed = on_changed.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke(cmd, args).await {
+                    Ok(res) => {
+                        if let Ok(account) = serde_wasm_bindgen::from_value::<AccountInfo>(res) {
+                            let msg = if mode == Editor::Create {
+                                format!("Account '{}' created", account.name)
+                            } else {
+                                format!("Account renamed to '{}'", account.name)
+                            };
+                            push_toast.emit((msg, ToastKind::Success));
+                        }
+                        editor.set(Editor::Closed);
+                        if mode == Editor::Rename {
+                            on_changed.emit(());
+                        }
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                reload.emit(());
+                busy.set(false);
+            });
+        })
+    };
+
+    if !props.enabled {
+        return html! {};
+    }
+
+    html! {
+        <div class="account-switcher">
+            <label class="account-label">{"Account"}</label>
+            <select
+                class="account-select"
+                onchange={on_select}
+                onfocus={on_refresh}
+                disabled={*busy}
+            >
+                { for accounts.iter().map(|account| {
+                    let balance = account
+                        .balance
+                        .as_ref()
+                        .map(|b| format!(" · {}", format_amount(b.balance)))
+                        .unwrap_or_default();
+                    html! {
+                        <option value={account.id.clone()} selected={account.active}>
+                            { format!("{}{}{}", account.name, if account.watch_only { " (watch-only)" } else { "" }, balance) }
+                        </option>
+                    }
+                })}
+            </select>
+            <div class="account-actions">
+                <button type="button" class="btn btn-small" onclick={open_editor(Editor::Create)} disabled={*busy}>{"New"}</button>
+                <button type="button" class="btn btn-small" onclick={open_editor(Editor::Rename)} disabled={*busy || active.is_none()}>{"Rename"}</button>
+            </div>
+            if *editor != Editor::Closed {
+                <form class="account-editor" onsubmit={on_submit}>
+                    <input
+                        class="input"
+                        placeholder={if *editor == Editor::Create { "Name (optional)" } else { "New name" }}
+                        value={(*name).clone()}
+                        oninput={on_name}
+                    />
+                    if *editor == Editor::Create && props.payment_secret_required {
+                        <input
+                            class="input"
+                            type="password"
+                            placeholder="Payment Secret"
+                            value={(*payment_secret).clone()}
+                            oninput={on_payment_secret}
+                        />
+                    }
+                    <button type="submit" class="btn btn-small" disabled={*busy}>
+                        { if *editor == Editor::Create { "Create" } else { "Save" } }
+                    </button>
+                </form>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,181 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use crate::models::{DerivedAddress, ToastKind};
+use crate::utils::{format_amount, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct AddressListProps {
+    /// Current receive address; the list reloads whenever it changes.
+    pub receive_address: String,
+    pub push_toast: Callback<(String, ToastKind)>,
+    pub on_new_address: Callback<()>,
+}
+
+fn short_address(address: &str) -> String {
+    if address.len() > 28 {
+        format!("{}…{}", &address[..16], &address[address.len() - 8..])
+    } else {
+        address.to_string()
+    }
+}
+
+async fn fetch_addresses() -> Result<Vec<DerivedAddress>, String> {
+    let res = safe_invoke("list_addresses", JsValue::NULL).await?;
+    serde_wasm_bindgen::from_value::<Vec<DerivedAddress>>(res)
+        .map_err(|_| "Failed to parse addresses".to_string())
+}
+
+#[function_component(AddressList)]
+pub fn address_list(props: &AddressListProps) -> Html {
+    let addresses = use_state(Vec::<DerivedAddress>::new);
+    let loading = use_state(|| false);
+    let show_change = use_state(|| false);
+
+    let reload = {
+        let addresses = addresses.clone();
+        let loading = loading.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_: ()| {
+            let addresses = addresses.clone();
+            let loading = loading.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                loading.set(true);
+                match fetch_addresses().await {
+                    Ok(list) => addresses.set(list),
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.receive_address.clone(), move |_| {
+            reload.emit(());
+            || {}
+        });
+    }
+
+    let on_new_address = {
+        let loading = loading.clone();
+        let push_toast = props.push_toast.clone();
+        let on_new_address = props.on_new_address.clone();
+        Callback::from(move |_| {
+            let loading = loading.clone();
+            let push_toast = push_toast.clone();
+            let on_new_address = on_new_address.clone();
+            spawn_local(async move {
+                loading.set(true);
+                match safe_invoke("new_receive_address", JsValue::NULL).await {
+                    Ok(res) => {
+                        if let Ok(address) = serde_wasm_bindgen::from_value::<DerivedAddress>(res) {
+                            push_toast.emit((format!("New receive address #{}", address.index), ToastKind::Success));
+                        }
+                        on_new_address.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_toggle_change = {
+        let show_change = show_change.clone();
+        Callback::from(move |_| show_change.set(!*show_change))
+    };
+
+    let on_label = {
+        let reload = reload.clone();
+        let push_toast = props.push_toast.clone();
+        move |address: String| {
+            let reload = reload.clone();
+            let push_toast = push_toast.clone();
+            Callback::from(move |e: Event| {
+                let Some(input) = e.target_dyn_into::<HtmlInputElement>() else { return };
+                let label = input.value();
+                let address = address.clone();
+                let reload = reload.clone();
+                let push_toast = push_toast.clone();
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                        "input": { "address": address, "label": label }
+                    })).unwrap_or(JsValue::NULL);
+                    match safe_invoke("set_address_label", args).await {
+                        Ok(_) => push_toast.emit(("Label saved".into(), ToastKind::Info)),
+                        Err(e) => push_toast.emit((e, ToastKind::Error)),
+                    }
+                    reload.emit(());
+                });
+            })
+        }
+    };
+
+    let visible: Vec<&DerivedAddress> = addresses
+        .iter()
+        .filter(|a| *show_change || a.kind == "receive")
+        .rev()
+        .collect();
+
+    html! {
+        <div class="batch-section address-list">
+            <div class="coin-control-toolbar">
+                <h3 class="send-recent-title">{"Addresses"}</h3>
+                <div class="button-group">
+                    <button type="button" class="btn btn-small" onclick={on_toggle_change}>
+                        { if *show_change { "Hide Change" } else { "Show Change" } }
+                    </button>
+                    <button type="button" class="btn btn-small btn-prominent" onclick={on_new_address} disabled={*loading}>
+                        {"New Address"}
+                    </button>
+                </div>
+            </div>
+            { if visible.is_empty() && !*loading {
+                html! { <p class="batch-hint">{"No addresses derived yet"}</p> }
+            } else {
+                html! {
+                    <table class="batch-table">
+                        <thead>
+                            <tr>
+                                <th>{"#"}</th>
+                                <th>{"Address"}</th>
+                                <th>{"Balance"}</th>
+                                <th>{"Status"}</th>
+                                <th>{"Label"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for visible.into_iter().map(|a| html! {
+                                <tr key={a.address.clone()} class={classes!("address-row", if a.current { "current" } else { "" })}>
+                                    <td>{ format!("{}{}", if a.kind == "change" { "c" } else { "" }, a.index) }</td>
+                                    <td class="batch-address" title={a.address.clone()}>{ short_address(&a.address) }</td>
+                                    <td>{ format_amount(a.balance) }</td>
+                                    <td>
+                                        if a.current {
+                                            <span class="tx-badge confirmed">{"current"}</span>
+                                        }
+                                        <span class={classes!("tx-badge", if a.used { "used" } else { "unused" })}>
+                                            { if a.used { "used" } else { "unused" } }
+                                        </span>
+                                    </td>
+                                    <td>
+                                        <input
+                                            class="input address-label"
+                                            placeholder="Add label"
+                                            value={a.label.clone().unwrap_or_default()}
+                                            onchange={on_label(a.address.clone())}
+                                        />
+                                    </td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,137 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use crate::models::{LockSettings, ToastKind};
+use crate::utils::safe_invoke;
+
+/// Idle timeouts offered in the selector, in minutes; 0 turns the timer off.
+const IDLE_OPTIONS: [(u32, &str); 6] = [
+    (1, "Lock after 1 minute"),
+    (5, "Lock after 5 minutes"),
+    (15, "Lock after 15 minutes"),
+    (30, "Lock after 30 minutes"),
+    (60, "Lock after 1 hour"),
+    (0, "Never lock when idle"),
+];
+
+#[derive(Properties, PartialEq)]
+pub struct AutoLockProps {
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(AutoLock)]
+pub fn auto_lock(props: &AutoLockProps) -> Html {
+    let lock_settings = use_state(LockSettings::default);
+    let saving = use_state(|| false);
+
+    {
+        let lock_settings = lock_settings.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(res) = safe_invoke("get_lock_settings", JsValue::NULL).await {
+                    if let Ok(loaded) = serde_wasm_bindgen::from_value::<LockSettings>(res) {
+                        lock_settings.set(loaded);
+                    }
+                }
+            });
+            || {}
+        });
+    }
+
+    // Every change is saved straight away, so the lock follows the form.
+    let save = {
+        let lock_settings = lock_settings.clone();
+        let saving = saving.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |updated: LockSettings| {
+            let previous = (*lock_settings).clone();
+            lock_settings.set(updated.clone());
+            let lock_settings = lock_settings.clone();
+            let saving = saving.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                saving.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "lockSettings": updated }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("save_lock_settings", args).await {
+                    Ok(res) => {
+                        if let Ok(saved) = serde_wasm_bindgen::from_value::<LockSettings>(res) {
+                            lock_settings.set(saved);
+                        }
+                    }
+                    Err(e) => {
+                        lock_settings.set(previous);
+                        push_toast.emit((e, ToastKind::Error));
+                    }
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let on_idle = {
+        let lock_settings = lock_settings.clone();
+        let save = save.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                if let Ok(minutes) = select.value().parse::<u32>() {
+                    save.emit(LockSettings { idle_minutes: minutes, ..(*lock_settings).clone() });
+                }
+            }
+        })
+    };
+
+    let on_blur = {
+        let lock_settings = lock_settings.clone();
+        let save = save.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                save.emit(LockSettings { lock_on_blur: i.checked(), ..(*lock_settings).clone() });
+            }
+        })
+    };
+
+    let on_suspend = {
+        let lock_settings = lock_settings.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                save.emit(LockSettings { lock_on_suspend: i.checked(), ..(*lock_settings).clone() });
+            }
+        })
+    };
+
+    let idle_minutes = lock_settings.idle_minutes;
+    let custom = !IDLE_OPTIONS.iter().any(|(minutes, _)| *minutes == idle_minutes);
+
+    html! {
+        <div class="settings-section">
+            <h3 class="section-title">{"Auto-Lock"}</h3>
+            <p class="settings-hint">
+                {"A locked wallet forgets its password and recovery phrase until you unlock it again. Balances stay hidden until then."}
+            </p>
+            <div class="settings-row">
+                <select class="input" onchange={on_idle} disabled={*saving}>
+                    if custom {
+                        <option value={idle_minutes.to_string()} selected=true>
+                            { format!("Lock after {} minutes", idle_minutes) }
+                        </option>
+                    }
+                    { for IDLE_OPTIONS.iter().map(|(minutes, label)| html! {
+                        <option value={minutes.to_string()} selected={*minutes == idle_minutes}>
+                            { *label }
+                        </option>
+                    })}
+                </select>
+            </div>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={lock_settings.lock_on_blur} oninput={on_blur} disabled={*saving} />
+                {"Lock when the window loses focus"}
+            </label>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={lock_settings.lock_on_suspend} oninput={on_suspend} disabled={*saving} />
+                {"Lock when the computer goes to sleep"}
+            </label>
+        </div>
+    }
+}
@@ -0,0 +1,233 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use crate::models::{ToastKind, UtxoInfo};
+use crate::utils::{format_amount, format_with_commas, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct CoinControlProps {
+    pub selected: Vec<String>,
+    pub on_select: Callback<Vec<String>>,
+    pub enabled: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+fn short_outpoint(outpoint: &str) -> String {
+    match outpoint.split_once(':') {
+        Some((txid, index)) if txid.len() > 16 => {
+            format!("{}…{}:{}", &txid[..8], &txid[txid.len() - 6..], index)
+        }
+        _ => outpoint.to_string(),
+    }
+}
+
+async fn fetch_utxos() -> Result<Vec<UtxoInfo>, String> {
+    let res = safe_invoke("list_utxos", JsValue::NULL).await?;
+    serde_wasm_bindgen::from_value::<Vec<UtxoInfo>>(res)
+        .map_err(|_| "Failed to parse UTXOs".to_string())
+}
+
+#[function_component(CoinControl)]
+pub fn coin_control(props: &CoinControlProps) -> Html {
+    let expanded = use_state(|| false);
+    let utxos = use_state(Vec::<UtxoInfo>::new);
+    let loading = use_state(|| false);
+
+    let reload = {
+        let utxos = utxos.clone();
+        let loading = loading.clone();
+        let selected = props.selected.clone();
+        let on_select = props.on_select.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_: ()| {
+            let utxos = utxos.clone();
+            let loading = loading.clone();
+            let selected = selected.clone();
+            let on_select = on_select.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                loading.set(true);
+                match fetch_utxos().await {
+                    Ok(list) => {
+                        // Drop selections that were spent or frozen meanwhile.
+                        let kept: Vec<String> = selected
+                            .iter()
+                            .filter(|o| list.iter().any(|u| &u.outpoint == *o && u.is_spendable()))
+                            .cloned()
+                            .collect();
+                        if kept.len() != selected.len() {
+                            on_select.emit(kept);
+                        }
+                        utxos.set(list);
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let on_toggle_expanded = {
+        let expanded = expanded.clone();
+        let reload = reload.clone();
+        Callback::from(move |_| {
+            let open = !*expanded;
+            expanded.set(open);
+            if open {
+                reload.emit(());
+            }
+        })
+    };
+
+    let on_refresh = {
+        let reload = reload.clone();
+        Callback::from(move |_| reload.emit(()))
+    };
+
+    let on_clear = {
+        let on_select = props.on_select.clone();
+        Callback::from(move |_| on_select.emit(Vec::new()))
+    };
+
+    let on_check = {
+        let selected = props.selected.clone();
+        let on_select = props.on_select.clone();
+        move |outpoint: String| {
+            let selected = selected.clone();
+            let on_select = on_select.clone();
+            Callback::from(move |e: Event| {
+                let checked = e
+                    .target_dyn_into::<HtmlInputElement>()
+                    .map(|i| i.checked())
+                    .unwrap_or(false);
+                let mut next: Vec<String> = selected.iter().filter(|o| **o != outpoint).cloned().collect();
+                if checked {
+                    next.push(outpoint.clone());
+                }
+                on_select.emit(next);
+            })
+        }
+    };
+
+    let on_freeze = {
+        let reload = reload.clone();
+        let push_toast = props.push_toast.clone();
+        move |outpoint: String, frozen: bool| {
+            let reload = reload.clone();
+            let push_toast = push_toast.clone();
+            Callback::from(move |_| {
+                let outpoint = outpoint.clone();
+                let reload = reload.clone();
+                let push_toast = push_toast.clone();
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                        "input": { "outpoints": [outpoint], "frozen": frozen }
+                    })).unwrap_or(JsValue::NULL);
+                    match safe_invoke("set_utxos_frozen", args).await {
+                        Ok(_) => {
+                            let msg = if frozen { "UTXO frozen" } else { "UTXO unfrozen" };
+                            push_toast.emit((msg.into(), ToastKind::Info));
+                            reload.emit(());
+                        }
+                        Err(e) => push_toast.emit((e, ToastKind::Error)),
+                    }
+                });
+            })
+        }
+    };
+
+    let selected_total: u64 = utxos
+        .iter()
+        .filter(|u| props.selected.contains(&u.outpoint))
+        .map(|u| u.amount)
+        .sum();
+
+    html! {
+        <div class="coin-control">
+            <div class="coin-control-header">
+                <button type="button" class="btn btn-small" onclick={on_toggle_expanded} disabled={!props.enabled}>
+                    { if *expanded { "Hide Coin Control" } else { "Coin Control" } }
+                </button>
+                if !props.selected.is_empty() {
+                    <span class="coin-control-summary">
+                        { format!("{} selected · {}", props.selected.len(), format_amount(selected_total)) }
+                    </span>
+                    <button type="button" class="btn btn-small" onclick={on_clear}>{"Use All"}</button>
+                }
+            </div>
+
+            if *expanded {
+                <div class="coin-control-body">
+                    <div class="coin-control-toolbar">
+                        <span class="coin-control-summary">
+                            { if props.selected.is_empty() {
+                                "No coins selected: every unfrozen UTXO may be spent".to_string()
+                            } else {
+                                "Only the selected UTXOs will be spent".to_string()
+                            }}
+                        </span>
+                        <button type="button" class="btn btn-small" onclick={on_refresh} disabled={*loading}>
+                            { if *loading { "Loading…" } else { "Refresh" } }
+                        </button>
+                    </div>
+                    { if utxos.is_empty() && !*loading {
+                        html! { <p class="batch-hint">{"No UTXOs found"}</p> }
+                    } else {
+                        html! {
+                            <table class="batch-table utxo-table">
+                                <thead>
+                                    <tr>
+                                        <th></th>
+                                        <th>{"Outpoint"}</th>
+                                        <th>{"Amount"}</th>
+                                        <th>{"DAA Score"}</th>
+                                        <th>{"Status"}</th>
+                                        <th></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for utxos.iter().map(|utxo| {
+                                        let checked = props.selected.contains(&utxo.outpoint);
+                                        html! {
+                                            <tr class={classes!("utxo-row", if utxo.frozen { "frozen" } else { "" })}>
+                                                <td>
+                                                    <input
+                                                        type="checkbox"
+                                                        checked={checked}
+                                                        disabled={!utxo.is_spendable()}
+                                                        onchange={on_check(utxo.outpoint.clone())}
+                                                    />
+                                                </td>
+                                                <td class="batch-address" title={format!("{}\n{}", utxo.outpoint, utxo.address.clone().unwrap_or_default())}>
+                                                    { short_outpoint(&utxo.outpoint) }
+                                                </td>
+                                                <td>{ format_amount(utxo.amount) }</td>
+                                                <td>{ format_with_commas(utxo.block_daa_score) }</td>
+                                                <td>
+                                                    <span class={classes!("tx-badge", utxo.maturity.clone())}>{ &utxo.maturity }</span>
+                                                    if utxo.is_coinbase {
+                                                        <span class="tx-badge">{"coinbase"}</span>
+                                                    }
+                                                </td>
+                                                <td>
+                                                    <button
+                                                        type="button"
+                                                        class="btn btn-small"
+                                                        onclick={on_freeze(utxo.outpoint.clone(), !utxo.frozen)}
+                                                    >
+                                                        { if utxo.frozen { "Unfreeze" } else { "Freeze" } }
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+                        }
+                    }}
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use log::error;
+use crate::models::{CompoundPreview, CompoundProgress, CompoundResult, ToastKind};
+use crate::utils::{format_amount, format_with_commas, listen, safe_invoke, ve_to_veni};
+
+#[derive(Properties, PartialEq)]
+pub struct CompoundProps {
+    pub enabled: bool,
+    pub fee_priority: String,
+    pub payment_secret: Option<String>,
+    pub payment_secret_required: bool,
+    pub selected_utxos: Vec<String>,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(Compound)]
+pub fn compound(props: &CompoundProps) -> Html {
+    let threshold = use_state(String::new);
+    let preview = use_state(|| Option::<CompoundPreview>::None);
+    let progress = use_state(|| Option::<CompoundProgress>::None);
+    let running = use_state(|| false);
+    let previewing = use_state(|| false);
+
+    {
+        let progress = progress.clone();
+        use_effect_with((), move |_| {
+            let unlisten: Rc<RefCell<Option<js_sys::Function>>> = Rc::new(RefCell::new(None));
+            let slot = unlisten.clone();
+            spawn_local(async move {
+                let on_progress = move |payload: JsValue| {
+                    if let Ok(p) = serde_wasm_bindgen::from_value::<CompoundProgress>(payload) {
+                        progress.set(Some(p));
+                    }
+                };
+                match listen("compound://progress", on_progress).await {
+                    Ok(f) => *slot.borrow_mut() = Some(f),
+                    Err(e) => error!("Failed to listen for compound://progress: {}", e),
+                }
+            });
+            move || {
+                if let Some(f) = unlisten.borrow_mut().take() {
+                    let _ = f.call0(&JsValue::NULL);
+                }
+            }
+        });
+    }
+
+    let input_json = {
+        let threshold = (*threshold).clone();
+        let fee_priority = props.fee_priority.clone();
+        let payment_secret = props.payment_secret.clone();
+        let utxos = (!props.selected_utxos.is_empty()).then(|| props.selected_utxos.clone());
+        move || {
+            serde_json::json!({
+                "input": {
+                    "fee_priority": fee_priority,
+                    "payment_secret": payment_secret,
+                    "max_utxo_amount": ve_to_veni(&threshold),
+                    "utxos": utxos
+                }
+            })
+        }
+    };
+
+    let on_threshold = {
+        let threshold = threshold.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                threshold.set(i.value());
+                preview.set(None);
+            }
+        })
+    };
+
+    let on_preview = {
+        let preview = preview.clone();
+        let previewing = previewing.clone();
+        let push_toast = props.push_toast.clone();
+        let input_json = input_json.clone();
+        Callback::from(move |_| {
+            let preview = preview.clone();
+            let previewing = previewing.clone();
+            let push_toast = push_toast.clone();
+            let args = serde_wasm_bindgen::to_value(&input_json()).unwrap_or(JsValue::NULL);
+            spawn_local(async move {
+                previewing.set(true);
+                match safe_invoke("preview_compound", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<CompoundPreview>(res) {
+                        Ok(p) => preview.set(Some(p)),
+                        Err(_) => push_toast.emit(("Failed to parse compound preview".into(), ToastKind::Error)),
+                    },
+                    Err(e) => {
+                        preview.set(None);
+                        push_toast.emit((e, ToastKind::Error));
+                    }
+                }
+                previewing.set(false);
+            });
+        })
+    };
+
+    let on_compound = {
+        let running = running.clone();
+        let progress = progress.clone();
+        let preview = preview.clone();
+        let push_toast = props.push_toast.clone();
+        let payment_secret_missing = props.payment_secret_required && props.payment_secret.is_none();
+        Callback::from(move |_| {
+            if payment_secret_missing {
+                push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
+                return;
+            }
+            let running = running.clone();
+            let progress = progress.clone();
+            let preview = preview.clone();
+            let push_toast = push_toast.clone();
+            let args = serde_wasm_bindgen::to_value(&input_json()).unwrap_or(JsValue::NULL);
+            spawn_local(async move {
+                running.set(true);
+                progress.set(None);
+                match safe_invoke("compound_utxos", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<CompoundResult>(res) {
+                        Ok(r) if r.aborted => push_toast.emit((
+                            format!("Compounding cancelled after {} transaction(s)", r.txids.len()),
+                            ToastKind::Warning,
+                        )),
+                        Ok(r) => push_toast.emit((
+                            format!("Compounded in {} transaction(s), fees {}", r.txids.len(), format_amount(r.total_fees)),
+                            ToastKind::Success,
+                        )),
+                        Err(_) => push_toast.emit(("Failed to parse compound result".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                preview.set(None);
+                running.set(false);
+            });
+        })
+    };
+
+    let on_cancel = {
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_| {
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                if let Err(e) = safe_invoke("cancel_compound", JsValue::NULL).await {
+                    push_toast.emit((e, ToastKind::Error));
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="batch-section compound-section">
+            <h3 class="send-recent-title">{"Consolidate UTXOs"}</h3>
+            <div class="compound-controls">
+                <input
+                    type="text"
+                    inputmode="decimal"
+                    class="input"
+                    placeholder="Only UTXOs below (VE, optional)"
+                    value={(*threshold).clone()}
+                    oninput={on_threshold}
+                    disabled={!props.enabled || *running}
+                />
+                <button
+                    type="button"
+                    class="btn btn-small"
+                    onclick={on_preview}
+                    disabled={!props.enabled || *running || *previewing}
+                >
+                    { if *previewing { "Estimating…" } else { "Preview" } }
+                </button>
+            </div>
+
+            { if let Some(p) = (*preview).as_ref() {
+                html! {
+                    <div class="send-estimate">
+                        <span>{ format!("{} UTXOs · {}", format_with_commas(p.utxos as u64), format_amount(p.total_amount)) }</span>
+                        <span>{ format!("{} transaction(s) · fees {} · mass {}", p.transactions, format_amount(p.total_fees), p.mass) }</span>
+                        <span>{"Result: "}<strong>{ format_amount(p.final_amount) }</strong></span>
+                        <div class="button-group">
+                            <button
+                                type="button"
+                                class={classes!("btn", "btn-prominent", if *running { "loading" } else { "" })}
+                                onclick={on_compound}
+                                disabled={!props.enabled || *running}
+                            >
+                                { if *running { "Compounding…" } else { "Compound" } }
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else { html!{} }}
+
+            if *running {
+                <div class="send-estimate">
+                    { match (*progress).as_ref() {
+                        Some(p) => html! {
+                            <span>{ format!("Submitted {} of ~{}: {}…", p.index, p.estimated_transactions.max(p.index), &p.txid[..p.txid.len().min(12)]) }</span>
+                        },
+                        None => html! { <span>{"Building transactions…"}</span> },
+                    }}
+                    <button type="button" class="btn btn-small" onclick={on_cancel}>{"Cancel"}</button>
+                </div>
+            }
+        </div>
+    }
+}
@@ -1,10 +1,12 @@
-use crate::models::ToastKind;
+use crate::models::{ToastKind, MNEMONIC_LANGUAGES};
 use yew::prelude::*;
+use web_sys::HtmlSelectElement;
 use crate::utils::{is_valid_password, is_valid_filename};
 
 #[derive(Properties, PartialEq)]
 pub struct CreateWalletProps {
-    pub on_submit: Callback<(String, String, Option<String>)>,
+    /// `(filename, password, payment secret, word count, wordlist code)`.
+    pub on_submit: Callback<(String, String, Option<String>, usize, String)>,
     pub is_loading: bool,
     pub on_import: Callback<MouseEvent>,
     pub push_toast: Callback<(String, ToastKind)>,
@@ -17,6 +19,8 @@ pub fn create_wallet(props: &CreateWalletProps) -> Html {
     let payment_secret_words   = use_state(|| vec![String::new(); 1]);
     let show_payment_secret    = use_state(|| false);
     let has_extended_payment   = use_state(|| false);
+    let word_count             = use_state(|| 24usize);
+    let language               = use_state(|| "english".to_string());
 
     let filename_error         = use_state(String::new);
     let password_error         = use_state(String::new);
@@ -54,6 +58,26 @@ pub fn create_wallet(props: &CreateWalletProps) -> Html {
         })
     };
 
+    let on_word_count = {
+        let wc = word_count.clone();
+        Callback::from(move |ev: Event| {
+            if let Some(s) = ev.target_dyn_into::<HtmlSelectElement>() {
+                if let Ok(count) = s.value().parse::<usize>() {
+                    wc.set(count);
+                }
+            }
+        })
+    };
+
+    let on_language = {
+        let lang = language.clone();
+        Callback::from(move |ev: Event| {
+            if let Some(s) = ev.target_dyn_into::<HtmlSelectElement>() {
+                lang.set(s.value());
+            }
+        })
+    };
+
     let on_payment_word_change = {
         let words = payment_secret_words.clone();
         let err   = payment_secret_error.clone();
@@ -108,6 +132,8 @@ pub fn create_wallet(props: &CreateWalletProps) -> Html {
         let pwd   = password.clone();
         let words = payment_secret_words.clone();
         let show  = *show_payment_secret;
+        let count = *word_count;
+        let lang  = language.clone();
 
         let e_fn  = filename_error.clone();
         let e_pw  = password_error.clone();
@@ -163,12 +189,14 @@ pub fn create_wallet(props: &CreateWalletProps) -> Html {
             if err { return; }
 
             web_sys::console::log_1(&format!(
-                "FRONTEND: CreateWallet → filename='{}', payment_secret={}",
+                "FRONTEND: CreateWallet → filename='{}', payment_secret={}, words={}, language={}",
                 name,
-                if pay_opt.is_some() { "provided" } else { "none" }
+                if pay_opt.is_some() { "provided" } else { "none" },
+                count,
+                *lang
             ).into());
 
-            cb.emit((name.to_string(), pw.to_string(), pay_opt));
+            cb.emit((name.to_string(), pw.to_string(), pay_opt, count, (*lang).clone()));
         })
     };
 
@@ -207,6 +235,27 @@ pub fn create_wallet(props: &CreateWalletProps) -> Html {
                         </div>
                     </div>
 
+                    <div class="row centered-row">
+                        <div class="input-wrapper">
+                            <select class="input" onchange={on_word_count} disabled={props.is_loading}>
+                                <option value="24" selected={*word_count == 24}>{"24-word recovery phrase"}</option>
+                                <option value="12" selected={*word_count == 12}>{"12-word recovery phrase"}</option>
+                            </select>
+                        </div>
+                        <div class="input-wrapper">
+                            <select class="input" onchange={on_language} disabled={props.is_loading}>
+                                { for MNEMONIC_LANGUAGES.iter().map(|(code, label)| html! {
+                                    <option value={*code} selected={*language == *code}>{ *label }</option>
+                                })}
+                            </select>
+                        </div>
+                    </div>
+                    if *language != "english" {
+                        <p class="mnemonic-language-warning" role="alert">
+                            {"Only English phrases can be stored as words. This wallet keeps just the seed of your phrase, so write the words down and confirm them right after creating it. They cannot be shown or checked once the wallet is closed."}
+                        </p>
+                    }
+
                     <div class="row centered-row">
                         <div class="mnemonic-toggle">
                             <label class="checkbox-label tooltip-wrapper">
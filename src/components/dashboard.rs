@@ -3,7 +3,8 @@ use yew::platform::spawn_local;
 use gloo_net::http::Request;
 use gloo_timers::callback::Interval;
 use serde::Deserialize;
-use crate::utils::{format_with_commas, format_hashrate, format_difficulty};
+use crate::models::BalanceResponse;
+use crate::utils::{format_amount, format_with_commas, format_hashrate, format_difficulty};
 
 #[derive(Clone, PartialEq, Debug, Deserialize)]
 struct NetworkInfo {
@@ -48,6 +49,13 @@ pub struct DashboardProps {
     pub balance: String,
     pub is_loading: bool,
     pub last_refreshed: String,
+    #[prop_or_default]
+    pub breakdown: Option<BalanceResponse>,
+    /// The recovery phrase of this new wallet has not been confirmed yet.
+    #[prop_or(false)]
+    pub backup_pending: bool,
+    #[prop_or_default]
+    pub on_verify_backup: Callback<MouseEvent>,
 }
 
 #[function_component(Dashboard)]
@@ -152,6 +160,12 @@ pub fn dashboard(props: &DashboardProps) -> Html {
 
     html! {
         <div class="screen-container" role="main" aria-label="Vecno Wallet Dashboard">
+            if props.backup_pending {
+                <div class="backup-warning" role="alert">
+                    <span>{"Your recovery phrase has not been confirmed. Without it, this wallet cannot be restored."}</span>
+                    <button class="btn btn-small" onclick={props.on_verify_backup.clone()}>{"Verify Now"}</button>
+                </div>
+            }
             <div class="balance-container" aria-live="assertive">
                 <h2>{"Wallet Balance"}</h2>
                 <p class={classes!("balance", if props.is_loading { "loading" } else { "" })}>
@@ -170,6 +184,30 @@ pub fn dashboard(props: &DashboardProps) -> Html {
                 <p class="last-updated" aria-live="polite">
                     { &props.last_refreshed }
                 </p>
+                if let Some(b) = props.breakdown.as_ref() {
+                    <div class="balance-breakdown">
+                        <div class="balance-part">
+                            <span class="stat-label">{"Spendable"}</span>
+                            <span class="stat-value">{ format_amount(b.mature) }</span>
+                            <span class="balance-utxos">{ format!("{} UTXOs", b.mature_utxo_count) }</span>
+                        </div>
+                        <div class="balance-part">
+                            <span class="stat-label">{"Pending"}</span>
+                            <span class="stat-value">{ format_amount(b.pending) }</span>
+                            <span class="balance-utxos">{ format!("{} UTXOs", b.pending_utxo_count) }</span>
+                        </div>
+                        <div class="balance-part">
+                            <span class="stat-label">{"Outgoing"}</span>
+                            <span class="stat-value">{ format_amount(b.outgoing) }</span>
+                            <span class="balance-utxos">{"in-flight sends"}</span>
+                        </div>
+                    </div>
+                    if b.stasis_utxo_count > 0 {
+                        <p class="balance-utxos">
+                            { format!("{} coinbase UTXO(s) in stasis", b.stasis_utxo_count) }
+                        </p>
+                    }
+                }
             </div>
 
             <div class="network-stats-section">
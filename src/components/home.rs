@@ -1,5 +1,6 @@
 use yew::prelude::*;
 use crate::models::WalletFile;
+use crate::utils::{is_mainnet, network_label};
 
 #[derive(Properties, PartialEq)]
 pub struct HomeProps {
@@ -57,7 +58,13 @@ pub fn home(props: &HomeProps) -> Html {
                                 <select id="wallet-select" class="input" onchange={on_wallet_change}>
                                     <option value="" selected=true disabled=true>{"Select a wallet"}</option>
                                     { for props.available_wallets.iter().map(|w| html! {
-                                        <option value={w.path.clone()}>{ &w.name }</option>
+                                        <option value={w.path.clone()}>
+                                            { if is_mainnet(&w.network) {
+                                                w.name.clone()
+                                            } else {
+                                                format!("{} ({})", w.name, network_label(&w.network))
+                                            }}
+                                        </option>
                                     })}
                                 </select>
                                 <input
@@ -0,0 +1,225 @@
+use crate::models::{PrivateKeyRequest, ToastKind};
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use crate::utils::{is_valid_password, is_valid_filename, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct ImportPrivateKeyProps {
+    pub on_submit: Callback<PrivateKeyRequest>,
+    pub is_loading: bool,
+    pub on_import: Callback<MouseEvent>,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(ImportPrivateKey)]
+pub fn import_private_key(props: &ImportPrivateKeyProps) -> Html {
+    let filename = use_state(String::new);
+    let password = use_state(String::new);
+    let payment_secret = use_state(String::new);
+    let private_key = use_state(String::new);
+    let preview = use_state(|| None::<String>);
+    let previewing = use_state(|| false);
+
+    let on_filename = {
+        let filename = filename.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                filename.set(input.value());
+            }
+        })
+    };
+
+    let on_password = {
+        let password = password.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                password.set(input.value());
+            }
+        })
+    };
+
+    let on_payment_secret = {
+        let payment_secret = payment_secret.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                payment_secret.set(input.value());
+            }
+        })
+    };
+
+    let on_private_key = {
+        let private_key = private_key.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                private_key.set(input.value());
+                preview.set(None);
+            }
+        })
+    };
+
+    let on_preview = {
+        let private_key = private_key.clone();
+        let preview = preview.clone();
+        let previewing = previewing.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_: MouseEvent| {
+            let key = (*private_key).trim().to_string();
+            if key.is_empty() {
+                push_toast.emit(("Private key is required".into(), ToastKind::Error));
+                return;
+            }
+            let preview = preview.clone();
+            let previewing = previewing.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                previewing.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "privateKey": key }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("preview_private_key", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<String>(res) {
+                        Ok(address) => preview.set(Some(address)),
+                        Err(_) => push_toast.emit(("Failed to parse address".into(), ToastKind::Error)),
+                    },
+                    Err(e) => {
+                        preview.set(None);
+                        push_toast.emit((e, ToastKind::Error));
+                    }
+                }
+                previewing.set(false);
+            });
+        })
+    };
+
+    let onsubmit = {
+        let filename = filename.clone();
+        let password = password.clone();
+        let payment_secret = payment_secret.clone();
+        let private_key = private_key.clone();
+        let preview = preview.clone();
+        let cb = props.on_submit.clone();
+        let push_toast = props.push_toast.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let mut has_error = false;
+
+            if (*filename).is_empty() {
+                push_toast.emit(("Filename is required".into(), ToastKind::Error));
+                has_error = true;
+            } else if !is_valid_filename(&filename) {
+                push_toast.emit(("Filename contains invalid characters or is too long".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if (*password).is_empty() {
+                push_toast.emit(("Password is required".into(), ToastKind::Error));
+                has_error = true;
+            } else if !is_valid_password(&password) {
+                push_toast.emit(("Password must be at least 8 characters".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if preview.is_none() {
+                push_toast.emit(("Preview the address before importing".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if has_error {
+                return;
+            }
+
+            let secret = (*payment_secret).trim().to_string();
+            cb.emit(PrivateKeyRequest {
+                private_key: (*private_key).trim().to_string(),
+                secret: (*password).clone(),
+                payment_secret: (!secret.is_empty()).then_some(secret),
+                filename: (*filename).clone(),
+            });
+        })
+    };
+
+    html! {
+        <div class="screen-container import-centered">
+            <div class="import-inner centered-inner">
+                <h2 class="import-title">{"Import Private Key"}</h2>
+                <p class="home-hint">
+                    {"Create a wallet from a raw private key in hex, as exported by mining software and older tools. The key controls a single address."}
+                </p>
+                <form class="import-form" {onsubmit}>
+                    <div class="row centered-row">
+                        <div class="input-wrapper">
+                            <input
+                                type="text"
+                                placeholder="Wallet filename"
+                                class="input"
+                                oninput={on_filename}
+                                disabled={props.is_loading}
+                            />
+                        </div>
+                        <div class="input-wrapper">
+                            <input
+                                type="password"
+                                placeholder="New password"
+                                class="input"
+                                oninput={on_password}
+                                disabled={props.is_loading}
+                            />
+                        </div>
+                    </div>
+
+                    <input
+                        type="password"
+                        class="input private-key-input"
+                        placeholder="Private key (64 hex characters)"
+                        autocomplete="off"
+                        value={(*private_key).clone()}
+                        oninput={on_private_key}
+                        disabled={props.is_loading}
+                    />
+                    <input
+                        type="password"
+                        class="input private-key-input"
+                        placeholder="Payment secret (optional)"
+                        value={(*payment_secret).clone()}
+                        oninput={on_payment_secret}
+                        disabled={props.is_loading}
+                    />
+
+                    if let Some(address) = (*preview).clone() {
+                        <div class="private-key-preview">
+                            <span class="private-key-preview-label">{"Address"}</span>
+                            <span class="private-key-preview-address">{address}</span>
+                        </div>
+                    }
+
+                    <div class="button-group">
+                        <button
+                            type="button"
+                            class="btn"
+                            onclick={on_preview}
+                            disabled={props.is_loading || *previewing}
+                        >
+                            { if *previewing { "Checking..." } else { "Preview Address" } }
+                        </button>
+                        <button
+                            type="submit"
+                            disabled={props.is_loading || preview.is_none()}
+                            class={classes!("btn", "btn-prominent", if props.is_loading { "loading" } else { "" })}
+                        >
+                            { if props.is_loading { "Importing..." } else { "Import Private Key" } }
+                        </button>
+                    </div>
+                </form>
+
+                <p class="import-create-link">
+                    {"Have a recovery phrase? "}
+                    <a href="#" onclick={props.on_import.clone()}>{"Import Wallet"}</a>
+                </p>
+            </div>
+        </div>
+    }
+}
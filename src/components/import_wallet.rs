@@ -1,16 +1,19 @@
-use crate::models::ToastKind;
+use crate::models::{mnemonic_language_label, MnemonicLanguage, ToastKind};
 use yew::prelude::*;
 use web_sys::{ClipboardEvent, HtmlInputElement};
 use gloo::events::{EventListener, EventListenerOptions};
 use gloo::utils::document;
-use wasm_bindgen::JsCast;
-use crate::utils::{is_valid_password, is_valid_filename};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::spawn_local;
+use crate::utils::{is_valid_password, is_valid_filename, safe_invoke};
 
 #[derive(Properties, PartialEq)]
 pub struct ImportWalletProps {
     pub on_submit: Callback<(String, String, Option<String>, String)>,
     pub is_loading: bool,
     pub on_create: Callback<MouseEvent>,
+    pub on_watch_only: Callback<MouseEvent>,
+    pub on_private_key: Callback<MouseEvent>,
     pub push_toast: Callback<(String, ToastKind)>,
 }
 
@@ -29,6 +32,8 @@ pub fn import_wallet(props: &ImportWalletProps) -> Html {
 
     let has_extended_mnemonic = use_state(|| false);
     let has_extended_payment = use_state(|| false);
+    // Wordlist the backend recognised once every slot is filled.
+    let detected = use_state(|| Option::<Result<MnemonicLanguage, String>>::None);
 
     {
         let mnemonic_words = mnemonic_words.clone();
@@ -40,6 +45,33 @@ pub fn import_wallet(props: &ImportWalletProps) -> Html {
         });
     }
 
+    {
+        let detected = detected.clone();
+        let deps = ((*mnemonic_words).clone(), *is_12_word);
+        use_effect_with(deps, move |(words, is_12)| {
+            let expected = if *is_12 { 12 } else { 24 };
+            let filled: Vec<&String> = words.iter().take(expected).filter(|w| !w.is_empty()).collect();
+            if filled.len() == expected {
+                let phrase = filled.iter().map(|w| w.as_str()).collect::<Vec<_>>().join(" ");
+                spawn_local(async move {
+                    let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                        "input": { "mnemonic": phrase }
+                    }))
+                    .unwrap_or(JsValue::NULL);
+                    let result = match safe_invoke("detect_mnemonic_language", args).await {
+                        Ok(res) => serde_wasm_bindgen::from_value::<MnemonicLanguage>(res)
+                            .map_err(|_| "Could not read the detected wordlist".to_string()),
+                        Err(e) => Err(e),
+                    };
+                    detected.set(Some(result));
+                });
+            } else {
+                detected.set(None);
+            }
+            || ()
+        });
+    }
+
     {
         let payment_secret_words = payment_secret_words.clone();
         let has_extended_payment = has_extended_payment.clone();
@@ -403,6 +435,24 @@ pub fn import_wallet(props: &ImportWalletProps) -> Html {
                         if !(*mnemonic_error).is_empty() {
                             <p class="status error centered-error">{ (*mnemonic_error).clone() }</p>
                         }
+                        { match (*detected).as_ref() {
+                            Some(Ok(found)) => html! {
+                                <>
+                                    <p class="mnemonic-language-note">
+                                        { format!("Detected a {}-word {} phrase", found.word_count, mnemonic_language_label(&found.language)) }
+                                    </p>
+                                    if found.language != "english" {
+                                        <p class="mnemonic-language-warning" role="alert">
+                                            {"Only English phrases can be stored as words. This wallet keeps just the seed of your phrase, so it can never show these words again. Make sure your written copy is correct before importing."}
+                                        </p>
+                                    }
+                                </>
+                            },
+                            Some(Err(e)) => html! {
+                                <p class="status error centered-error">{ e.clone() }</p>
+                            },
+                            None => html! {},
+                        }}
                     </div>
 
                     <div class={classes!(
@@ -463,6 +513,14 @@ pub fn import_wallet(props: &ImportWalletProps) -> Html {
                     {"No phrase? "}
                     <a href="#" onclick={props.on_create.clone()}>{"Create New Wallet"}</a>
                 </p>
+                <p class="import-create-link">
+                    {"Only have an xpub? "}
+                    <a href="#" onclick={props.on_watch_only.clone()}>{"Watch-only Wallet"}</a>
+                </p>
+                <p class="import-create-link">
+                    {"Have a raw private key? "}
+                    <a href="#" onclick={props.on_private_key.clone()}>{"Import Private Key"}</a>
+                </p>
             </div>
         </div>
     }
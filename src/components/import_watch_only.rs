@@ -0,0 +1,175 @@
+use crate::models::{ToastKind, WatchOnlyRequest};
+use yew::prelude::*;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use crate::utils::{is_valid_password, is_valid_filename};
+
+#[derive(Properties, PartialEq)]
+pub struct ImportWatchOnlyProps {
+    pub on_submit: Callback<WatchOnlyRequest>,
+    pub is_loading: bool,
+    pub on_import: Callback<MouseEvent>,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(ImportWatchOnly)]
+pub fn import_watch_only(props: &ImportWatchOnlyProps) -> Html {
+    let filename = use_state(String::new);
+    let password = use_state(String::new);
+    let xpub = use_state(String::new);
+    let addresses = use_state(String::new);
+
+    let on_filename = {
+        let filename = filename.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                filename.set(input.value());
+            }
+        })
+    };
+
+    let on_password = {
+        let password = password.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                password.set(input.value());
+            }
+        })
+    };
+
+    let on_xpub = {
+        let xpub = xpub.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                xpub.set(input.value());
+            }
+        })
+    };
+
+    let on_addresses = {
+        let addresses = addresses.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                addresses.set(input.value());
+            }
+        })
+    };
+
+    let onsubmit = {
+        let filename = filename.clone();
+        let password = password.clone();
+        let xpub = xpub.clone();
+        let addresses = addresses.clone();
+        let cb = props.on_submit.clone();
+        let push_toast = props.push_toast.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let mut has_error = false;
+
+            if (*filename).is_empty() {
+                push_toast.emit(("Filename is required".into(), ToastKind::Error));
+                has_error = true;
+            } else if !is_valid_filename(&filename) {
+                push_toast.emit(("Filename contains invalid characters or is too long".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if (*password).is_empty() {
+                push_toast.emit(("Password is required".into(), ToastKind::Error));
+                has_error = true;
+            } else if !is_valid_password(&password) {
+                push_toast.emit(("Password must be at least 8 characters".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if (*xpub).trim().is_empty() {
+                push_toast.emit(("Extended public key is required".into(), ToastKind::Error));
+                has_error = true;
+            }
+
+            if has_error {
+                return;
+            }
+
+            let addresses: Vec<String> = (*addresses)
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(str::to_string)
+                .collect();
+
+            cb.emit(WatchOnlyRequest {
+                xpub: (*xpub).trim().to_string(),
+                addresses,
+                secret: (*password).clone(),
+                filename: (*filename).clone(),
+            });
+        })
+    };
+
+    html! {
+        <div class="screen-container import-centered">
+            <div class="import-inner centered-inner">
+                <h2 class="import-title">{"Watch-only Wallet"}</h2>
+                <p class="home-hint">
+                    {"Monitor an account from its extended public key. No private keys are stored, so this wallet can show balances and addresses but cannot sign."}
+                </p>
+                <form class="import-form" {onsubmit}>
+                    <div class="row centered-row">
+                        <div class="input-wrapper">
+                            <input
+                                type="text"
+                                placeholder="Wallet filename"
+                                class="input"
+                                oninput={on_filename}
+                                disabled={props.is_loading}
+                            />
+                        </div>
+                        <div class="input-wrapper">
+                            <input
+                                type="password"
+                                placeholder="New password"
+                                class="input"
+                                oninput={on_password}
+                                disabled={props.is_loading}
+                            />
+                        </div>
+                    </div>
+
+                    <textarea
+                        class="input watch-only-xpub"
+                        placeholder="Extended public key (xpub…)"
+                        rows="3"
+                        value={(*xpub).clone()}
+                        oninput={on_xpub}
+                        disabled={props.is_loading}
+                    />
+                    <textarea
+                        class="input watch-only-addresses"
+                        placeholder="Additional addresses to watch (optional, one per line)"
+                        rows="4"
+                        value={(*addresses).clone()}
+                        oninput={on_addresses}
+                        disabled={props.is_loading}
+                    />
+
+                    <div class="button-group">
+                        <button
+                            type="submit"
+                            disabled={props.is_loading}
+                            class={classes!("btn", "btn-prominent", if props.is_loading { "loading" } else { "" })}
+                        >
+                            { if props.is_loading { "Creating..." } else { "Create Watch-only Wallet" } }
+                        </button>
+                    </div>
+                </form>
+
+                <p class="import-create-link">
+                    {"Have a recovery phrase? "}
+                    <a href="#" onclick={props.on_import.clone()}>{"Import Wallet"}</a>
+                </p>
+            </div>
+        </div>
+    }
+}
@@ -22,7 +22,10 @@ pub fn mnemonic_display(props: &MnemonicDisplayProps) -> Html {
             <div class="mnemonic-inner">
                 <h2 class="mnemonic-title">{"Wallet Created Successfully"}</h2>
                 <p class="mnemonic-instruction">
-                    {"Please save your 24-word mnemonic phrase securely. This is the ONLY way to recover your wallet if you lose access. Store it offline and never share it."}
+                    { format!(
+                        "Please save your {}-word mnemonic phrase securely. This is the ONLY way to recover your wallet if you lose access. Store it offline and never share it.",
+                        words.len()
+                    ) }
                 </p>
 
                 <div class="mnemonic-container">
@@ -43,7 +46,7 @@ pub fn mnemonic_display(props: &MnemonicDisplayProps) -> Html {
 
                 <div class="button-group mnemonic-button-group">
                     <button onclick={props.on_proceed.clone()} class="btn btn-prominent">
-                        {"Proceed to Wallet"}
+                        {"I've Written It Down"}
                     </button>
                 </div>
             </div>
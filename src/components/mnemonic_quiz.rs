@@ -0,0 +1,164 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use crate::models::{BackupChallenge, ToastKind};
+use crate::utils::{bind_input, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct MnemonicQuizProps {
+    pub on_confirmed: Callback<()>,
+    /// Returns to the phrase, or to the wallet when it is not on screen.
+    pub on_back: Callback<MouseEvent>,
+    pub on_skip: Callback<MouseEvent>,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(MnemonicQuiz)]
+pub fn mnemonic_quiz(props: &MnemonicQuizProps) -> Html {
+    let positions = use_state(Vec::<usize>::new);
+    let answers = use_state(Vec::<String>::new);
+    let payment_secret_required = use_state(|| false);
+    let payment_secret = use_state(String::new);
+    let busy = use_state(|| false);
+
+    {
+        let positions = positions.clone();
+        let answers = answers.clone();
+        let payment_secret_required = payment_secret_required.clone();
+        let push_toast = props.push_toast.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match safe_invoke("get_backup_challenge", JsValue::NULL).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<BackupChallenge>(res) {
+                        Ok(challenge) => {
+                            answers.set(vec![String::new(); challenge.positions.len()]);
+                            positions.set(challenge.positions);
+                            payment_secret_required.set(challenge.payment_secret_required);
+                        }
+                        Err(_) => push_toast.emit(("Failed to load the backup check".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+            });
+            || {}
+        });
+    }
+
+    let on_answer = {
+        let answers = answers.clone();
+        move |i: usize| {
+            let answers = answers.clone();
+            Callback::from(move |e: InputEvent| {
+                if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                    let mut current = (*answers).clone();
+                    if let Some(slot) = current.get_mut(i) {
+                        *slot = input.value();
+                    }
+                    answers.set(current);
+                }
+            })
+        }
+    };
+
+    let onsubmit = {
+        let answers = answers.clone();
+        let payment_secret_required = payment_secret_required.clone();
+        let payment_secret = payment_secret.clone();
+        let busy = busy.clone();
+        let on_confirmed = props.on_confirmed.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if answers.iter().any(|word| word.trim().is_empty()) {
+                push_toast.emit(("Fill in every requested word".into(), ToastKind::Error));
+                return;
+            }
+            let payment = (*payment_secret).trim().to_string();
+            if *payment_secret_required && payment.is_empty() {
+                push_toast.emit(("Payment secret is required".into(), ToastKind::Error));
+                return;
+            }
+            let args = serde_json::json!({
+                "input": { "words": *answers, "payment_secret": (!payment.is_empty()).then_some(payment) }
+            });
+            let busy = busy.clone();
+            let on_confirmed = on_confirmed.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("confirm_mnemonic_backup", args).await {
+                    Ok(_) => {
+                        push_toast.emit(("Recovery phrase backup confirmed".into(), ToastKind::Success));
+                        on_confirmed.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="screen-container mnemonic-centered">
+            <div class="mnemonic-inner">
+                <h2 class="mnemonic-title">{"Confirm Your Recovery Phrase"}</h2>
+                <p class="mnemonic-instruction">
+                    {"Enter the requested words from the phrase you wrote down to show it was saved correctly."}
+                </p>
+
+                <form class="mnemonic-quiz" {onsubmit}>
+                    <div class="mnemonic-quiz-grid">
+                        { for positions.iter().enumerate().map(|(i, position)| html! {
+                            <label class="mnemonic-quiz-slot">
+                                <span class="mnemonic-quiz-label">{ format!("Word #{}", position) }</span>
+                                <input
+                                    class="input"
+                                    type="text"
+                                    autocomplete="off"
+                                    autocapitalize="off"
+                                    spellcheck="false"
+                                    value={answers.get(i).cloned().unwrap_or_default()}
+                                    oninput={on_answer(i)}
+                                    disabled={*busy}
+                                />
+                            </label>
+                        })}
+                    </div>
+
+                    if *payment_secret_required {
+                        <p class="mnemonic-instruction">
+                            {"This phrase is stored under your payment secret, so enter it to check the words."}
+                        </p>
+                        <input
+                            class="input"
+                            type="password"
+                            placeholder="Payment secret"
+                            value={(*payment_secret).clone()}
+                            oninput={bind_input(&payment_secret)}
+                            disabled={*busy}
+                        />
+                    }
+
+                    <div class="button-group mnemonic-button-group">
+                        <button type="button" class="btn" onclick={props.on_back.clone()} disabled={*busy}>
+                            {"Back"}
+                        </button>
+                        <button
+                            type="submit"
+                            class={classes!("btn", "btn-prominent", if *busy { "loading" } else { "" })}
+                            disabled={*busy || positions.is_empty()}
+                        >
+                            {"Confirm Backup"}
+                        </button>
+                    </div>
+                </form>
+
+                <p class="mnemonic-skip">
+                    <a href="#" onclick={props.on_skip.clone()}>{"Remind me later"}</a>
+                </p>
+            </div>
+        </div>
+    }
+}
@@ -2,6 +2,8 @@ pub mod intro;
 pub mod home;
 pub mod create_wallet;
 pub mod import_wallet;
+pub mod import_watch_only;
+pub mod import_private_key;
 pub mod mnemonic_display;
 pub mod dashboard;
 pub mod transactions;
@@ -9,13 +11,41 @@ pub mod send;
 pub mod receive;
 pub mod toast;
 pub mod tx_detail_modal;
+pub mod settings;
+pub mod coin_control;
+pub mod compound;
+pub mod account_switcher;
+pub mod address_list;
+pub mod multisig;
+pub mod offline_signing;
+pub mod sweep_key;
+pub mod wallet_security;
+pub mod unlock_screen;
+pub mod auto_lock;
+pub mod mnemonic_quiz;
+pub mod recovery_phrase;
 
 pub use intro::Intro;
 pub use home::Home;
 pub use create_wallet::CreateWallet;
 pub use import_wallet::ImportWallet;
+pub use import_watch_only::ImportWatchOnly;
+pub use import_private_key::ImportPrivateKey;
 pub use mnemonic_display::MnemonicDisplay;
 pub use dashboard::Dashboard;
 pub use transactions::Transactions;
 pub use send::Send;
-pub use tx_detail_modal::TxDetailModal;
\ No newline at end of file
+pub use tx_detail_modal::TxDetailModal;
+pub use settings::Settings;
+pub use coin_control::CoinControl;
+pub use compound::Compound;
+pub use account_switcher::AccountSwitcher;
+pub use address_list::AddressList;
+pub use multisig::Multisig;
+pub use offline_signing::OfflineSigning;
+pub use sweep_key::SweepKey;
+pub use wallet_security::WalletSecurity;
+pub use unlock_screen::UnlockScreen;
+pub use auto_lock::AutoLock;
+pub use mnemonic_quiz::MnemonicQuiz;
+pub use recovery_phrase::RecoveryPhrase;
\ No newline at end of file
@@ -0,0 +1,431 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use crate::models::{AccountInfo, PartialTransaction, ToastKind};
+use crate::utils::{bind_input, bind_textarea, copy_callback, format_amount, safe_invoke, ve_to_veni};
+
+#[derive(Properties, PartialEq)]
+pub struct MultisigProps {
+    pub enabled: bool,
+    /// Current receive address; the selected account is re-read whenever it changes.
+    pub receive_address: String,
+    pub payment_secret_required: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+    pub on_changed: Callback<()>,
+}
+
+/// Bundles pasted one after another; the serialized form has no whitespace.
+fn split_bundles(text: &str) -> Vec<String> {
+    text.split_whitespace().map(str::to_string).collect()
+}
+
+async fn fetch_selected_account() -> Result<Option<AccountInfo>, String> {
+    let res = safe_invoke("list_accounts", JsValue::NULL).await?;
+    let accounts = serde_wasm_bindgen::from_value::<Vec<AccountInfo>>(res)
+        .map_err(|_| "Failed to parse accounts".to_string())?;
+    Ok(accounts.into_iter().find(|a| a.active))
+}
+
+#[function_component(Multisig)]
+pub fn multisig(props: &MultisigProps) -> Html {
+    let selected = use_state(|| Option::<AccountInfo>::None);
+    let payment_secret = use_state(String::new);
+    let own_xpub = use_state(String::new);
+    let name = use_state(String::new);
+    let cosigner_xpubs = use_state(String::new);
+    let minimum_signatures = use_state(|| "2".to_string());
+    let to_address = use_state(String::new);
+    let amount = use_state(String::new);
+    let pasted = use_state(String::new);
+    let partial = use_state(|| Option::<PartialTransaction>::None);
+    let busy = use_state(|| false);
+
+    {
+        let selected = selected.clone();
+        let partial = partial.clone();
+        let push_toast = props.push_toast.clone();
+        use_effect_with((props.enabled, props.receive_address.clone()), move |(enabled, _)| {
+            partial.set(None);
+            if *enabled {
+                spawn_local(async move {
+                    match fetch_selected_account().await {
+                        Ok(account) => selected.set(account),
+                        Err(e) => push_toast.emit((e, ToastKind::Error)),
+                    }
+                });
+            } else {
+                selected.set(None);
+            }
+            || {}
+        });
+    }
+
+    let secret_arg = {
+        let payment_secret = (*payment_secret).trim().to_string();
+        move || (!payment_secret.is_empty()).then(|| payment_secret.clone())
+    };
+
+    // Runs a command returning a partial transaction and shows the result.
+    let run_partial = {
+        let partial = partial.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        move |cmd: &'static str, args: serde_json::Value, success: &'static str| {
+            let partial = partial.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke(cmd, args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<PartialTransaction>(res) {
+                        Ok(tx) => {
+                            push_toast.emit((
+                                format!("{} ({} of {} signatures)", success, tx.signatures, tx.required_signatures),
+                                ToastKind::Success,
+                            ));
+                            partial.set(Some(tx));
+                        }
+                        Err(_) => push_toast.emit(("Failed to parse partial transaction".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let on_show_xpub = {
+        let own_xpub = own_xpub.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        let secret_arg = secret_arg.clone();
+        Callback::from(move |_| {
+            let own_xpub = own_xpub.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            let args = serde_json::json!({ "input": { "payment_secret": secret_arg() } });
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("get_multisig_xpub", args).await {
+                    Ok(res) => own_xpub.set(res.as_string().unwrap_or_default()),
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_create = {
+        let name = name.clone();
+        let cosigner_xpubs = cosigner_xpubs.clone();
+        let minimum_signatures = minimum_signatures.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        let on_changed = props.on_changed.clone();
+        let secret_arg = secret_arg.clone();
+        let payment_secret_required = props.payment_secret_required;
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let xpubs = split_bundles(&cosigner_xpubs);
+            if xpubs.is_empty() {
+                push_toast.emit(("Add at least one cosigner extended public key".into(), ToastKind::Error));
+                return;
+            }
+            let Ok(required) = minimum_signatures.trim().parse::<u16>() else {
+                push_toast.emit(("Required signatures must be a number".into(), ToastKind::Error));
+                return;
+            };
+            if payment_secret_required && secret_arg().is_none() {
+                push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
+                return;
+            }
+
+            let value = (*name).trim().to_string();
+            let args = serde_json::json!({
+                "input": {
+                    "name": (!value.is_empty()).then_some(value),
+                    "cosigner_xpubs": xpubs,
+                    "minimum_signatures": required,
+                    "payment_secret": secret_arg()
+                }
+            });
+
+            let cosigner_xpubs = cosigner_xpubs.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            let on_changed = on_changed.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("create_multisig_account", args).await {
+                    Ok(res) => {
+                        if let Ok(account) = serde_wasm_bindgen::from_value::<AccountInfo>(res) {
+                            push_toast.emit((
+                                format!(
+                                    "Multisig account '{}' created: {}",
+                                    account.name,
+                                    account.receive_address.unwrap_or_default()
+                                ),
+                                ToastKind::Success,
+                            ));
+                        }
+                        cosigner_xpubs.set(String::new());
+                        on_changed.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_build = {
+        let to_address = to_address.clone();
+        let amount = amount.clone();
+        let push_toast = props.push_toast.clone();
+        let secret_arg = secret_arg.clone();
+        let run_partial = run_partial.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let address = (*to_address).trim().to_string();
+            if address.is_empty() {
+                push_toast.emit(("Recipient address is required".into(), ToastKind::Error));
+                return;
+            }
+            let Some(veni) = ve_to_veni(&amount) else {
+                push_toast.emit(("Enter a valid amount".into(), ToastKind::Error));
+                return;
+            };
+            run_partial(
+                "create_partial_transaction",
+                serde_json::json!({
+                    "input": { "to_address": address, "amount": veni, "payment_secret": secret_arg() }
+                }),
+                "Transaction created and signed",
+            );
+        })
+    };
+
+    let on_sign = {
+        let pasted = pasted.clone();
+        let push_toast = props.push_toast.clone();
+        let secret_arg = secret_arg.clone();
+        let run_partial = run_partial.clone();
+        Callback::from(move |_| {
+            let bundles = split_bundles(&pasted);
+            let [pskb] = bundles.as_slice() else {
+                push_toast.emit(("Paste exactly one partially signed transaction to sign".into(), ToastKind::Error));
+                return;
+            };
+            run_partial(
+                "sign_partial_transaction",
+                serde_json::json!({ "input": { "pskb": pskb, "payment_secret": secret_arg() } }),
+                "Signed",
+            );
+        })
+    };
+
+    let on_combine = {
+        let pasted = pasted.clone();
+        let partial = partial.clone();
+        let push_toast = props.push_toast.clone();
+        let run_partial = run_partial.clone();
+        Callback::from(move |_| {
+            let mut bundles = split_bundles(&pasted);
+            if let Some(current) = partial.as_ref() {
+                bundles.insert(0, current.pskb.clone());
+            }
+            if bundles.len() < 2 {
+                push_toast.emit(("Paste the cosigners' transactions to combine".into(), ToastKind::Error));
+                return;
+            }
+            run_partial(
+                "combine_partial_transactions",
+                serde_json::json!({ "input": { "pskbs": bundles } }),
+                "Signatures combined",
+            );
+        })
+    };
+
+    let on_submit = {
+        let partial = partial.clone();
+        let pasted = pasted.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_| {
+            let Some(pskb) = partial.as_ref().map(|p| p.pskb.clone()) else { return };
+            let partial = partial.clone();
+            let pasted = pasted.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            let on_changed = on_changed.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "pskb": pskb }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("submit_partial_transaction", args).await {
+                    Ok(res) => {
+                        let txids = serde_wasm_bindgen::from_value::<Vec<String>>(res).unwrap_or_default();
+                        push_toast.emit((
+                            format!("Submitted {}", txids.last().cloned().unwrap_or_default()),
+                            ToastKind::Success,
+                        ));
+                        partial.set(None);
+                        pasted.set(String::new());
+                        on_changed.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    if !props.enabled {
+        return html! {
+            <div class="screen-container">
+                <p class="batch-hint">{"Open a wallet and connect to a node to use multisig."}</p>
+            </div>
+        };
+    }
+
+    let multisig_info = selected.as_ref().and_then(|a| a.multisig.clone());
+    let receive_address = selected.as_ref().and_then(|a| a.receive_address.clone()).unwrap_or_default();
+
+    html! {
+        <div class="screen-container multisig">
+            if props.payment_secret_required {
+                <div class="row centered-row">
+                    <input
+                        class="input"
+                        type="password"
+                        placeholder="Payment Secret"
+                        value={(*payment_secret).clone()}
+                        oninput={bind_input(&payment_secret)}
+                    />
+                </div>
+            }
+
+            <div class="batch-section">
+                <h3 class="send-recent-title">{"Cosigner Key"}</h3>
+                <p class="batch-hint">{"Share this key with the other cosigners so everyone can create the same multisig account."}</p>
+                if own_xpub.is_empty() {
+                    <button type="button" class="btn btn-small" onclick={on_show_xpub} disabled={*busy}>{"Show Cosigner Key"}</button>
+                } else {
+                    <div class="multisig-blob">
+                        <textarea class="input" rows="3" readonly=true value={(*own_xpub).clone()} />
+                        <button type="button" class="btn btn-small" onclick={copy_callback((*own_xpub).clone(), props.push_toast.clone())}>{"Copy"}</button>
+                    </div>
+                }
+            </div>
+
+            <form class="batch-section" onsubmit={on_create}>
+                <h3 class="send-recent-title">{"New Multisig Account"}</h3>
+                <div class="row">
+                    <input class="input" placeholder="Name (optional)" value={(*name).clone()} oninput={bind_input(&name)} />
+                    <input
+                        class="input multisig-threshold"
+                        type="number"
+                        min="1"
+                        placeholder="Required signatures"
+                        value={(*minimum_signatures).clone()}
+                        oninput={bind_input(&minimum_signatures)}
+                    />
+                </div>
+                <textarea
+                    class="input"
+                    rows="4"
+                    placeholder="Cosigner extended public keys, one per line"
+                    value={(*cosigner_xpubs).clone()}
+                    oninput={bind_textarea(&cosigner_xpubs)}
+                />
+                <p class="batch-hint">
+                    { format!("{} of {} cosigners must sign", minimum_signatures.trim(), split_bundles(&cosigner_xpubs).len() + 1) }
+                </p>
+                <button type="submit" class="btn btn-small btn-prominent" disabled={*busy}>{"Create Account"}</button>
+            </form>
+
+            { if let Some(info) = multisig_info {
+                html! {
+                    <div class="batch-section">
+                        <h3 class="send-recent-title">
+                            { format!("Co-sign · {}-of-{} multisig", info.minimum_signatures, info.cosigners) }
+                        </h3>
+                        <div class="address-container">
+                            <p class="receive-address">{ &receive_address }</p>
+                            <button type="button" class="btn btn-small" onclick={copy_callback(receive_address.clone(), props.push_toast.clone())}>{"Copy"}</button>
+                        </div>
+
+                        <form class="row" onsubmit={on_build}>
+                            <input class="input" placeholder="Recipient address" value={(*to_address).clone()} oninput={bind_input(&to_address)} />
+                            <input class="input" placeholder="Amount (VE)" value={(*amount).clone()} oninput={bind_input(&amount)} />
+                            <button type="submit" class="btn btn-small" disabled={*busy}>{"Create & Sign"}</button>
+                        </form>
+
+                        <textarea
+                            class="input"
+                            rows="4"
+                            placeholder="Paste partially signed transactions from cosigners"
+                            value={(*pasted).clone()}
+                            oninput={bind_textarea(&pasted)}
+                        />
+                        <div class="button-group">
+                            <button type="button" class="btn btn-small" onclick={on_sign} disabled={*busy}>{"Sign"}</button>
+                            <button type="button" class="btn btn-small" onclick={on_combine} disabled={*busy}>{"Combine"}</button>
+                        </div>
+
+                        { if let Some(tx) = partial.as_ref() {
+                            html! {
+                                <div class="multisig-partial">
+                                    <p class={classes!("batch-summary", if tx.complete { "complete" } else { "" })}>
+                                        { format!(
+                                            "{} of {} signatures · {} input(s) · fees {}",
+                                            tx.signatures,
+                                            tx.required_signatures,
+                                            tx.inputs,
+                                            format_amount(tx.fees)
+                                        ) }
+                                    </p>
+                                    <table class="batch-table">
+                                        <tbody>
+                                            { for tx.outputs.iter().map(|output| html! {
+                                                <tr>
+                                                    <td class="batch-address">{ output.address.clone().unwrap_or_else(|| "non-standard script".into()) }</td>
+                                                    <td>{ format_amount(output.amount) }</td>
+                                                    <td>
+                                                        if output.change {
+                                                            <span class="tx-badge">{"change"}</span>
+                                                        }
+                                                    </td>
+                                                </tr>
+                                            })}
+                                        </tbody>
+                                    </table>
+                                    <div class="multisig-blob">
+                                        <textarea class="input" rows="4" readonly=true value={tx.pskb.clone()} />
+                                        <button type="button" class="btn btn-small" onclick={copy_callback(tx.pskb.clone(), props.push_toast.clone())}>{"Copy"}</button>
+                                    </div>
+                                    <button
+                                        type="button"
+                                        class="btn btn-prominent"
+                                        onclick={on_submit}
+                                        disabled={*busy || !tx.complete}
+                                    >
+                                        {"Submit"}
+                                    </button>
+                                </div>
+                            }
+                        } else { html!{} }}
+                    </div>
+                }
+            } else {
+                html! {
+                    <p class="batch-hint">{"Select a multisig account in the sidebar to create, sign and submit transactions."}</p>
+                }
+            }}
+        </div>
+    }
+}
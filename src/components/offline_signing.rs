@@ -0,0 +1,410 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use gloo::file::callbacks::{read_as_text, FileReader};
+use crate::models::{ToastKind, TransactionReview};
+use crate::utils::{bind_input, copy_callback, format_amount, safe_invoke, ve_to_veni};
+
+const FEE_PRIORITIES: [(&str, &str); 3] = [("low", "Low"), ("normal", "Normal"), ("priority", "Priority")];
+const FILE_FORMATS: [(&str, &str); 2] = [("json", "JSON"), ("borsh", "Borsh (hex)")];
+
+#[derive(Properties, PartialEq)]
+pub struct OfflineSigningProps {
+    pub wallet_created: bool,
+    pub node_connected: bool,
+    pub watch_only: bool,
+    pub payment_secret_required: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+fn short(text: &str) -> String {
+    if text.len() > 28 {
+        format!("{}…{}", &text[..16], &text[text.len() - 8..])
+    } else {
+        text.to_string()
+    }
+}
+
+fn default_filename(review: &TransactionReview) -> String {
+    let stamp = js_sys::Date::now() as u64 / 1000;
+    format!("vecno-tx-{}-{}", stamp, if review.signed { "signed" } else { "unsigned" })
+}
+
+#[function_component(OfflineSigning)]
+pub fn offline_signing(props: &OfflineSigningProps) -> Html {
+    let to_address = use_state(String::new);
+    let amount = use_state(String::new);
+    let sweep = use_state(|| false);
+    let fee_priority = use_state(|| "normal".to_string());
+    let payment_secret = use_state(String::new);
+    let filename = use_state(String::new);
+    // Format the file is saved in; follows the last loaded file.
+    let save_format = use_state(|| "json".to_string());
+    let review = use_state(|| Option::<TransactionReview>::None);
+    let busy = use_state(|| false);
+    let file_reader = use_mut_ref(|| None::<FileReader>);
+
+    // Runs a command returning a review and makes it the current one.
+    let run_review = {
+        let review = review.clone();
+        let filename = filename.clone();
+        let save_format = save_format.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        move |cmd: &'static str, args: serde_json::Value, success: &'static str| {
+            let review = review.clone();
+            let filename = filename.clone();
+            let save_format = save_format.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke(cmd, args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<TransactionReview>(res) {
+                        Ok(loaded) => {
+                            push_toast.emit((success.to_string(), ToastKind::Success));
+                            filename.set(default_filename(&loaded));
+                            save_format.set(loaded.format.clone());
+                            review.set(Some(loaded));
+                        }
+                        Err(_) => push_toast.emit(("Failed to parse transaction review".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let on_build = {
+        let to_address = to_address.clone();
+        let amount = amount.clone();
+        let sweep = sweep.clone();
+        let fee_priority = fee_priority.clone();
+        let push_toast = props.push_toast.clone();
+        let run_review = run_review.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let address = (*to_address).trim().to_string();
+            if address.is_empty() {
+                push_toast.emit(("Recipient address is required".into(), ToastKind::Error));
+                return;
+            }
+            let veni = if *sweep {
+                0
+            } else {
+                match ve_to_veni(&amount) {
+                    Some(veni) => veni,
+                    None => {
+                        push_toast.emit(("Enter a valid amount".into(), ToastKind::Error));
+                        return;
+                    }
+                }
+            };
+            run_review(
+                "build_unsigned_transaction",
+                serde_json::json!({
+                    "input": {
+                        "to_address": address,
+                        "amount": veni,
+                        "fee_priority": *fee_priority,
+                        "sweep": *sweep
+                    }
+                }),
+                "Unsigned transaction built",
+            );
+        })
+    };
+
+    let on_file = {
+        let reader = file_reader.clone();
+        let push_toast = props.push_toast.clone();
+        let run_review = run_review.clone();
+        Callback::from(move |ev: Event| {
+            let Some(input) = ev.target_dyn_into::<web_sys::HtmlInputElement>() else { return };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+            // Reset so loading the same file again fires `change` again.
+            input.set_value("");
+
+            let push_toast = push_toast.clone();
+            let run_review = run_review.clone();
+            let task = read_as_text(&gloo::file::File::from(file), move |result| match result {
+                Ok(text) => run_review(
+                    "review_transaction_file",
+                    serde_json::json!({ "data": text }),
+                    "Transaction file loaded",
+                ),
+                Err(e) => push_toast.emit((format!("Failed to read file: {}", e), ToastKind::Error)),
+            });
+            *reader.borrow_mut() = Some(task);
+        })
+    };
+
+    let on_sign = {
+        let review = review.clone();
+        let payment_secret = payment_secret.clone();
+        let push_toast = props.push_toast.clone();
+        let payment_secret_required = props.payment_secret_required;
+        let run_review = run_review.clone();
+        Callback::from(move |_| {
+            let Some(current) = review.as_ref() else { return };
+            let secret = (*payment_secret).trim().to_string();
+            if payment_secret_required && secret.is_empty() {
+                push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
+                return;
+            }
+            run_review(
+                "sign_transaction_file",
+                serde_json::json!({
+                    "input": {
+                        "data": current.data,
+                        "payment_secret": (!secret.is_empty()).then_some(secret)
+                    }
+                }),
+                "Transaction signed",
+            );
+        })
+    };
+
+    let on_save = {
+        let review = review.clone();
+        let filename = filename.clone();
+        let save_format = save_format.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_| {
+            let Some(data) = review.as_ref().map(|r| r.data.clone()) else { return };
+            let name = (*filename).trim().to_string();
+            let format = (*save_format).clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "input": { "data": data, "filename": name, "format": format }
+                })).unwrap_or(JsValue::NULL);
+                match safe_invoke("save_transaction_file", args).await {
+                    Ok(res) => push_toast.emit((
+                        format!("Saved to {}", res.as_string().unwrap_or_default()),
+                        ToastKind::Success,
+                    )),
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_save_format = {
+        let save_format = save_format.clone();
+        Callback::from(move |ev: Event| {
+            if let Some(select) = ev.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                save_format.set(select.value());
+            }
+        })
+    };
+
+    let on_submit = {
+        let review = review.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_| {
+            let Some(data) = review.as_ref().map(|r| r.data.clone()) else { return };
+            let review = review.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "data": data }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("submit_transaction_file", args).await {
+                    Ok(res) => {
+                        let txids = serde_wasm_bindgen::from_value::<Vec<String>>(res).unwrap_or_default();
+                        push_toast.emit((
+                            format!("Submitted {}", txids.last().cloned().unwrap_or_default()),
+                            ToastKind::Success,
+                        ));
+                        review.set(None);
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_fee_priority = {
+        let fee_priority = fee_priority.clone();
+        move |value: &'static str| {
+            let fee_priority = fee_priority.clone();
+            Callback::from(move |_| fee_priority.set(value.to_string()))
+        }
+    };
+
+    let on_sweep = {
+        let sweep = sweep.clone();
+        Callback::from(move |_| sweep.set(!*sweep))
+    };
+
+    let on_discard = {
+        let review = review.clone();
+        Callback::from(move |_| review.set(None))
+    };
+
+    let can_build = props.wallet_created && props.node_connected && !*busy;
+
+    html! {
+        <div class="screen-container offline-signing">
+            <form class="batch-section" onsubmit={on_build}>
+                <h3 class="send-recent-title">{"Build Unsigned Transaction"}</h3>
+                <p class="batch-hint">{"Run on the online machine. Nothing is signed; save the file and carry it to the signing machine."}</p>
+                <div class="row">
+                    <input class="input" placeholder="Recipient address" value={(*to_address).clone()} oninput={bind_input(&to_address)} />
+                    <input
+                        class="input"
+                        placeholder={if *sweep { "Entire balance" } else { "Amount (VE)" }}
+                        value={(*amount).clone()}
+                        oninput={bind_input(&amount)}
+                        disabled={*sweep}
+                    />
+                </div>
+                <div class="fee-selector">
+                    { for FEE_PRIORITIES.iter().map(|(value, label)| html! {
+                        <button
+                            type="button"
+                            class={classes!("btn", "btn-small", "fee-option", if *fee_priority == *value { "active" } else { "" })}
+                            onclick={on_fee_priority(value)}
+                        >
+                            { *label }
+                        </button>
+                    })}
+                    <label class="checkbox-label">
+                        <input type="checkbox" checked={*sweep} onchange={on_sweep} />
+                        {" Send entire balance"}
+                    </label>
+                </div>
+                <button type="submit" class="btn btn-small btn-prominent" disabled={!can_build}>{"Build"}</button>
+                if !props.node_connected {
+                    <p class="batch-hint">{"Building needs a node connection; signing a loaded file does not."}</p>
+                }
+            </form>
+
+            <div class="batch-section">
+                <div class="batch-header">
+                    <h3 class="send-recent-title">{"Load Transaction File"}</h3>
+                    <label class={classes!("btn", "btn-small", if *busy || !props.wallet_created { "disabled" } else { "" })}>
+                        {"Open File"}
+                        <input
+                            type="file"
+                            accept=".json,.borsh,application/json,text/plain"
+                            hidden=true
+                            onchange={on_file}
+                            disabled={*busy || !props.wallet_created}
+                        />
+                    </label>
+                </div>
+
+                { if let Some(tx) = review.as_ref() {
+                    html! {
+                        <div class="offline-review">
+                            <p class="batch-summary">
+                                <span class={classes!("tx-badge", if tx.signed { "confirmed" } else { "pending" })}>
+                                    { if tx.signed { "signed" } else { "unsigned" } }
+                                </span>
+                                { format!(" {} transaction(s) on {}", tx.transactions, tx.network_id) }
+                            </p>
+
+                            <h4>{"Inputs"}</h4>
+                            <table class="batch-table">
+                                <tbody>
+                                    { for tx.inputs.iter().map(|input| html! {
+                                        <tr>
+                                            <td class="batch-address" title={input.outpoint.clone()}>{ short(&input.outpoint) }</td>
+                                            <td class="batch-address" title={input.address.clone()}>{ short(&input.address) }</td>
+                                            <td>{ format_amount(input.amount) }</td>
+                                        </tr>
+                                    })}
+                                </tbody>
+                            </table>
+
+                            <h4>{"Outputs"}</h4>
+                            <table class="batch-table">
+                                <tbody>
+                                    { for tx.outputs.iter().map(|output| {
+                                        let address = output.address.clone().unwrap_or_else(|| "non-standard script".into());
+                                        html! {
+                                            <tr>
+                                                <td class="batch-address" title={address.clone()}>{ address }</td>
+                                                <td>{ format_amount(output.amount) }</td>
+                                                <td>
+                                                    if output.change {
+                                                        <span class="tx-badge">{"change"}</span>
+                                                    }
+                                                </td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+
+                            <p class="batch-summary">
+                                { format!(
+                                    "In {} · Out {} · Change {} · Fees {}",
+                                    format_amount(tx.total_input),
+                                    format_amount(tx.total_output),
+                                    format_amount(tx.change),
+                                    format_amount(tx.fees)
+                                ) }
+                            </p>
+
+                            <div class="row">
+                                <input class="input" placeholder="File name" value={(*filename).clone()} oninput={bind_input(&filename)} />
+                                <select class="input" onchange={on_save_format}>
+                                    { for FILE_FORMATS.iter().map(|(value, label)| html! {
+                                        <option value={*value} selected={*save_format == *value}>{ *label }</option>
+                                    })}
+                                </select>
+                                <button type="button" class="btn btn-small" onclick={on_save} disabled={*busy}>{"Save File"}</button>
+                                <button type="button" class="btn btn-small" onclick={copy_callback(tx.data.clone(), props.push_toast.clone())}>{"Copy"}</button>
+                            </div>
+
+                            if !tx.signed {
+                                if props.watch_only {
+                                    <p class="batch-hint">{"This is a watch-only wallet: sign the file on the machine holding the keys."}</p>
+                                } else {
+                                    <div class="row">
+                                        if props.payment_secret_required {
+                                            <input
+                                                class="input"
+                                                type="password"
+                                                placeholder="Payment Secret"
+                                                value={(*payment_secret).clone()}
+                                                oninput={bind_input(&payment_secret)}
+                                            />
+                                        }
+                                        <button type="button" class="btn btn-prominent" onclick={on_sign} disabled={*busy}>{"Sign"}</button>
+                                    </div>
+                                }
+                            }
+
+                            <div class="button-group">
+                                <button type="button" class="btn btn-small" onclick={on_discard} disabled={*busy}>{"Discard"}</button>
+                                <button
+                                    type="button"
+                                    class="btn btn-prominent"
+                                    onclick={on_submit}
+                                    disabled={*busy || !tx.signed || !props.node_connected}
+                                >
+                                    {"Submit"}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! { <p class="batch-hint">{"Build a transaction above or open a saved transaction file (JSON or hex-encoded Borsh) to review it."}</p> }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -5,12 +5,15 @@ use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use gloo::timers::future::TimeoutFuture;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::{window, Navigator, Clipboard};
-use crate::models::WalletAddress;
+use crate::components::{AddressList, SweepKey};
+use crate::models::{ToastKind, WalletAddress};
 
 #[derive(Properties, PartialEq)]
 pub struct ReceiveProps {
     pub addresses: Vec<WalletAddress>,
     pub is_loading: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+    pub on_new_address: Callback<()>,
 }
 
 #[function_component(Receive)]
@@ -169,7 +172,19 @@ pub fn receive(props: &ReceiveProps) -> Html {
                         </div>
                     }
                 }}
+
+                if let Some(addr) = props.addresses.first() {
+                    <AddressList
+                        receive_address={addr.receive_address.clone()}
+                        push_toast={props.push_toast.clone()}
+                        on_new_address={props.on_new_address.clone()}
+                    />
+                    <SweepKey
+                        receive_address={addr.receive_address.clone()}
+                        push_toast={props.push_toast.clone()}
+                    />
+                }
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
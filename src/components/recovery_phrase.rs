@@ -0,0 +1,146 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use gloo_timers::callback::Interval;
+use js_sys::Date;
+use crate::models::ToastKind;
+use crate::utils::{bind_input, copy_secret, reveal_mnemonic};
+
+/// How long the phrase stays on screen once revealed.
+const REVEAL_MS: f64 = 60_000.0;
+
+#[derive(Properties, PartialEq)]
+pub struct RecoveryPhraseProps {
+    pub payment_secret_required: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(RecoveryPhrase)]
+pub fn recovery_phrase(props: &RecoveryPhraseProps) -> Html {
+    let password = use_state(String::new);
+    let payment_secret = use_state(String::new);
+    let words = use_state(|| Option::<Vec<String>>::None);
+    // When the revealed phrase hides itself again.
+    let deadline = use_state(|| Option::<f64>::None);
+    let now = use_state(Date::now);
+    let busy = use_state(|| false);
+
+    {
+        let words = words.clone();
+        let deadline_state = deadline.clone();
+        let now = now.clone();
+        use_effect_with(*deadline, move |deadline| {
+            let interval = deadline.map(|deadline| {
+                Interval::new(1000, move || {
+                    let current = Date::now();
+                    if current >= deadline {
+                        words.set(None);
+                        deadline_state.set(None);
+                    } else {
+                        now.set(current);
+                    }
+                })
+            });
+            move || drop(interval)
+        });
+    }
+
+    let on_reveal = {
+        let password = password.clone();
+        let payment_secret = payment_secret.clone();
+        let words = words.clone();
+        let deadline = deadline.clone();
+        let now = now.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if password.is_empty() {
+                push_toast.emit(("Wallet password is required".into(), ToastKind::Error));
+                return;
+            }
+            let secret = (*password).clone();
+            let payment = (*payment_secret).trim().to_string();
+            let password = password.clone();
+            let payment_secret = payment_secret.clone();
+            let words = words.clone();
+            let deadline = deadline.clone();
+            let now = now.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let payment = (!payment.is_empty()).then_some(payment);
+                match reveal_mnemonic(&secret, payment.as_deref()).await {
+                    Ok(phrase) => {
+                        password.set(String::new());
+                        payment_secret.set(String::new());
+                        words.set(Some(phrase.split_whitespace().map(str::to_string).collect()));
+                        now.set(Date::now());
+                        deadline.set(Some(Date::now() + REVEAL_MS));
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_hide = {
+        let words = words.clone();
+        let deadline = deadline.clone();
+        Callback::from(move |_: MouseEvent| {
+            words.set(None);
+            deadline.set(None);
+        })
+    };
+
+    let remaining = deadline.map(|d| ((d - *now) / 1000.0).ceil().max(0.0) as u32).unwrap_or(0);
+
+    html! {
+        <div class="settings-section">
+            <h3 class="section-title">{"Recovery Phrase"}</h3>
+            if let Some(list) = words.as_ref() {
+                <p class="settings-hint">
+                    { format!("Write these words down in order. They hide again in {} seconds.", remaining) }
+                </p>
+                <div class="mnemonic-text recovery-phrase-words">
+                    { for list.iter().enumerate().map(|(i, word)| html! {
+                        <span>
+                            <strong>{ format!("{}.", i + 1) }</strong>
+                            { word.clone() }
+                        </span>
+                    })}
+                </div>
+                <div class="button-group">
+                    <button
+                        type="button"
+                        class="btn"
+                        onclick={{
+                            let phrase = list.join(" ");
+                            let push_toast = props.push_toast.clone();
+                            Callback::from(move |_: MouseEvent| copy_secret(phrase.clone(), push_toast.clone()))
+                        }}
+                    >
+                        {"Copy"}
+                    </button>
+                    <button type="button" class="btn btn-primary" onclick={on_hide}>{"Hide Now"}</button>
+                </div>
+            } else {
+                <form class="security-form" onsubmit={on_reveal}>
+                    <p class="settings-hint">
+                        {"Show the words that restore this wallet. Anyone who sees them can take your funds, so check nobody is watching your screen."}
+                    </p>
+                    <div class="settings-row">
+                        <input class="input" type="password" placeholder="Wallet password" value={(*password).clone()} oninput={bind_input(&password)} disabled={*busy} />
+                        if props.payment_secret_required {
+                            <input class="input" type="password" placeholder="Payment secret" value={(*payment_secret).clone()} oninput={bind_input(&payment_secret)} disabled={*busy} />
+                        }
+                    </div>
+                    <div class="button-group">
+                        <button type="submit" class="btn btn-primary" disabled={*busy}>{"Reveal Recovery Phrase"}</button>
+                    </div>
+                </form>
+            }
+        </div>
+    }
+}
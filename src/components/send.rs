@@ -1,10 +1,50 @@
 use yew::prelude::*;
-use crate::utils::{ve_to_veni, format_amount};
-use crate::models::{SentTxInfo, Transaction, ToastKind};
+use gloo::file::callbacks::{read_as_text, FileReader};
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use crate::utils::{ve_to_veni, veni_to_ve, format_amount, parse_payment_csv, safe_invoke};
+use crate::components::{CoinControl, Compound};
+use crate::models::{
+    BatchOutputStatus, BatchRequest, BatchRow, BatchSendResult, SendEstimate, SendRequest, SentTxInfo,
+    Transaction, ToastKind,
+};
+
+const FEE_PRIORITIES: [(&str, &str); 3] = [("low", "Low"), ("normal", "Normal"), ("priority", "Priority")];
+const ESTIMATE_DEBOUNCE_MS: u32 = 500;
+
+fn payment_secret_from(words: &[String]) -> Option<String> {
+    let filled: Vec<&str> = words.iter().map(String::as_str).filter(|s| !s.is_empty()).collect();
+    if filled.is_empty() {
+        None
+    } else {
+        Some(filled.join(" "))
+    }
+}
+
+/// Backend status for a CSV row, if the last batch result was for these rows.
+fn row_status<'a>(result: Option<&'a BatchSendResult>, index: usize, row: &BatchRow) -> Option<&'a BatchOutputStatus> {
+    result
+        .and_then(|r| r.outputs.get(index))
+        .filter(|s| s.to_address == row.to_address && s.amount == row.amount)
+}
+
+fn format_eta(seconds: f64) -> String {
+    if seconds < 1.0 {
+        "< 1s".to_string()
+    } else if seconds < 60.0 {
+        format!("~{:.0}s", seconds)
+    } else {
+        format!("~{:.0}m", seconds / 60.0)
+    }
+}
 
 #[derive(Properties, PartialEq)]
 pub struct SendProps {
-    pub on_send: Callback<(String, u64, Option<String>)>,
+    pub on_send: Callback<SendRequest>,
+    pub on_send_batch: Callback<BatchRequest>,
+    #[prop_or_default]
+    pub batch_result: Option<BatchSendResult>,
     pub transaction_status: String,
     pub last_sent: Option<SentTxInfo>,
     pub balance: String,
@@ -17,6 +57,11 @@ pub struct SendProps {
     pub push_toast: Callback<(String, ToastKind)>,
     #[prop_or(false)]
     pub payment_secret_required: bool,
+    #[prop_or(true)]
+    pub node_connected: bool,
+    /// Watch-only accounts can estimate but never sign.
+    #[prop_or(false)]
+    pub watch_only: bool,
 }
 
 #[function_component(Send)]
@@ -28,6 +73,13 @@ pub fn send(props: &SendProps) -> Html {
     let to_addr_error = use_state(String::new);
     let amount_error = use_state(String::new);
     let payment_secret_error = use_state(String::new);
+    let fee_priority = use_state(|| "normal".to_string());
+    let estimate = use_state(|| None::<SendEstimate>);
+    let estimate_error = use_state(String::new);
+    let sweep = use_state(|| false);
+    let selected_utxos = use_state(Vec::<String>::new);
+    let batch_rows = use_state(Vec::<BatchRow>::new);
+    let csv_reader = use_mut_ref(|| None::<FileReader>);
     let on_send = props.on_send.clone();
     let push_toast = props.push_toast.clone();
     let our_receive_address = props.our_receive_address.clone();
@@ -52,6 +104,69 @@ pub fn send(props: &SendProps) -> Html {
         });
     }
 
+    {
+        let estimate = estimate.clone();
+        let estimate_error = estimate_error.clone();
+        let amount_ve = amount_ve.clone();
+        let sweep_on = *sweep;
+        // A sweep only needs a well-formed address to size the transaction,
+        // so fall back to our own until the recipient is entered.
+        let to = match (*to_addr).trim() {
+            "" if sweep_on => our_receive_address.clone(),
+            to => to.to_string(),
+        };
+        // The sweep fills the amount field itself; keying on it would loop.
+        let amount = if sweep_on { None } else { ve_to_veni(&amount_ve) };
+        let priority = (*fee_priority).clone();
+        let enabled = props.wallet_created && props.node_connected;
+        let coins = (*selected_utxos).clone();
+        use_effect_with((to, amount, priority, enabled, sweep_on, coins), move |(to, amount, priority, enabled, sweep_on, coins)| {
+            estimate_error.set(String::new());
+            let sweep_on = *sweep_on;
+            let ready = *enabled && !to.is_empty() && (sweep_on || amount.is_some_and(|a| a > 0));
+            // Dropping the timeout on the next keystroke cancels the pending
+            // request, so the node only sees the last edit.
+            let timeout = if ready {
+                let to = to.clone();
+                let amount = amount.unwrap_or(0);
+                let priority = priority.clone();
+                let coins = (!coins.is_empty()).then(|| coins.clone());
+                Some(Timeout::new(ESTIMATE_DEBOUNCE_MS, move || {
+                    spawn_local(async move {
+                        let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                            "input": {
+                                "to_address": to,
+                                "amount": amount,
+                                "fee_priority": priority,
+                                "sweep": sweep_on,
+                                "utxos": coins
+                            }
+                        })).unwrap_or(JsValue::NULL);
+                        match safe_invoke("estimate_send", args).await {
+                            Ok(res) => match serde_wasm_bindgen::from_value::<SendEstimate>(res) {
+                                Ok(est) => {
+                                    if est.sweep {
+                                        amount_ve.set(veni_to_ve(est.amount));
+                                    }
+                                    estimate.set(Some(est));
+                                }
+                                Err(_) => estimate.set(None),
+                            },
+                            Err(e) => {
+                                estimate.set(None);
+                                estimate_error.set(e);
+                            }
+                        }
+                    });
+                }))
+            } else {
+                estimate.set(None);
+                None
+            };
+            move || drop(timeout)
+        });
+    }
+
     let on_to = {
         let a = to_addr.clone();
         let e = to_addr_error.clone();
@@ -69,9 +184,11 @@ pub fn send(props: &SendProps) -> Html {
     let on_amount = {
         let a = amount_ve.clone();
         let e = amount_error.clone();
+        let sweep = sweep.clone();
         Callback::from(move |ev: InputEvent| {
             if let Some(i) = ev.target_dyn_into::<web_sys::HtmlInputElement>() {
                 let val = i.value();
+                sweep.set(false);
                 a.set(val.clone());
                 if val.trim().is_empty() {
                     e.set(String::new());
@@ -116,8 +233,28 @@ pub fn send(props: &SendProps) -> Html {
         })
     };
 
+    let on_max = {
+        let sweep = sweep.clone();
+        let e = amount_error.clone();
+        Callback::from(move |_| {
+            e.set(String::new());
+            sweep.set(true);
+        })
+    };
+
+    let on_fee_priority = {
+        let fp = fee_priority.clone();
+        move |priority: &'static str| {
+            let fp = fp.clone();
+            Callback::from(move |_| fp.set(priority.to_string()))
+        }
+    };
+
     let onsubmit = {
         let to = to_addr.clone();
+        let fp = fee_priority.clone();
+        let sweep = sweep.clone();
+        let coins = selected_utxos.clone();
         let amt = amount_ve.clone();
         let words = payment_secret_words.clone();
 
@@ -160,19 +297,9 @@ pub fn send(props: &SendProps) -> Html {
                 }
             };
 
-            let filled: Vec<String> = (*words)
-                .iter()
-                .cloned()
-                .filter(|s| !s.is_empty())
-                .collect();
+            let pay_secret_opt = payment_secret_from(&words);
 
-            let pay_secret_opt = if !filled.is_empty() {
-                Some(filled.join(" "))
-            } else {
-                None
-            };
-
-            if payment_secret_required && filled.is_empty() {
+            if payment_secret_required && pay_secret_opt.is_none() {
                 push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
                 has_error = true;
             }
@@ -186,15 +313,98 @@ pub fn send(props: &SendProps) -> Html {
             }
 
             push_toast.emit(("Sending transaction...".into(), ToastKind::Info));
-            on_send.emit((to_addr_str, amount_veni, pay_secret_opt));
+            on_send.emit(SendRequest {
+                to_address: to_addr_str,
+                amount: amount_veni,
+                payment_secret: pay_secret_opt,
+                fee_priority: (*fp).clone(),
+                sweep: *sweep,
+                utxos: (!coins.is_empty()).then(|| (*coins).clone()),
+            });
+        })
+    };
+
+    let on_csv = {
+        let rows = batch_rows.clone();
+        let reader = csv_reader.clone();
+        let push_toast = push_toast.clone();
+        Callback::from(move |ev: Event| {
+            let Some(input) = ev.target_dyn_into::<web_sys::HtmlInputElement>() else { return };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+            // Reset so re-importing the same file fires `change` again.
+            input.set_value("");
+
+            let rows = rows.clone();
+            let push_toast = push_toast.clone();
+            let task = read_as_text(&gloo::file::File::from(file), move |result| match result {
+                Ok(text) => {
+                    let parsed = parse_payment_csv(&text);
+                    if parsed.is_empty() {
+                        push_toast.emit(("No payments found in the CSV file".into(), ToastKind::Warning));
+                    }
+                    rows.set(parsed);
+                }
+                Err(e) => push_toast.emit((format!("Failed to read CSV: {}", e), ToastKind::Error)),
+            });
+            *reader.borrow_mut() = Some(task);
+        })
+    };
+
+    let on_clear_batch = {
+        let rows = batch_rows.clone();
+        Callback::from(move |_| rows.set(Vec::new()))
+    };
+
+    let on_send_batch_click = {
+        let rows = batch_rows.clone();
+        let words = payment_secret_words.clone();
+        let fp = fee_priority.clone();
+        let coins = selected_utxos.clone();
+        let on_send_batch = props.on_send_batch.clone();
+        let push_toast = push_toast.clone();
+        let payment_secret_required = props.payment_secret_required;
+        Callback::from(move |_| {
+            if rows.is_empty() {
+                return;
+            }
+            let invalid = rows.iter().filter(|row| row.error.is_some()).count();
+            if invalid > 0 {
+                push_toast.emit((format!("Fix {} invalid row(s) before sending", invalid), ToastKind::Error));
+                return;
+            }
+
+            let payment_secret = payment_secret_from(&words);
+            if payment_secret_required && payment_secret.is_none() {
+                push_toast.emit(("Payment Secret is required for this wallet".into(), ToastKind::Error));
+                return;
+            }
+
+            push_toast.emit((format!("Sending batch of {} payment(s)...", rows.len()), ToastKind::Info));
+            on_send_batch.emit(BatchRequest {
+                rows: (*rows).clone(),
+                payment_secret,
+                fee_priority: (*fp).clone(),
+                utxos: (!coins.is_empty()).then(|| (*coins).clone()),
+            });
         })
     };
 
+    let on_select_utxos = {
+        let coins = selected_utxos.clone();
+        Callback::from(move |next: Vec<String>| coins.set(next))
+    };
+
+    let batch_total: u64 = batch_rows.iter().map(|row| row.amount).sum();
+    let batch_invalid = batch_rows.iter().filter(|row| row.error.is_some()).count();
+
     let sent_to_tx = |sent: &SentTxInfo| Transaction {
         txid: sent.txid.clone(),
+        kind: "outgoing".into(),
         to_address: sent.to_address.clone(),
         amount: sent.amount,
         timestamp: sent.timestamp.clone(),
+        maturity: "pending".into(),
+        ..Default::default()
     };
 
     let mut recent: Vec<SentTxInfo> = props.sent_transactions.clone();
@@ -244,12 +454,63 @@ pub fn send(props: &SendProps) -> Html {
                             disabled={props.is_loading || !props.wallet_created}
                             class={classes!("input", if !(*amount_error).is_empty() { "error" } else { "" })}
                         />
+                        <button
+                            type="button"
+                            class={classes!("btn", "btn-small", "input-max", if *sweep { "active" } else { "" })}
+                            onclick={on_max}
+                            disabled={props.is_loading || !props.wallet_created || !props.node_connected}
+                        >
+                            {"Max"}
+                        </button>
                         if !(*amount_error).is_empty() {
                             <p class="status error">{ (*amount_error).clone() }</p>
                         }
                     </div>
                 </div>
 
+                <div class="fee-selector">
+                    { for FEE_PRIORITIES.iter().map(|(value, label)| {
+                        let eta = (*estimate)
+                            .as_ref()
+                            .and_then(|est| est.tiers.iter().find(|tier| tier.priority == *value))
+                            .map(|tier| format_eta(tier.estimated_seconds));
+                        html! {
+                            <button
+                                type="button"
+                                class={classes!("btn", "btn-small", "fee-option", if *fee_priority == *value { "active" } else { "" })}
+                                onclick={on_fee_priority(value)}
+                                disabled={props.is_loading || !props.wallet_created}
+                            >
+                                <span>{ *label }</span>
+                                if let Some(eta) = eta {
+                                    <span class="fee-eta">{ eta }</span>
+                                }
+                            </button>
+                        }
+                    })}
+                </div>
+
+                <CoinControl
+                    selected={(*selected_utxos).clone()}
+                    on_select={on_select_utxos}
+                    enabled={props.wallet_created && !props.is_loading}
+                    push_toast={push_toast.clone()}
+                />
+
+                { if let Some(est) = (*estimate).as_ref() {
+                    html! {
+                        <div class="send-estimate">
+                            <span>{"Network fee: "}<strong>{ format_amount(est.total_fees) }</strong></span>
+                            if est.sweep {
+                                <span>{"Recipient receives "}<strong>{ format_amount(est.amount) }</strong>{" (fees deducted)"}</span>
+                            }
+                            <span>{ format!("{} input(s) · {} transaction(s) · mass {}", est.inputs, est.transactions, est.mass) }</span>
+                        </div>
+                    }
+                } else if !(*estimate_error).is_empty() {
+                    html! { <p class="status error centered-error">{ (*estimate_error).clone() }</p> }
+                } else { html!{} }}
+
                 { if props.payment_secret_required {
                     html! {
                         <>
@@ -309,14 +570,112 @@ pub fn send(props: &SendProps) -> Html {
                 <div class="button-group">
                     <button
                         type="submit"
-                        disabled={props.is_loading || !props.wallet_created}
+                        disabled={props.is_loading || !props.wallet_created || !props.node_connected || props.watch_only}
                         class={classes!("btn", "btn-prominent", if props.is_loading { "loading" } else { "" })}
                     >
                         { if props.is_loading { "Sending…" } else { "Send Transaction" } }
                     </button>
                 </div>
+                if props.wallet_created && !props.node_connected {
+                    <p class="status error centered-error">{"Waiting for the node connection before sending."}</p>
+                }
+                if props.watch_only {
+                    <p class="status error centered-error">{"This is a watch-only wallet: it has no private keys and cannot sign transactions."}</p>
+                }
             </form>
 
+            <div class="batch-section">
+                <div class="batch-header">
+                    <h3 class="send-recent-title">{"Batch Payment"}</h3>
+                    <label class={classes!("btn", "btn-small", if props.is_loading || !props.wallet_created { "disabled" } else { "" })}>
+                        {"Import CSV"}
+                        <input
+                            type="file"
+                            accept=".csv,text/csv"
+                            hidden=true
+                            onchange={on_csv}
+                            disabled={props.is_loading || !props.wallet_created}
+                        />
+                    </label>
+                </div>
+                { if batch_rows.is_empty() {
+                    html! { <p class="batch-hint">{"One payment per line: address,amount (VE)"}</p> }
+                } else {
+                    html! {
+                        <>
+                            <table class="batch-table">
+                                <thead>
+                                    <tr>
+                                        <th>{"#"}</th>
+                                        <th>{"Address"}</th>
+                                        <th>{"Amount"}</th>
+                                        <th>{"Status"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for batch_rows.iter().enumerate().map(|(i, row)| {
+                                        let status = row_status(props.batch_result.as_ref(), i, row);
+                                        let (label, error) = match (&row.error, status) {
+                                            (Some(e), _) => ("invalid".to_string(), Some(e.clone())),
+                                            (None, Some(s)) => (s.status.clone(), s.error.clone()),
+                                            (None, None) => ("ready".to_string(), None),
+                                        };
+                                        html! {
+                                            <tr class={classes!("batch-row", label.clone())}>
+                                                <td>{ row.line }</td>
+                                                <td class="batch-address" title={row.to_address.clone()}>{ &row.to_address }</td>
+                                                <td>{ format_amount(row.amount) }</td>
+                                                <td title={error.clone().unwrap_or_default()}>{ label }</td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+                            <p class="batch-summary">
+                                { format!("{} recipient(s) · total {}", batch_rows.len(), format_amount(batch_total)) }
+                                if batch_invalid > 0 {
+                                    <span class="batch-invalid">{ format!(" · {} invalid", batch_invalid) }</span>
+                                }
+                            </p>
+                            { if let Some(result) = props.batch_result.as_ref().filter(|r| !r.txids.is_empty()) {
+                                html! {
+                                    <p class="batch-summary">
+                                        { format!("Sent in {} transaction(s), fees {}", result.txids.len(), format_amount(result.total_fees)) }
+                                    </p>
+                                }
+                            } else { html!{} }}
+                            <div class="button-group">
+                                <button
+                                    type="button"
+                                    class="btn btn-small"
+                                    onclick={on_clear_batch}
+                                    disabled={props.is_loading}
+                                >
+                                    {"Clear"}
+                                </button>
+                                <button
+                                    type="button"
+                                    class={classes!("btn", "btn-prominent", if props.is_loading { "loading" } else { "" })}
+                                    onclick={on_send_batch_click}
+                                    disabled={props.is_loading || !props.wallet_created || !props.node_connected || props.watch_only || batch_invalid > 0}
+                                >
+                                    { if props.is_loading { "Sending…" } else { "Send Batch" } }
+                                </button>
+                            </div>
+                        </>
+                    }
+                }}
+            </div>
+
+            <Compound
+                enabled={props.wallet_created && props.node_connected && !props.is_loading && !props.watch_only}
+                fee_priority={(*fee_priority).clone()}
+                payment_secret={payment_secret_from(&payment_secret_words)}
+                payment_secret_required={props.payment_secret_required}
+                selected_utxos={(*selected_utxos).clone()}
+                push_toast={push_toast.clone()}
+            />
+
             { if !props.transaction_status.is_empty() {
                 html! { <p class="status">{ &props.transaction_status }</p> }
             } else { html!{} }}
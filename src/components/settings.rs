@@ -0,0 +1,377 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use crate::models::{NodeEndpoint, NodeSettings, NodeTestResult, ToastKind};
+use crate::components::{AutoLock, RecoveryPhrase, WalletSecurity};
+use crate::utils::{network_label, safe_invoke, format_with_commas};
+
+#[derive(Properties, PartialEq)]
+pub struct SettingsProps {
+    pub network: String,
+    pub networks: Vec<String>,
+    pub wallet_open: bool,
+    pub is_loading: bool,
+    pub on_network_change: Callback<String>,
+    pub on_wallet_changed: Callback<()>,
+    #[prop_or(false)]
+    pub payment_secret_required: bool,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+fn encoding_label(encoding: &str) -> &'static str {
+    if encoding == "json" { "JSON" } else { "Borsh" }
+}
+
+#[function_component(Settings)]
+pub fn settings(props: &SettingsProps) -> Html {
+    let node_settings = use_state(NodeSettings::default);
+    let new_url = use_state(String::new);
+    let new_encoding = use_state(|| "borsh".to_string());
+    let test_result = use_state(|| Option::<NodeTestResult>::None);
+    let testing = use_state(|| false);
+    let saving = use_state(|| false);
+    let dirty = use_state(|| false);
+
+    {
+        let node_settings = node_settings.clone();
+        let dirty = dirty.clone();
+        let test_result = test_result.clone();
+        let push_toast = props.push_toast.clone();
+        use_effect_with(props.network.clone(), move |_| {
+            spawn_local(async move {
+                match safe_invoke("get_node_settings", JsValue::NULL).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<NodeSettings>(res) {
+                        Ok(s) => node_settings.set(s),
+                        Err(_) => push_toast.emit(("Failed to parse node settings".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                dirty.set(false);
+                test_result.set(None);
+            });
+            || {}
+        });
+    }
+
+    let on_network = {
+        let cb = props.on_network_change.clone();
+        Callback::from(move |e: Event| {
+            if let Some(el) = e.target_dyn_into::<HtmlSelectElement>() {
+                cb.emit(el.value());
+            }
+        })
+    };
+
+    let on_new_url = {
+        let new_url = new_url.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                new_url.set(i.value());
+            }
+        })
+    };
+
+    let on_new_encoding = {
+        let new_encoding = new_encoding.clone();
+        Callback::from(move |e: Event| {
+            if let Some(el) = e.target_dyn_into::<HtmlSelectElement>() {
+                new_encoding.set(el.value());
+            }
+        })
+    };
+
+    let run_test = {
+        let testing = testing.clone();
+        let test_result = test_result.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |(url, encoding): (String, String)| {
+            let url = url.trim().to_string();
+            if url.is_empty() {
+                push_toast.emit(("Enter a node URL to test".into(), ToastKind::Error));
+                return;
+            }
+            let testing = testing.clone();
+            let test_result = test_result.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                testing.set(true);
+                test_result.set(None);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "url": url,
+                    "encoding": encoding
+                })).unwrap_or(JsValue::NULL);
+                match safe_invoke("test_node_connection", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<NodeTestResult>(res) {
+                        Ok(result) => test_result.set(Some(result)),
+                        Err(_) => push_toast.emit(("Failed to parse test result".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                testing.set(false);
+            });
+        })
+    };
+
+    let on_test_new = {
+        let run_test = run_test.clone();
+        let new_url = new_url.clone();
+        let new_encoding = new_encoding.clone();
+        Callback::from(move |_: MouseEvent| run_test.emit(((*new_url).clone(), (*new_encoding).clone())))
+    };
+
+    let on_add = {
+        let node_settings = node_settings.clone();
+        let new_url = new_url.clone();
+        let new_encoding = new_encoding.clone();
+        let dirty = dirty.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_: MouseEvent| {
+            let url = (*new_url).trim().to_string();
+            if !(url.starts_with("ws://") || url.starts_with("wss://")) {
+                push_toast.emit(("Node URL must start with ws:// or wss://".into(), ToastKind::Error));
+                return;
+            }
+            let mut current = (*node_settings).clone();
+            if current.endpoints.iter().any(|e| e.url == url) {
+                push_toast.emit(("Endpoint already in the list".into(), ToastKind::Warning));
+                return;
+            }
+            current.endpoints.push(NodeEndpoint { url, encoding: (*new_encoding).clone() });
+            node_settings.set(current);
+            new_url.set(String::new());
+            dirty.set(true);
+        })
+    };
+
+    let move_endpoint = {
+        let node_settings = node_settings.clone();
+        let dirty = dirty.clone();
+        move |from: usize, to: usize| {
+            let node_settings = node_settings.clone();
+            let dirty = dirty.clone();
+            Callback::from(move |_: MouseEvent| {
+                let mut current = (*node_settings).clone();
+                if to < current.endpoints.len() {
+                    current.endpoints.swap(from, to);
+                    node_settings.set(current);
+                    dirty.set(true);
+                }
+            })
+        }
+    };
+
+    let remove_endpoint = {
+        let node_settings = node_settings.clone();
+        let dirty = dirty.clone();
+        move |idx: usize| {
+            let node_settings = node_settings.clone();
+            let dirty = dirty.clone();
+            Callback::from(move |_: MouseEvent| {
+                let mut current = (*node_settings).clone();
+                if idx < current.endpoints.len() {
+                    current.endpoints.remove(idx);
+                    node_settings.set(current);
+                    dirty.set(true);
+                }
+            })
+        }
+    };
+
+    let on_toggle_resolver = {
+        let node_settings = node_settings.clone();
+        let dirty = dirty.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+                let mut current = (*node_settings).clone();
+                current.use_resolver = i.checked();
+                node_settings.set(current);
+                dirty.set(true);
+            }
+        })
+    };
+
+    let on_save = {
+        let node_settings = node_settings.clone();
+        let saving = saving.clone();
+        let dirty = dirty.clone();
+        let push_toast = props.push_toast.clone();
+        let wallet_open = props.wallet_open;
+        Callback::from(move |_: MouseEvent| {
+            let current = (*node_settings).clone();
+            if current.endpoints.is_empty() && !current.use_resolver {
+                push_toast.emit(("Add at least one node or enable the public resolver".into(), ToastKind::Error));
+                return;
+            }
+            let node_settings = node_settings.clone();
+            let saving = saving.clone();
+            let dirty = dirty.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                saving.set(true);
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({ "nodeSettings": current }))
+                    .unwrap_or(JsValue::NULL);
+                match safe_invoke("save_node_settings", args).await {
+                    Ok(res) => {
+                        if let Ok(saved) = serde_wasm_bindgen::from_value::<NodeSettings>(res) {
+                            node_settings.set(saved);
+                        }
+                        dirty.set(false);
+                        let msg = if wallet_open {
+                            "Node settings saved. They apply the next time a wallet is opened."
+                        } else {
+                            "Node settings saved"
+                        };
+                        push_toast.emit((msg.into(), ToastKind::Success));
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                saving.set(false);
+            });
+        })
+    };
+
+    let busy = props.is_loading || *testing || *saving;
+    let endpoints = node_settings.endpoints.clone();
+    let endpoint_count = endpoints.len();
+
+    html! {
+        <div class="screen-container settings-centered" role="main" aria-label="Settings">
+            <div class="settings-inner">
+                <h2 class="settings-title">{"Settings"}</h2>
+
+                <div class="settings-section">
+                    <h3 class="section-title">{"Network"}</h3>
+                    <div class="settings-row">
+                        <select
+                            class="input"
+                            onchange={on_network}
+                            disabled={props.is_loading || props.wallet_open}
+                        >
+                            { for props.networks.iter().map(|n| html! {
+                                <option value={n.clone()} selected={*n == props.network}>
+                                    { network_label(n) }
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                    <p class="settings-hint">
+                        { if props.wallet_open {
+                            "Close the current wallet to switch networks. Wallets always reopen on the network they were created for."
+                        } else {
+                            "New wallets are created and imported on the selected network. Use testnet or simnet before moving real funds."
+                        }}
+                    </p>
+                </div>
+
+                <div class="settings-section">
+                    <h3 class="section-title">{ format!("Nodes ({})", network_label(&props.network)) }</h3>
+                    <p class="settings-hint">
+                        {"Custom wRPC endpoints are tried in order. The public resolver is only used when none of them respond."}
+                    </p>
+
+                    { if endpoints.is_empty() {
+                        html! { <p class="settings-hint">{"No custom nodes configured."}</p> }
+                    } else {
+                        html! {
+                            <ul class="node-list">
+                                { for endpoints.iter().enumerate().map(|(i, ep)| {
+                                    let on_test = {
+                                        let run_test = run_test.clone();
+                                        let ep = ep.clone();
+                                        Callback::from(move |_: MouseEvent| run_test.emit((ep.url.clone(), ep.encoding.clone())))
+                                    };
+                                    html! {
+                                        <li class="node-item" key={ep.url.clone()}>
+                                            <span class="node-order">{ format!("{}.", i + 1) }</span>
+                                            <span class="node-url">{ &ep.url }</span>
+                                            <span class="node-encoding">{ encoding_label(&ep.encoding) }</span>
+                                            <button type="button" class="btn btn-small" onclick={move_endpoint(i, i.wrapping_sub(1))} disabled={busy || i == 0}>{"↑"}</button>
+                                            <button type="button" class="btn btn-small" onclick={move_endpoint(i, i + 1)} disabled={busy || i + 1 == endpoint_count}>{"↓"}</button>
+                                            <button type="button" class="btn btn-small" onclick={on_test} disabled={busy}>{"Test"}</button>
+                                            <button type="button" class="btn btn-small" onclick={remove_endpoint(i)} disabled={busy}>{"Remove"}</button>
+                                        </li>
+                                    }
+                                })}
+                            </ul>
+                        }
+                    }}
+
+                    <div class="settings-row">
+                        <input
+                            type="text"
+                            class="input"
+                            placeholder="ws://127.0.0.1:17110"
+                            value={(*new_url).clone()}
+                            oninput={on_new_url}
+                            disabled={busy}
+                        />
+                        <select class="input node-encoding-select" onchange={on_new_encoding} disabled={busy}>
+                            <option value="borsh" selected={*new_encoding == "borsh"}>{"Borsh"}</option>
+                            <option value="json" selected={*new_encoding == "json"}>{"JSON"}</option>
+                        </select>
+                        <button type="button" class="btn btn-small" onclick={on_test_new} disabled={busy}>
+                            { if *testing { "Testing…" } else { "Test" } }
+                        </button>
+                        <button type="button" class="btn btn-small" onclick={on_add} disabled={busy}>{"Add"}</button>
+                    </div>
+
+                    { if let Some(result) = (*test_result).as_ref() {
+                        html! {
+                            <div class={classes!("node-test-result", if result.success { "success" } else { "failure" })}>
+                                <p><strong>{ if result.success { "Handshake succeeded" } else { "Handshake failed" } }</strong>{ format!(" — {}", result.url) }</p>
+                                if let Some(version) = &result.server_version {
+                                    <p>{ format!("Server version: {}", version) }</p>
+                                }
+                                if let Some(network) = &result.network {
+                                    <p>{ format!("Network: {}", network_label(network)) }</p>
+                                }
+                                if result.success {
+                                    <p>{ format!("Sync status: {}", if result.is_synced { "synced" } else { "syncing" }) }</p>
+                                    <p>{ format!("Virtual DAA score: {}", format_with_commas(result.virtual_daa_score)) }</p>
+                                }
+                                if let Some(err) = &result.error {
+                                    <p class="node-test-error">{ err }</p>
+                                }
+                            </div>
+                        }
+                    } else { html!{} }}
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={node_settings.use_resolver}
+                            oninput={on_toggle_resolver}
+                            disabled={busy}
+                        />
+                        {"Fall back to the public resolver"}
+                    </label>
+
+                    <div class="button-group">
+                        <button
+                            type="button"
+                            class={classes!("btn", "btn-primary", if *saving { "loading" } else { "" })}
+                            onclick={on_save}
+                            disabled={busy || !*dirty}
+                        >
+                            { if *saving { "Saving…" } else { "Save Node Settings" } }
+                        </button>
+                    </div>
+                </div>
+
+                <AutoLock push_toast={props.push_toast.clone()} />
+
+                if props.wallet_open {
+                    <WalletSecurity
+                        push_toast={props.push_toast.clone()}
+                        on_changed={props.on_wallet_changed.clone()}
+                    />
+                    <RecoveryPhrase
+                        payment_secret_required={props.payment_secret_required}
+                        push_toast={props.push_toast.clone()}
+                    />
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,197 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use crate::models::{SweepPreview, SweepResult, ToastKind};
+use crate::utils::{format_amount, safe_invoke};
+
+const FEE_PRIORITIES: [(&str, &str); 3] = [("low", "Low"), ("normal", "Normal"), ("priority", "Priority")];
+
+#[derive(Properties, PartialEq)]
+pub struct SweepKeyProps {
+    /// Open account's receive address; swept funds land here.
+    pub receive_address: String,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+#[function_component(SweepKey)]
+pub fn sweep_key(props: &SweepKeyProps) -> Html {
+    let private_key = use_state(String::new);
+    let fee_priority = use_state(|| "normal".to_string());
+    let preview = use_state(|| Option::<SweepPreview>::None);
+    let busy = use_state(|| false);
+
+    {
+        let preview = preview.clone();
+        use_effect_with(props.receive_address.clone(), move |_| {
+            preview.set(None);
+            || {}
+        });
+    }
+
+    let on_private_key = {
+        let private_key = private_key.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                private_key.set(input.value());
+                preview.set(None);
+            }
+        })
+    };
+
+    let on_fee_priority = {
+        let fee_priority = fee_priority.clone();
+        let preview = preview.clone();
+        move |value: &'static str| {
+            let fee_priority = fee_priority.clone();
+            let preview = preview.clone();
+            Callback::from(move |_| {
+                fee_priority.set(value.to_string());
+                preview.set(None);
+            })
+        }
+    };
+
+    let on_scan = {
+        let private_key = private_key.clone();
+        let fee_priority = fee_priority.clone();
+        let preview = preview.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let key = (*private_key).trim().to_string();
+            if key.is_empty() {
+                push_toast.emit(("Private key is required".into(), ToastKind::Error));
+                return;
+            }
+            let args = serde_json::json!({
+                "input": { "private_key": key, "fee_priority": *fee_priority }
+            });
+            let preview = preview.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("scan_private_key", args).await {
+                    Ok(res) => match serde_wasm_bindgen::from_value::<SweepPreview>(res) {
+                        Ok(found) => preview.set(Some(found)),
+                        Err(_) => push_toast.emit(("Failed to parse balance".into(), ToastKind::Error)),
+                    },
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_sweep = {
+        let private_key = private_key.clone();
+        let fee_priority = fee_priority.clone();
+        let preview = preview.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |_| {
+            let args = serde_json::json!({
+                "input": { "private_key": (*private_key).trim(), "fee_priority": *fee_priority }
+            });
+            let private_key = private_key.clone();
+            let preview = preview.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("sweep_private_key", args).await {
+                    Ok(res) => {
+                        if let Ok(result) = serde_wasm_bindgen::from_value::<SweepResult>(res) {
+                            push_toast.emit((
+                                format!("Swept {} into your wallet", format_amount(result.amount)),
+                                ToastKind::Success,
+                            ));
+                        }
+                        private_key.set(String::new());
+                        preview.set(None);
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    html! {
+        <form class="batch-section sweep-key" onsubmit={on_scan}>
+            <h3 class="send-recent-title">{"Sweep Private Key"}</h3>
+            <p class="batch-hint">
+                {"Move everything held by a paper wallet or other external key to your current receive address. The key is used once and not stored."}
+            </p>
+            <input
+                class="input sweep-key-input"
+                type="password"
+                placeholder="Private key (64 hex characters)"
+                autocomplete="off"
+                value={(*private_key).clone()}
+                oninput={on_private_key}
+                disabled={*busy}
+            />
+            <div class="fee-selector">
+                { for FEE_PRIORITIES.iter().map(|(value, label)| html! {
+                    <button
+                        type="button"
+                        class={classes!("btn", "btn-small", "fee-option", if *fee_priority == *value { "active" } else { "" })}
+                        onclick={on_fee_priority(value)}
+                        disabled={*busy}
+                    >
+                        { *label }
+                    </button>
+                })}
+            </div>
+            <button type="submit" class="btn btn-small" disabled={*busy}>{"Check Balance"}</button>
+
+            if let Some(found) = preview.as_ref() {
+                <div class="sweep-key-preview">
+                    <table class="batch-table">
+                        <tbody>
+                            <tr>
+                                <td>{"Key address"}</td>
+                                <td class="batch-address" title={found.address.clone()}>{ found.address.clone() }</td>
+                            </tr>
+                            <tr>
+                                <td>{"Found"}</td>
+                                <td>{ format!("{} in {} UTXO(s)", format_amount(found.balance), found.utxos) }</td>
+                            </tr>
+                            if found.pending > 0 {
+                                <tr>
+                                    <td>{"Not yet mature"}</td>
+                                    <td>{ format_amount(found.pending) }</td>
+                                </tr>
+                            }
+                            <tr>
+                                <td>{"Fees"}</td>
+                                <td>{ format_amount(found.fees) }</td>
+                            </tr>
+                            <tr>
+                                <td>{"You receive"}</td>
+                                <td>{ format_amount(found.amount) }</td>
+                            </tr>
+                            <tr>
+                                <td>{"To"}</td>
+                                <td class="batch-address" title={found.destination.clone()}>{ found.destination.clone() }</td>
+                            </tr>
+                        </tbody>
+                    </table>
+                    if found.balance == 0 {
+                        <p class="batch-hint">{"Nothing to sweep: this key holds no mature funds."}</p>
+                    } else {
+                        <button type="button" class="btn btn-prominent" onclick={on_sweep} disabled={*busy}>
+                            { format!("Sweep {}", format_amount(found.amount)) }
+                        </button>
+                    }
+                </div>
+            }
+        </form>
+    }
+}
@@ -10,21 +10,19 @@ pub struct TransactionsProps {
     pub is_loading: bool,
     pub our_receive_address: String,
     pub on_tx_click: Callback<Transaction>,
+    #[prop_or_default]
+    pub total: usize,
+    #[prop_or_default]
+    pub on_load_more: Callback<()>,
 }
 
 #[function_component(Transactions)]
 pub fn transactions(props: &TransactionsProps) -> Html {
     let transactions = props.transactions.clone();
-    let our_receive_address = props.our_receive_address.clone();
     let on_tx_click = props.on_tx_click.clone();
-
-    let mut recent: Vec<Transaction> = transactions
-        .clone()
-        .into_iter()
-        .rev()
-        .take(4)
-        .collect();
-    recent.reverse();
+    let loaded = transactions.len();
+    let has_more = loaded < props.total;
+    let on_load_more = props.on_load_more.reform(|_: MouseEvent| ());
 
     html! {
         <div class="screen-container" role="main" aria-label="Transactions">
@@ -46,28 +44,29 @@ pub fn transactions(props: &TransactionsProps) -> Html {
             } else {
                 html! {
                     <>
-                        <h3 class="tx-recent-title">{"Latest Activity"}</h3>
-                        <div class="tx-grid">
-                            { for recent.into_iter().map(move |tx| {
+                        <h3 class="tx-recent-title">{ format!("Activity ({})", props.total) }</h3>
+                        <div class="tx-grid tx-history">
+                            { for transactions.into_iter().map(move |tx| {
                                 let tx_owned = tx.clone();
                                 let cb = on_tx_click.clone();
-                                let our_addr = our_receive_address.clone();
 
                                 let on_click = Callback::from(move |_| {
                                     cb.emit(tx_owned.clone());
                                 });
 
-                                let is_outgoing = !tx.to_address.is_empty() && tx.to_address != our_addr;
+                                let is_outgoing = tx.is_outgoing();
                                 let amount_str = format_amount(tx.amount);
-                                let direction = if is_outgoing { "Sent" } else { "Received" };
                                 let amount_class = if is_outgoing { "amount-out" } else { "amount-in" };
                                 let icon_class = if is_outgoing { "outgoing" } else { "incoming" };
 
                                 html! {
-                                    <div class="tx-card clickable" onclick={on_click}>
+                                    <div class={classes!("tx-card", "clickable", if tx.kind == "reorg" { "tx-reorg" } else { "" })} onclick={on_click} key={tx.txid.clone()}>
                                         <div class="tx-header">
                                             <span class={classes!("icon", icon_class)}></span>
-                                            <strong>{ direction }</strong>
+                                            <strong>{ tx.kind_label() }</strong>
+                                            if tx.is_pending() {
+                                                <span class="tx-badge pending">{"Pending"}</span>
+                                            }
                                         </div>
                                         <div class="tx-body">
                                             <p class={classes!("tx-amt", amount_class)}>
@@ -79,6 +78,14 @@ pub fn transactions(props: &TransactionsProps) -> Html {
                                 }
                             })}
                         </div>
+                        <p class="tx-count">{ format!("Showing {} of {}", loaded, props.total) }</p>
+                        if has_more {
+                            <div class="button-group">
+                                <button type="button" class="btn btn-small" onclick={on_load_more} disabled={props.is_loading}>
+                                    { if props.is_loading { "Loading…" } else { "Load More" } }
+                                </button>
+                            </div>
+                        }
                     </>
                 }
             }}
@@ -1,6 +1,6 @@
 use yew::prelude::*;
 use crate::models::Transaction;
-use crate::utils::format_amount;
+use crate::utils::{format_amount, format_with_commas, explorer_tx_url};
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen::prelude::*;
 use js_sys::Reflect;
@@ -10,20 +10,21 @@ use web_sys::window;
 pub struct TxDetailProps {
     pub tx: Transaction,
     pub our_address: String,
+    pub network: String,
     pub on_close: Callback<()>,
 }
 
 #[function_component(TxDetailModal)]
 pub fn tx_detail_modal(props: &TxDetailProps) -> Html {
-    let is_out = !props.tx.to_address.is_empty() && props.tx.to_address != props.our_address;
-    let direction = if is_out { "Sent" } else { "Received" };
+    let is_out = props.tx.is_outgoing();
+    let direction = props.tx.kind_label();
     let sign = if is_out { "-" } else { "+" };
     let amount_class = if is_out { "amount-out" } else { "amount-in" };
 
-    let explorer_url = format!("https://vecnoscan.org/txs/{}", props.tx.txid);
+    let explorer_url = explorer_tx_url(&props.network, &props.tx.txid);
 
     let on_explorer_click = {
-        let url = explorer_url.clone();
+        let url = explorer_url.clone().unwrap_or_default();
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
             e.stop_propagation();
@@ -60,19 +61,32 @@ pub fn tx_detail_modal(props: &TxDetailProps) -> Html {
                             { sign }{ format_amount(props.tx.amount) }
                         </span>
                     </p>
+                    if let Some(fee) = props.tx.fee {
+                        <p><strong>{"Fee:"}</strong> { format_amount(fee) }</p>
+                    }
                     <p><strong>{"Date:"}</strong> { &props.tx.timestamp }</p>
+                    if !props.tx.maturity.is_empty() {
+                        <p><strong>{"Status:"}</strong> { if props.tx.is_pending() { "Pending" } else { "Confirmed" } }</p>
+                    }
+                    if props.tx.block_daa_score > 0 {
+                        <p><strong>{"DAA Score:"}</strong> { format_with_commas(props.tx.block_daa_score) }</p>
+                    }
                     <p><strong>{"Address:"}</strong>
-                        <span class="tx-addr">{ if is_out { &props.tx.to_address } else { &props.our_address } }</span>
+                        <span class="tx-addr">
+                            { if props.tx.to_address.is_empty() { &props.our_address } else { &props.tx.to_address } }
+                        </span>
                     </p>
                     <p><strong>{"TXID:"}</strong></p>
                     <div class="txid-box">
                         <code class="tx-addr">{ &props.tx.txid }</code>
                     </div>
-                    <div class="button-container">
-                        <button onclick={on_explorer_click} class="btn btn-explorer centered">
-                            {"Open in Vecnoscan"}
-                        </button>
-                    </div>
+                    if explorer_url.is_some() {
+                        <div class="button-container">
+                            <button onclick={on_explorer_click} class="btn btn-explorer centered">
+                                {"Open in Vecnoscan"}
+                            </button>
+                        </div>
+                    }
                 </div>
             </div>
         </div>
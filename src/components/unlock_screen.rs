@@ -0,0 +1,111 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use crate::models::{LockStatus, ToastKind};
+use crate::utils::{bind_input, safe_invoke, verify_password};
+
+#[derive(Properties, PartialEq)]
+pub struct UnlockScreenProps {
+    /// Why the wallet locked, as sent with `wallet://locked`.
+    pub reason: Option<String>,
+    pub on_unlocked: Callback<()>,
+    pub push_toast: Callback<(String, ToastKind)>,
+}
+
+fn reason_text(reason: Option<&str>) -> &'static str {
+    match reason {
+        Some("idle") => "Locked after a period of inactivity.",
+        Some("blur") => "Locked when the window lost focus.",
+        Some("suspend") => "Locked while the computer was asleep.",
+        _ => "The wallet is locked.",
+    }
+}
+
+#[function_component(UnlockScreen)]
+pub fn unlock_screen(props: &UnlockScreenProps) -> Html {
+    let status = use_state(LockStatus::default);
+    let password = use_state(String::new);
+    let busy = use_state(|| false);
+
+    {
+        let status = status.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(res) = safe_invoke("get_lock_status", JsValue::NULL).await {
+                    if let Ok(current) = serde_wasm_bindgen::from_value::<LockStatus>(res) {
+                        status.set(current);
+                    }
+                }
+            });
+            || {}
+        });
+    }
+
+    let onsubmit = {
+        let status = status.clone();
+        let password = password.clone();
+        let busy = busy.clone();
+        let on_unlocked = props.on_unlocked.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(path) = status.wallet_path.clone() else {
+                push_toast.emit(("Wallet file not found".into(), ToastKind::Error));
+                return;
+            };
+            let secret = (*password).clone();
+            let password = password.clone();
+            let busy = busy.clone();
+            let on_unlocked = on_unlocked.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                if let Err(e) = verify_password(&path, &secret).await {
+                    push_toast.emit((e, ToastKind::Error));
+                    busy.set(false);
+                    return;
+                }
+                let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+                    "input": { "secret": secret }
+                }))
+                .unwrap_or(JsValue::NULL);
+                match safe_invoke("unlock_wallet", args).await {
+                    Ok(_) => {
+                        password.set(String::new());
+                        on_unlocked.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="unlock-screen" role="dialog" aria-modal="true">
+            <form class="unlock-inner" {onsubmit}>
+                <h2 class="unlock-title">{"Wallet Locked"}</h2>
+                if let Some(name) = status.wallet_name.clone() {
+                    <p class="unlock-wallet">{ name }</p>
+                }
+                <p class="home-hint">{ reason_text(props.reason.as_deref()) }</p>
+                <input
+                    class="input"
+                    type="password"
+                    placeholder="Wallet password"
+                    autofocus=true
+                    value={(*password).clone()}
+                    oninput={bind_input(&password)}
+                    disabled={*busy}
+                />
+                <button
+                    type="submit"
+                    class={classes!("btn", "btn-prominent", if *busy { "loading" } else { "" })}
+                    disabled={*busy || password.is_empty()}
+                >
+                    { if *busy { "Unlocking..." } else { "Unlock" } }
+                </button>
+            </form>
+        </div>
+    }
+}
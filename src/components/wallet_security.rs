@@ -0,0 +1,174 @@
+use yew::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use crate::models::ToastKind;
+use crate::utils::{bind_input, is_valid_password, safe_invoke};
+
+#[derive(Properties, PartialEq)]
+pub struct WalletSecurityProps {
+    pub push_toast: Callback<(String, ToastKind)>,
+    /// Fired after the payment secret changed so callers can re-check
+    /// whether signing still needs one.
+    pub on_changed: Callback<()>,
+}
+
+#[function_component(WalletSecurity)]
+pub fn wallet_security(props: &WalletSecurityProps) -> Html {
+    let old_password = use_state(String::new);
+    let new_password = use_state(String::new);
+    let confirm_password = use_state(String::new);
+    let wallet_password = use_state(String::new);
+    let old_payment_secret = use_state(String::new);
+    let new_payment_secret = use_state(String::new);
+    let busy = use_state(|| false);
+    // None until the backend has said whether the keys allow it.
+    let payment_secret_changeable = use_state(|| None::<bool>);
+
+    {
+        let payment_secret_changeable = payment_secret_changeable.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(res) = safe_invoke("payment_secret_changeable", JsValue::NULL).await {
+                    payment_secret_changeable.set(res.as_bool());
+                }
+            });
+            || {}
+        });
+    }
+
+    let on_change_password = {
+        let old_password = old_password.clone();
+        let new_password = new_password.clone();
+        let confirm_password = confirm_password.clone();
+        let busy = busy.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if old_password.is_empty() {
+                push_toast.emit(("Current password is required".into(), ToastKind::Error));
+                return;
+            }
+            if !is_valid_password(&new_password) {
+                push_toast.emit(("Password must be at least 8 characters".into(), ToastKind::Error));
+                return;
+            }
+            if *new_password != *confirm_password {
+                push_toast.emit(("New passwords do not match".into(), ToastKind::Error));
+                return;
+            }
+            let args = serde_json::json!({
+                "input": { "old_secret": *old_password, "new_secret": *new_password }
+            });
+            let old_password = old_password.clone();
+            let new_password = new_password.clone();
+            let confirm_password = confirm_password.clone();
+            let busy = busy.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("change_wallet_password", args).await {
+                    Ok(_) => {
+                        push_toast.emit(("Wallet password changed".into(), ToastKind::Success));
+                        old_password.set(String::new());
+                        new_password.set(String::new());
+                        confirm_password.set(String::new());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    let on_change_payment_secret = {
+        let wallet_password = wallet_password.clone();
+        let old_payment_secret = old_payment_secret.clone();
+        let new_payment_secret = new_payment_secret.clone();
+        let busy = busy.clone();
+        let on_changed = props.on_changed.clone();
+        let push_toast = props.push_toast.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if wallet_password.is_empty() {
+                push_toast.emit(("Wallet password is required".into(), ToastKind::Error));
+                return;
+            }
+            let old_secret = (*old_payment_secret).trim().to_string();
+            let new_secret = (*new_payment_secret).trim().to_string();
+            let args = serde_json::json!({
+                "input": {
+                    "secret": *wallet_password,
+                    "old_payment_secret": (!old_secret.is_empty()).then_some(old_secret),
+                    "new_payment_secret": (!new_secret.is_empty()).then_some(new_secret.clone())
+                }
+            });
+            let wallet_password = wallet_password.clone();
+            let old_payment_secret = old_payment_secret.clone();
+            let new_payment_secret = new_payment_secret.clone();
+            let busy = busy.clone();
+            let on_changed = on_changed.clone();
+            let push_toast = push_toast.clone();
+            spawn_local(async move {
+                busy.set(true);
+                let args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+                match safe_invoke("change_payment_secret", args).await {
+                    Ok(_) => {
+                        let msg = if new_secret.is_empty() { "Payment secret removed" } else { "Payment secret changed" };
+                        push_toast.emit((msg.into(), ToastKind::Success));
+                        wallet_password.set(String::new());
+                        old_payment_secret.set(String::new());
+                        new_payment_secret.set(String::new());
+                        on_changed.emit(());
+                    }
+                    Err(e) => push_toast.emit((e, ToastKind::Error)),
+                }
+                busy.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="settings-section">
+            <h3 class="section-title">{"Security"}</h3>
+
+            <form class="security-form" onsubmit={on_change_password}>
+                <p class="settings-hint">{"Change the password that encrypts this wallet file and its keys."}</p>
+                <div class="settings-row">
+                    <input class="input" type="password" placeholder="Current password" value={(*old_password).clone()} oninput={bind_input(&old_password)} disabled={*busy} />
+                </div>
+                <div class="settings-row">
+                    <input class="input" type="password" placeholder="New password" value={(*new_password).clone()} oninput={bind_input(&new_password)} disabled={*busy} />
+                    <input class="input" type="password" placeholder="Confirm new password" value={(*confirm_password).clone()} oninput={bind_input(&confirm_password)} disabled={*busy} />
+                </div>
+                <div class="button-group">
+                    <button type="submit" class="btn btn-primary" disabled={*busy}>{"Change Password"}</button>
+                </div>
+            </form>
+
+            {match *payment_secret_changeable {
+                Some(true) => html! {
+                    <form class="security-form" onsubmit={on_change_payment_secret}>
+                        <p class="settings-hint">{"Change or remove the payment secret that protects private keys."}</p>
+                        <div class="settings-row">
+                            <input class="input" type="password" placeholder="Wallet password" value={(*wallet_password).clone()} oninput={bind_input(&wallet_password)} disabled={*busy} />
+                        </div>
+                        <div class="settings-row">
+                            <input class="input" type="password" placeholder="Current payment secret (if any)" value={(*old_payment_secret).clone()} oninput={bind_input(&old_payment_secret)} disabled={*busy} />
+                            <input class="input" type="password" placeholder="New payment secret (empty to remove)" value={(*new_payment_secret).clone()} oninput={bind_input(&new_payment_secret)} disabled={*busy} />
+                        </div>
+                        <div class="button-group">
+                            <button type="submit" class="btn btn-primary" disabled={*busy}>{"Change Payment Secret"}</button>
+                        </div>
+                    </form>
+                },
+                Some(false) => html! {
+                    <p class="settings-hint">
+                        {"The payment secret of a wallet created or restored from a recovery phrase is also its BIP39 passphrase, so changing it would derive different addresses. To use a new one, create a new wallet and move the funds."}
+                    </p>
+                },
+                None => html! {},
+            }}
+        </div>
+    }
+}
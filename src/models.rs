@@ -22,12 +22,32 @@ pub struct ImportWalletArgs {
     pub filename: String,
 }
 
-#[derive(serde::Deserialize, Debug, Clone)]
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct BalanceResponse {
     pub balance: u64,
+    #[serde(default)]
+    pub mature: u64,
+    #[serde(default)]
+    pub pending: u64,
+    #[serde(default)]
+    pub outgoing: u64,
+    #[serde(default)]
+    pub mature_utxo_count: usize,
+    #[serde(default)]
+    pub pending_utxo_count: usize,
+    #[serde(default)]
+    pub stasis_utxo_count: usize,
     pub timestamp: i64,
 }
 
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct TransactionEvent {
+    pub txid: String,
+    pub kind: String,
+    pub amount: u64,
+    pub timestamp: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct GetBalanceArgs {
     pub address: String,
@@ -44,26 +64,436 @@ pub struct SendTransactionArgs {
 pub struct WalletFile {
     pub name: String,
     pub path: String,
+    #[serde(default)]
+    pub network: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub struct Transaction {
     pub txid: String,
-    pub to_address: String,
+    #[serde(default)]
+    pub kind: String,
     pub amount: u64,
+    #[serde(default)]
+    pub fee: Option<u64>,
+    pub to_address: String,
+    #[serde(default)]
+    pub block_daa_score: u64,
     pub timestamp: String,
+    #[serde(default)]
+    pub maturity: String,
+    #[serde(default)]
+    pub is_coinbase: bool,
+}
+
+impl Transaction {
+    pub fn is_outgoing(&self) -> bool {
+        matches!(self.kind.as_str(), "outgoing" | "batch" | "transfer-outgoing")
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.maturity == "pending"
+    }
+
+    pub fn kind_label(&self) -> &'static str {
+        match self.kind.as_str() {
+            "incoming" if self.is_coinbase => "Mined",
+            "incoming" => "Received",
+            "outgoing" => "Sent",
+            "change" => "Change",
+            "batch" => "Consolidation",
+            "reorg" => "Reorged",
+            "external" => "External",
+            "transfer-incoming" => "Transfer In",
+            "transfer-outgoing" => "Transfer Out",
+            _ => "Transaction",
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct TransactionPage {
+    pub transactions: Vec<Transaction>,
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
 }
 
 pub type SentTxInfo = Transaction;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendRequest {
+    pub to_address: String,
+    pub amount: u64,
+    pub payment_secret: Option<String>,
+    pub fee_priority: String,
+    pub sweep: bool,
+    pub utxos: Option<Vec<String>>,
+}
+
+/// One recipient row of a batch payment, as read from the CSV import.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchOnlyRequest {
+    pub xpub: String,
+    pub addresses: Vec<String>,
+    pub secret: String,
+    pub filename: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrivateKeyRequest {
+    pub private_key: String,
+    pub secret: String,
+    pub payment_secret: Option<String>,
+    pub filename: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchRow {
+    pub line: usize,
+    pub to_address: String,
+    pub amount: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchRequest {
+    pub rows: Vec<BatchRow>,
+    pub payment_secret: Option<String>,
+    pub fee_priority: String,
+    pub utxos: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct UtxoInfo {
+    pub outpoint: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub address: Option<String>,
+    pub block_daa_score: u64,
+    pub maturity: String,
+    pub is_coinbase: bool,
+    pub frozen: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct AccountInfo {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    #[serde(default)]
+    pub index: Option<u64>,
+    #[serde(default)]
+    pub receive_address: Option<String>,
+    #[serde(default)]
+    pub balance: Option<BalanceResponse>,
+    #[serde(default)]
+    pub watch_only: bool,
+    #[serde(default)]
+    pub multisig: Option<MultisigInfo>,
+    pub active: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct MultisigInfo {
+    pub minimum_signatures: u16,
+    pub cosigners: usize,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct PartialOutput {
+    #[serde(default)]
+    pub address: Option<String>,
+    pub amount: u64,
+    pub change: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ReviewInput {
+    pub outpoint: String,
+    pub address: String,
+    pub amount: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ReviewOutput {
+    #[serde(default)]
+    pub address: Option<String>,
+    pub amount: u64,
+    pub change: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct TransactionReview {
+    pub data: String,
+    /// `json` or `borsh` (hex-encoded).
+    pub format: String,
+    pub network_id: String,
+    pub transactions: usize,
+    pub inputs: Vec<ReviewInput>,
+    pub outputs: Vec<ReviewOutput>,
+    pub total_input: u64,
+    pub total_output: u64,
+    pub change: u64,
+    pub fees: u64,
+    pub signed: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct SweepPreview {
+    pub address: String,
+    pub destination: String,
+    pub utxos: usize,
+    pub balance: u64,
+    pub pending: u64,
+    pub amount: u64,
+    pub fees: u64,
+    pub transactions: usize,
+    pub fee_priority: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct SweepResult {
+    pub txids: Vec<String>,
+    pub address: String,
+    pub destination: String,
+    pub amount: u64,
+    pub fees: u64,
+    pub timestamp: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct PartialTransaction {
+    pub pskb: String,
+    pub transactions: usize,
+    pub inputs: usize,
+    pub outputs: Vec<PartialOutput>,
+    pub total_input: u64,
+    pub fees: u64,
+    pub signatures: usize,
+    pub required_signatures: u16,
+    pub complete: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct DerivedAddress {
+    pub address: String,
+    pub kind: String,
+    pub index: u32,
+    pub balance: u64,
+    pub utxo_count: usize,
+    pub used: bool,
+    pub current: bool,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct CompoundPreview {
+    pub utxos: usize,
+    pub total_amount: u64,
+    pub transactions: usize,
+    pub total_fees: u64,
+    pub mass: u64,
+    pub final_amount: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct CompoundProgress {
+    pub index: usize,
+    pub estimated_transactions: usize,
+    pub txid: String,
+    pub fees: u64,
+    pub is_final: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct CompoundResult {
+    pub txids: Vec<String>,
+    pub total_fees: u64,
+    pub aborted: bool,
+}
+
+impl UtxoInfo {
+    pub fn is_spendable(&self) -> bool {
+        !self.frozen && self.maturity == "confirmed"
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct BatchOutputStatus {
+    pub index: usize,
+    pub to_address: String,
+    pub amount: u64,
+    pub status: String,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct BatchSendResult {
+    pub txids: Vec<String>,
+    pub outputs: Vec<BatchOutputStatus>,
+    pub total_amount: u64,
+    pub total_fees: u64,
+    pub timestamp: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct FeeTier {
+    pub priority: String,
+    pub feerate: f64,
+    pub estimated_seconds: f64,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct SendEstimate {
+    pub amount: u64,
+    pub total_fees: u64,
+    pub mass: u64,
+    pub transactions: usize,
+    pub inputs: usize,
+    pub fee_priority: String,
+    #[serde(default)]
+    pub sweep: bool,
+    #[serde(default)]
+    pub tiers: Vec<FeeTier>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct NodeInfo {
     pub url: String,
+    #[serde(default)]
+    pub network: String,
+    #[serde(default)]
+    pub encoding: Option<String>,
+    #[serde(default)]
+    pub from_resolver: bool,
+    #[serde(default)]
+    pub server_version: Option<String>,
+    #[serde(default)]
+    pub is_synced: bool,
+    #[serde(default)]
+    pub connected: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeStatusEvent {
+    pub url: Option<String>,
+    pub network: Option<String>,
+    pub server_version: Option<String>,
+    pub is_synced: bool,
+    pub virtual_daa_score: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeDisconnectedEvent {
+    pub url: Option<String>,
+    pub attempt: u32,
+    pub retry_in_secs: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeEndpoint {
+    pub url: String,
+    pub encoding: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeSettings {
+    pub endpoints: Vec<NodeEndpoint>,
+    pub use_resolver: bool,
+}
+
+impl Default for NodeSettings {
+    fn default() -> Self {
+        NodeSettings {
+            endpoints: Vec::new(),
+            use_resolver: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LockSettings {
+    pub idle_minutes: u32,
+    pub lock_on_blur: bool,
+    pub lock_on_suspend: bool,
+}
+
+impl Default for LockSettings {
+    fn default() -> Self {
+        LockSettings {
+            idle_minutes: 15,
+            lock_on_blur: false,
+            lock_on_suspend: true,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct LockStatus {
+    pub locked: bool,
+    pub wallet_name: Option<String>,
+    pub wallet_path: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct LockedEvent {
+    pub reason: String,
+}
+
+/// BIP39 wordlists offered for recovery phrases, as `(code, label)`.
+pub const MNEMONIC_LANGUAGES: [(&str, &str); 10] = [
+    ("english", "English"),
+    ("spanish", "Español"),
+    ("french", "Français"),
+    ("italian", "Italiano"),
+    ("portuguese", "Português"),
+    ("czech", "Čeština"),
+    ("japanese", "日本語"),
+    ("korean", "한국어"),
+    ("chinese-simplified", "简体中文"),
+    ("chinese-traditional", "繁體中文"),
+];
+
+pub fn mnemonic_language_label(code: &str) -> &str {
+    MNEMONIC_LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, label)| *label)
+        .unwrap_or(code)
+}
+
+/// Wordlist detected for a typed or pasted phrase.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct MnemonicLanguage {
+    pub language: String,
+    pub word_count: usize,
+}
+
+/// Word positions the backup quiz asks for.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct BackupChallenge {
+    pub positions: Vec<usize>,
+    pub payment_secret_required: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeTestResult {
+    pub success: bool,
+    pub url: String,
+    pub server_version: Option<String>,
+    pub network: Option<String>,
+    pub is_synced: bool,
+    pub has_utxo_index: bool,
+    pub virtual_daa_score: u64,
+    pub error: Option<String>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -72,11 +502,19 @@ pub enum Screen {
     Home,
     CreateWallet,
     ImportWallet,
+    ImportWatchOnly,
+    ImportPrivateKey,
     MnemonicDisplay(String),
+    /// Backup check; carries the phrase when reached from `MnemonicDisplay`
+    /// so Back can show it again.
+    MnemonicQuiz(Option<String>),
     Wallet,
     Receive,
     Transactions,
     Send,
+    Multisig,
+    Offline,
+    Settings,
 }
 
 #[derive(Clone, PartialEq, Debug, Default)]
@@ -1,11 +1,12 @@
-use yew::UseStateHandle;
+use yew::{Callback, InputEvent, MouseEvent, TargetCast, UseStateHandle};
 use yew::platform::spawn_local;
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::*;
 use log::error;
 use js_sys::{Promise, Reflect};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::window;
+use web_sys::{window, HtmlInputElement, HtmlTextAreaElement};
+use crate::models::{BatchRow, ToastKind};
 
 #[wasm_bindgen]
 extern "C" {
@@ -85,6 +86,58 @@ pub fn ve_to_veni(ve_str: &str) -> Option<u64> {
     }
 }
 
+/// Exact VE string for an amount in veni, suitable for an input field.
+pub fn veni_to_ve(veni: u64) -> String {
+    let whole = veni / 100_000_000;
+    let frac = veni % 100_000_000;
+    if frac == 0 {
+        whole.to_string()
+    } else {
+        format!("{}.{:08}", whole, frac).trim_end_matches('0').to_string()
+    }
+}
+
+/// Parses `address,amount` lines from a payment CSV. Amounts are in VE;
+/// a leading header row and blank lines are ignored, and `;` is accepted
+/// as the separator for spreadsheets using decimal commas.
+pub fn parse_payment_csv(text: &str) -> Vec<BatchRow> {
+    let mut rows = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let separator = if line.contains(';') { ';' } else { ',' };
+        let mut fields = line.split(separator).map(|f| f.trim().trim_matches('"').trim());
+        let to_address = fields.next().unwrap_or_default().to_string();
+        let raw_amount = fields.next().unwrap_or_default().replace(',', ".");
+        let amount = ve_to_veni(&raw_amount);
+
+        if rows.is_empty() && amount.is_none() && !to_address.contains(':') {
+            continue;
+        }
+
+        let error = if to_address.is_empty() {
+            Some("Missing address".to_string())
+        } else if amount.is_none() {
+            Some(format!("Invalid amount '{}'", raw_amount))
+        } else {
+            None
+        };
+
+        rows.push(BatchRow {
+            line: i + 1,
+            to_address,
+            amount: amount.unwrap_or(0),
+            error,
+        });
+    }
+
+    rows
+}
+
 pub fn clear_status_after_delay(status: UseStateHandle<String>, delay_ms: u64) {
     let status = status.clone();
     spawn_local(async move {
@@ -99,6 +152,38 @@ pub fn clear_status_after_delay(status: UseStateHandle<String>, delay_ms: u64) {
     });
 }
 
+/// Subscribes to a backend event via `window.__TAURI__.event.listen` and
+/// hands the event payload to `handler`. Returns the unlisten function.
+pub async fn listen<F>(event: &str, handler: F) -> Result<js_sys::Function, String>
+where
+    F: FnMut(JsValue) + 'static,
+{
+    let window = window().ok_or("No window")?;
+    let tauri = Reflect::get(&window, &"__TAURI__".into())
+        .map_err(|_| "Tauri not found".to_string())?;
+    let event_api = Reflect::get(&tauri, &"event".into())
+        .map_err(|_| "Tauri event API not found".to_string())?;
+    let listen_fn = Reflect::get(&event_api, &"listen".into())
+        .map_err(|_| "listen not found".to_string())?;
+
+    let mut handler = handler;
+    let callback = Closure::<dyn FnMut(JsValue)>::new(move |evt: JsValue| {
+        let payload = Reflect::get(&evt, &"payload".into()).unwrap_or(JsValue::NULL);
+        handler(payload);
+    });
+
+    let promise = js_sys::Function::from(listen_fn)
+        .call2(&JsValue::NULL, &event.into(), callback.as_ref().unchecked_ref())
+        .map_err(|_| "Call failed".to_string())?;
+    // The listener lives until unlisten is called, so the closure must too.
+    callback.forget();
+
+    let unlisten = JsFuture::from(Promise::from(promise)).await
+        .map_err(get_error_message)?;
+
+    Ok(js_sys::Function::from(unlisten))
+}
+
 pub fn get_error_message(res: JsValue) -> String {
     if let Ok(error_val) = Reflect::get(&res, &"error".into()) {
         if let Some(s) = error_val.as_string() {
@@ -181,6 +266,41 @@ pub async fn verify_password(filename: &str, secret: &str) -> Result<(), String>
     }
 }
 
+/// Fetches the open wallet's recovery phrase; the backend checks the
+/// password against the wallet file first.
+pub async fn reveal_mnemonic(secret: &str, payment_secret: Option<&str>) -> Result<String, String> {
+    let args = serde_wasm_bindgen::to_value(&serde_json::json!({
+        "input": { "secret": secret, "payment_secret": payment_secret }
+    }))
+    .map_err(|e| format!("Request error: {}", e))?;
+    let res = safe_invoke("reveal_mnemonic", args).await?;
+    res.as_string().ok_or_else(|| "Unexpected response".to_string())
+}
+
+pub fn is_mainnet(network: &str) -> bool {
+    network.is_empty() || network == "mainnet"
+}
+
+pub fn network_label(network: &str) -> String {
+    match network {
+        "" | "mainnet" => "Mainnet".to_string(),
+        n if n.starts_with("testnet") => format!("Testnet ({})", n.trim_start_matches("testnet-")),
+        "devnet" => "Devnet".to_string(),
+        "simnet" => "Simnet".to_string(),
+        other => other.to_string(),
+    }
+}
+
+pub fn explorer_tx_url(network: &str, txid: &str) -> Option<String> {
+    if is_mainnet(network) {
+        Some(format!("https://vecnoscan.org/txs/{}", txid))
+    } else if network.starts_with("testnet") {
+        Some(format!("https://testnet.vecnoscan.org/txs/{}", txid))
+    } else {
+        None
+    }
+}
+
 pub fn format_with_commas(n: u64) -> String {
     let s = n.to_string();
     let chars: Vec<char> = s.chars().collect();
@@ -236,4 +356,66 @@ pub fn format_difficulty(diff: f64) -> String {
     } else {
         format!("{} {}", num_str, unit)
     }
-}
\ No newline at end of file
+}
+
+/// Keeps a string state in sync with a text input.
+pub fn bind_input(state: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let state = state.clone();
+    Callback::from(move |e: InputEvent| {
+        if let Some(i) = e.target_dyn_into::<HtmlInputElement>() {
+            state.set(i.value());
+        }
+    })
+}
+
+/// Keeps a string state in sync with a textarea.
+pub fn bind_textarea(state: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let state = state.clone();
+    Callback::from(move |e: InputEvent| {
+        if let Some(i) = e.target_dyn_into::<HtmlTextAreaElement>() {
+            state.set(i.value());
+        }
+    })
+}
+
+/// Copies `text` to the clipboard and confirms with a toast.
+pub fn copy_callback(text: String, push_toast: Callback<(String, ToastKind)>) -> Callback<MouseEvent> {
+    Callback::from(move |_| {
+        let Some(window) = window() else { return };
+        let future = wasm_bindgen_futures::JsFuture::from(window.navigator().clipboard().write_text(&text));
+        let push_toast = push_toast.clone();
+        spawn_local(async move {
+            if future.await.is_ok() {
+                push_toast.emit(("Copied to clipboard".into(), ToastKind::Info));
+            }
+        });
+    })
+}
+
+/// How long a copied recovery phrase may stay on the clipboard.
+pub const CLIPBOARD_CLEAR_MS: u32 = 30_000;
+
+/// Copies a secret to the clipboard and wipes it again after
+/// `CLIPBOARD_CLEAR_MS`, unless something else was copied since.
+pub fn copy_secret(text: String, push_toast: Callback<(String, ToastKind)>) {
+    let Some(window) = window() else { return };
+    let clipboard = window.navigator().clipboard();
+    let written = JsFuture::from(clipboard.write_text(&text));
+    spawn_local(async move {
+        if let Err(e) = written.await {
+            error!("Clipboard error: {:?}", e);
+            push_toast.emit(("Copy failed".into(), ToastKind::Error));
+            return;
+        }
+        push_toast.emit((
+            format!("Copied. The clipboard is cleared in {} seconds", CLIPBOARD_CLEAR_MS / 1000),
+            ToastKind::Info,
+        ));
+        gloo_timers::future::TimeoutFuture::new(CLIPBOARD_CLEAR_MS).await;
+        // Without read access, clear anyway: a lost clipboard beats a leaked phrase.
+        let current = JsFuture::from(clipboard.read_text()).await.ok().and_then(|v| v.as_string());
+        if current.is_none() || current.as_deref() == Some(text.as_str()) {
+            let _ = JsFuture::from(clipboard.write_text("")).await;
+        }
+    });
+}